@@ -0,0 +1,54 @@
+use std::time::Instant;
+
+use curv::arithmetic::*;
+use curv::elliptic::curves::*;
+
+const ITERATIONS: usize = 1_000_000;
+
+/// Barrett reduction benchmark:
+/// multiplies many pairs of numbers modulo curve order, first via plain `Modulo::mod_mul`,
+/// then via precomputed `ReductionContext`, and prints time spent by both.
+/// TO RUN:
+/// cargo run --release --example barrett_reduction -- CURVE_NAME
+/// CURVE_NAME is any of the supported curves: i.e.:
+/// cargo run --release --example barrett_reduction -- secp256k1
+pub fn bench_reduction<E: Curve>() {
+    let q = Scalar::<E>::group_order();
+    let pairs: Vec<(BigInt, BigInt)> = (0..1000)
+        .map(|_| (BigInt::sample_below(q), BigInt::sample_below(q)))
+        .collect();
+
+    let start = Instant::now();
+    let mut acc = BigInt::zero();
+    for (a, b) in pairs.iter().cycle().take(ITERATIONS) {
+        acc = BigInt::mod_add(&acc, &BigInt::mod_mul(a, b, q), q);
+    }
+    let plain = start.elapsed();
+
+    let ctx = ReductionContext::new(q);
+    let start = Instant::now();
+    let mut acc_ctx = BigInt::zero();
+    for (a, b) in pairs.iter().cycle().take(ITERATIONS) {
+        acc_ctx = BigInt::mod_add(&acc_ctx, &ctx.mul_mod(a, b), q);
+    }
+    let barrett = start.elapsed();
+
+    assert_eq!(acc, acc_ctx);
+    println!("{} modular multiplications:", ITERATIONS);
+    println!("  Modulo::mod_mul:             {:?}", plain);
+    println!("  ReductionContext::mul_mod:   {:?}", barrett);
+}
+
+fn main() {
+    let curve_name = std::env::args().nth(1);
+    match curve_name.as_deref() {
+        Some("secp256k1") => bench_reduction::<Secp256k1>(),
+        Some("ristretto") => bench_reduction::<Ristretto>(),
+        Some("ed25519") => bench_reduction::<Ed25519>(),
+        Some("bls12_381_1") => bench_reduction::<Bls12_381_1>(),
+        Some("bls12_381_2") => bench_reduction::<Bls12_381_2>(),
+        Some("p256") => bench_reduction::<Secp256r1>(),
+        Some(unknown_curve) => eprintln!("Unknown curve: {}", unknown_curve),
+        None => eprintln!("Missing curve name"),
+    }
+}
@@ -0,0 +1,59 @@
+use std::time::Instant;
+
+use curv::cryptographic_primitives::commitments::pedersen_commitment::PedersenTable;
+use curv::elliptic::curves::*;
+
+const COMMITMENTS: usize = 1000;
+
+/// Pedersen commitments benchmark:
+/// computes many commitments `m*G + r*H`, first naively via two scalar multiplications,
+/// then via precomputed `PedersenTable`, and prints time spent by both.
+/// TO RUN:
+/// cargo run --release --example pedersen_table -- CURVE_NAME [WINDOW]
+/// CURVE_NAME is any of the supported curves: i.e.:
+/// cargo run --release --example pedersen_table -- secp256k1 4
+pub fn bench_commitments<E: Curve>(window: usize) {
+    let g = Point::<E>::generator().to_point();
+    let h = Point::<E>::base_point2();
+    let openings: Vec<(Scalar<E>, Scalar<E>)> = (0..COMMITMENTS)
+        .map(|_| (Scalar::random(), Scalar::random()))
+        .collect();
+
+    let start = Instant::now();
+    let naive: Vec<Point<E>> = openings.iter().map(|(m, r)| &g * m + h * r).collect();
+    let naive_time = start.elapsed();
+
+    let start = Instant::now();
+    let table = PedersenTable::new(&g, h, window);
+    let precomputation_time = start.elapsed();
+
+    let start = Instant::now();
+    let tabled: Vec<Point<E>> = openings.iter().map(|(m, r)| table.commit(m, r)).collect();
+    let table_time = start.elapsed();
+
+    assert_eq!(naive, tabled);
+    println!("{} commitments:", COMMITMENTS);
+    println!("  g*m + h*r:                   {:?}", naive_time);
+    println!(
+        "  PedersenTable (window {}):   {:?} (+ {:?} precomputation)",
+        window, table_time, precomputation_time
+    );
+}
+
+fn main() {
+    let curve_name = std::env::args().nth(1);
+    let window = match std::env::args().nth(2) {
+        Some(window) => window.parse().expect("window must be a number"),
+        None => 4,
+    };
+    match curve_name.as_deref() {
+        Some("secp256k1") => bench_commitments::<Secp256k1>(window),
+        Some("ristretto") => bench_commitments::<Ristretto>(window),
+        Some("ed25519") => bench_commitments::<Ed25519>(window),
+        Some("bls12_381_1") => bench_commitments::<Bls12_381_1>(window),
+        Some("bls12_381_2") => bench_commitments::<Bls12_381_2>(window),
+        Some("p256") => bench_commitments::<Secp256r1>(window),
+        Some(unknown_curve) => eprintln!("Unknown curve: {}", unknown_curve),
+        None => eprintln!("Missing curve name"),
+    }
+}
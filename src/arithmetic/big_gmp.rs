@@ -84,6 +84,7 @@ impl Converter for BigInt {
     }
 
     fn from_hex(value: &str) -> Result<BigInt, ParseBigIntError> {
+        validate_hex(value)?;
         Mpz::from_str_radix(value, 16)
             .map(Wrap::wrap)
             .map_err(|e| ParseBigIntError {
@@ -68,6 +68,7 @@ impl Converter for BigInt {
     }
 
     fn from_hex(n: &str) -> Result<Self, ParseBigIntError> {
+        validate_hex(n)?;
         BN::parse_bytes(n.as_bytes(), 16)
             .map(Wrap::wrap)
             .ok_or(ParseBigIntError {
@@ -401,7 +401,7 @@ pub fn next_prime(n: &BigUint) -> BigUint {
     'outer: loop {
         let mut prime = 3;
         for i in 0..prime_limit {
-            moduli[i] = &res / prime;
+            moduli[i] = &res % prime;
             prime += PRIME_GAP[i];
         }
 
@@ -503,7 +503,7 @@ fn is_bit_set(x: &BigUint, i: usize) -> bool {
 fn gen_biguint_below<R: Rng>(r: &mut R, upper: &BigUint) -> BigUint {
     loop {
         let bits = upper.bits();
-        let bytes = bits.div_ceil(&8);
+        let bytes = bits.div_ceil(8);
         let mut buf = vec![0u8; bytes as usize];
         r.fill_bytes(&mut buf);
 
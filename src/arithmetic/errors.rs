@@ -13,6 +13,9 @@ pub enum ParseErrorReason {
     Gmp(gmp::mpz::ParseMpzError),
     #[cfg(feature = "num-bigint")]
     NumBigint,
+    NonCanonical,
+    Empty,
+    InvalidDigit,
 }
 
 impl fmt::Display for ParseBigIntError {
@@ -24,6 +27,27 @@ impl fmt::Display for ParseBigIntError {
             ParseErrorReason::NumBigint => {
                 write!(f, "invalid {}-based number representation", self.radix)
             }
+            ParseErrorReason::NonCanonical => {
+                write!(
+                    f,
+                    "non-canonical {}-based number representation",
+                    self.radix
+                )
+            }
+            ParseErrorReason::Empty => {
+                write!(
+                    f,
+                    "cannot parse {}-based number from empty string",
+                    self.radix
+                )
+            }
+            ParseErrorReason::InvalidDigit => {
+                write!(
+                    f,
+                    "invalid digit found in {}-based number representation",
+                    self.radix
+                )
+            }
         }
     }
 }
@@ -35,10 +59,28 @@ impl error::Error for ParseBigIntError {
             ParseErrorReason::Gmp(reason) => Some(reason),
             #[cfg(feature = "num-bigint")]
             ParseErrorReason::NumBigint => None,
+            ParseErrorReason::NonCanonical => None,
+            ParseErrorReason::Empty | ParseErrorReason::InvalidDigit => None,
         }
     }
 }
 
+/// Checks that string is an optional `-` followed by one or more hex digits
+///
+/// Backends are more lenient than that (GMP skips whitespace, num-bigint accepts `+` and `_`),
+/// so hex strings are checked before being passed to them.
+pub(super) fn validate_hex(n: &str) -> Result<(), ParseBigIntError> {
+    let digits = n.strip_prefix('-').unwrap_or(n);
+    let reason = if digits.is_empty() {
+        ParseErrorReason::Empty
+    } else if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        ParseErrorReason::InvalidDigit
+    } else {
+        return Ok(());
+    };
+    Err(ParseBigIntError { reason, radix: 16 })
+}
+
 /// Error type returned when conversion from BigInt to primitive integer type (u64, i64, etc) fails
 #[derive(Debug)]
 pub struct TryFromBigIntError {
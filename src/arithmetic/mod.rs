@@ -16,7 +16,9 @@
 
 mod errors;
 mod macros;
+mod reduction;
 mod samplable;
+pub mod serde_canonical_hex;
 pub mod traits;
 
 #[cfg(not(any(feature = "rust-gmp-kzen", feature = "num-bigint")))]
@@ -35,6 +37,7 @@ mod big_native;
 pub use big_native::BigInt;
 
 pub use errors::{ParseBigIntError, TryFromBigIntError};
+pub use reduction::ReductionContext;
 pub use traits::*;
 
 #[cfg(test)]
@@ -59,6 +62,56 @@ mod test {
         assert_eq!(n, BigInt::from(1_000_000_u32));
     }
 
+    #[test]
+    fn deserializing_from_malformed_hex() {
+        use errors::ParseErrorReason;
+
+        for h in ["", "-"] {
+            let err = BigInt::from_hex(h).unwrap_err();
+            assert!(matches!(err.reason, ParseErrorReason::Empty), "{:?}", h);
+        }
+        for h in [
+            "1g", "0x1f", "+1f", " 1f", "1f ", "1 f", "1_f", "--1f", "1f\0", "ф",
+        ] {
+            let err = BigInt::from_hex(h).unwrap_err();
+            assert!(
+                matches!(err.reason, ParseErrorReason::InvalidDigit),
+                "{:?}",
+                h
+            );
+        }
+
+        // Odd number of digits is fine
+        assert_eq!(BigInt::from_hex("f").unwrap(), BigInt::from(15));
+        assert_eq!(BigInt::from_hex("f4240").unwrap(), BigInt::from(1_000_000));
+        assert_eq!(
+            BigInt::from_hex("00F4240").unwrap(),
+            BigInt::from(1_000_000)
+        );
+        assert_eq!(BigInt::from_hex("-0").unwrap(), BigInt::zero());
+    }
+
+    #[test]
+    fn deserializing_from_canonical_hex() {
+        assert_eq!(BigInt::from_hex_canonical("a").unwrap(), BigInt::from(10));
+        assert!(BigInt::from_hex_canonical("0a").is_err());
+        assert!(BigInt::from_hex_canonical("0A").is_err());
+        assert!(BigInt::from_hex_canonical("A").is_err());
+        // All of them are accepted by non-strict parser
+        for h in ["a", "0a", "0A", "A"] {
+            assert_eq!(BigInt::from_hex(h).unwrap(), BigInt::from(10));
+        }
+
+        assert_eq!(BigInt::from_hex_canonical("0").unwrap(), BigInt::zero());
+        assert_eq!(
+            BigInt::from_hex_canonical("-1f").unwrap(),
+            BigInt::from(-31)
+        );
+        for h in ["", "00", "-0", "-01f", "+1f", " 1f", "1g"] {
+            assert!(BigInt::from_hex_canonical(h).is_err(), "{:?}", h);
+        }
+    }
+
     #[test]
     fn serializing_to_vec() {
         let n = BigInt::from(1_000_000_u32);
@@ -73,6 +126,40 @@ mod test {
         assert_eq!(n, BigInt::from(1_000_000_u32))
     }
 
+    #[test]
+    fn limbs_round_trip() {
+        // 2^130 + 2^64 + 5: bit length 131 is neither multiple of 64 nor of 16
+        let n = (BigInt::one() << 130) + (BigInt::one() << 64) + BigInt::from(5);
+        assert_eq!(n.bit_length(), 131);
+
+        let limbs = n.to_limbs(64);
+        assert_eq!(limbs, vec![5, 1, 4]);
+        assert_eq!(BigInt::from_limbs(&limbs, 64), n);
+
+        let limbs = n.to_limbs(16);
+        assert_eq!(limbs.len(), 9);
+        assert_eq!(limbs[0], 5);
+        assert_eq!(limbs[4], 1);
+        assert_eq!(limbs[8], 4);
+        assert_eq!(BigInt::from_limbs(&limbs, 16), n);
+
+        let n = BigInt::sample(1001);
+        for limb_bits in [1, 7, 16, 33, 64] {
+            let limbs = n.to_limbs(limb_bits);
+            assert!(limbs.iter().all(|l| limb_bits == 64 || l >> limb_bits == 0));
+            assert_eq!(BigInt::from_limbs(&limbs, limb_bits), n);
+        }
+
+        assert!(BigInt::zero().to_limbs(64).is_empty());
+        assert_eq!(BigInt::from_limbs(&[], 64), BigInt::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_limbs_rejects_oversized_limb() {
+        BigInt::from_limbs(&[1 << 16], 16);
+    }
+
     #[test]
     fn count_bits() {
         let mut n = BigInt::one();
@@ -264,6 +351,83 @@ mod test {
         assert_eq!(one, BigInt::one());
     }
 
+    /// Known answers computed independently of both backends, so running the test suite under
+    /// `rust-gmp-kzen` and `num-bigint` features confirms that they agree
+    #[test]
+    fn backends_agree_on_known_answers() {
+        let hex = |s: &str| BigInt::from_hex(s).unwrap();
+        let a = hex("9f3c1e5b7a2d4c6e8f0a1b2c3d4e5f60718293a4b5c6d7e8f9");
+        let p = (BigInt::one() << 255) - BigInt::from(19);
+
+        assert_eq!(a.bit_length(), 200);
+        assert_eq!(
+            hex::encode(a.to_bytes()),
+            "9f3c1e5b7a2d4c6e8f0a1b2c3d4e5f60718293a4b5c6d7e8f9"
+        );
+        assert_eq!(BigInt::from_bytes(&a.to_bytes()), a);
+        assert_eq!(
+            BigInt::mod_pow(&a, &hex("123456789abcdef0fedcba9876543210"), &p),
+            hex("6351d46b7c2f4b855a0cedfcf28097b66520a2aa76c7611aa0e4c036e54a417c")
+        );
+        assert_eq!(
+            BigInt::mod_inv(&a, &p),
+            Some(hex(
+                "372dc2091e47590a69b1d7fc750e6e6f2cbc656c56cca73e5b27ef31ac26e174"
+            ))
+        );
+        assert_eq!(
+            BigInt::mod_mul(&a, &a, &p),
+            hex("7b69575a1345459b3ed996af6054f6dbbd83144963ce69b8287c80526268246a")
+        );
+        assert_eq!(
+            &a / BigInt::from(12345),
+            hex("34d54cd3432f5149a836489889f64b515fdf030df15e52a")
+        );
+        assert_eq!(&a % BigInt::from(12345), BigInt::from(671));
+        assert_eq!(
+            BigInt::mod_sub(&BigInt::from(1), &BigInt::from(2), &BigInt::from(7)),
+            BigInt::from(6)
+        );
+        assert_eq!(BigInt::from(-5).modulus(&BigInt::from(7)), BigInt::from(2));
+
+        // Next prime must not skip any primes
+        let next_primes = [
+            (BigInt::one() << 64, 13),
+            ((BigInt::one() << 127) - BigInt::from(3), 2),
+            (BigInt::one() << 128, 51),
+            (BigInt::from(10).pow(20), 39),
+            (&p - BigInt::one(), 1),
+        ];
+        for (n, gap) in next_primes {
+            assert_eq!(n.next_prime(), &n + BigInt::from(gap));
+        }
+    }
+
+    #[test]
+    fn mod_inv_with_composite_modulus() {
+        assert_eq!(
+            BigInt::mod_inv(&BigInt::from(3), &BigInt::from(11)),
+            Some(BigInt::from(4))
+        );
+        assert_eq!(BigInt::mod_inv(&BigInt::from(2), &BigInt::from(4)), None);
+        assert_eq!(BigInt::mod_inv(&BigInt::from(6), &BigInt::from(15)), None);
+
+        let mut coprime_pairs = 0;
+        while coprime_pairs < 100 {
+            let m = BigInt::sample(256) + BigInt::from(2);
+            let a = BigInt::sample_below(&m);
+            let inv = BigInt::mod_inv(&a, &m);
+            if BigInt::egcd(&a, &m).0 != BigInt::one() {
+                assert_eq!(inv, None);
+                continue;
+            }
+            let inv = inv.unwrap();
+            assert!(BigInt::zero() <= inv && inv < m);
+            assert_eq!(BigInt::mod_mul(&a, &inv, &m), BigInt::one());
+            coprime_pairs += 1;
+        }
+    }
+
     #[test]
     #[should_panic]
     fn mod_pow_panics_if_exp_is_negative() {
@@ -311,6 +475,30 @@ mod test {
         }
     }
 
+    #[test]
+    fn probabilistically_test_composites() {
+        // Carmichael numbers, the last one is a strong pseudoprime to bases 2, 3, 5 and 7
+        let carmichael = ["561", "41041", "3215031751"];
+        for n in carmichael {
+            let n = BigInt::from_str_radix(n, 10).unwrap();
+            assert!(!n.is_probable_prime(64));
+        }
+    }
+
+    #[test]
+    fn next_prime_with_rounds() {
+        let mersenne = (BigInt::one() << 127) - BigInt::one();
+        assert!(mersenne.is_probable_prime(64));
+        assert_eq!(
+            (&mersenne - BigInt::from(2)).next_prime_with_rounds(64),
+            mersenne
+        );
+        assert_eq!(
+            BigInt::from(560).next_prime_with_rounds(64),
+            BigInt::from(563)
+        );
+    }
+
     #[test]
     fn display_bigint_returns_decimal_representation() {
         let s = BigInt::from(12345).to_string();
@@ -0,0 +1,115 @@
+use super::traits::{BitManipulation, Modulo, One, Zero};
+use super::BigInt;
+
+/// Precomputed context for repeated reductions modulo the same number
+///
+/// Uses Barrett reduction: parameter `mu = floor(4^k / m)`, where `k` is bit length of
+/// modulus `m`, is computed once, and then every reduction costs two multiplications and
+/// shifts instead of a division. Actual speedup depends on BigInt backend: GMP division is
+/// already well optimized, so measure it on your workload (see `barrett_reduction` example).
+///
+/// ## Example
+/// ```
+/// # use curv::arithmetic::*;
+/// let m = BigInt::from(1_000_003);
+/// let ctx = ReductionContext::new(&m);
+/// let (a, b) = (BigInt::from(999_999), BigInt::from(123_456));
+/// assert_eq!(ctx.mul_mod(&a, &b), BigInt::mod_mul(&a, &b, &m));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReductionContext {
+    modulus: BigInt,
+    mu: BigInt,
+    k: usize,
+}
+
+impl ReductionContext {
+    /// Precomputes Barrett parameter for given modulus
+    ///
+    /// ## Panics
+    /// Panics if `modulus <= 0`
+    pub fn new(modulus: &BigInt) -> Self {
+        assert!(*modulus > BigInt::zero(), "modulus must be positive");
+        let k = modulus.bit_length();
+        let mu = (BigInt::one() << (2 * k)) / modulus;
+        Self {
+            modulus: modulus.clone(),
+            mu,
+            k,
+        }
+    }
+
+    /// Returns the modulus
+    pub fn modulus(&self) -> &BigInt {
+        &self.modulus
+    }
+
+    /// Calculates x mod m
+    ///
+    /// Fast path applies to `0 <= x < 4^k`, that includes any product of two reduced numbers.
+    /// Other values are reduced by regular [Modulo::modulus].
+    pub fn reduce(&self, x: &BigInt) -> BigInt {
+        if *x < BigInt::zero() || x.bit_length() > 2 * self.k {
+            return x.modulus(&self.modulus);
+        }
+        let q = ((x >> (self.k - 1)) * &self.mu) >> (self.k + 1);
+        let mut r = x - q * &self.modulus;
+        // Barrett estimate of the quotient is off by at most 2
+        while r >= self.modulus {
+            r -= &self.modulus;
+        }
+        r
+    }
+
+    /// Calculates a * b mod m
+    pub fn mul_mod(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.reduce(&(a * b))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arithmetic::Samplable;
+
+    #[test]
+    fn mul_mod_matches_modulo() {
+        let moduli = [
+            BigInt::from(1),
+            BigInt::from(2),
+            BigInt::from(1_000_003),
+            BigInt::strict_sample(256),
+            BigInt::strict_sample(521),
+        ];
+        for m in &moduli {
+            let ctx = ReductionContext::new(m);
+            for _ in 0..1000 {
+                let a = BigInt::sample_below(m);
+                let b = BigInt::sample_below(m);
+                assert_eq!(ctx.mul_mod(&a, &b), BigInt::mod_mul(&a, &b, m));
+            }
+        }
+    }
+
+    #[test]
+    fn reduce_handles_values_out_of_fast_path() {
+        let m = BigInt::strict_sample(256);
+        let ctx = ReductionContext::new(&m);
+        for x in [
+            BigInt::zero(),
+            BigInt::from(-5),
+            -BigInt::sample(600),
+            BigInt::sample(600),
+            &m * &m,
+            &m * &m - BigInt::one(),
+        ] {
+            assert_eq!(ctx.reduce(&x), x.modulus(&m));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn zero_modulus_is_rejected() {
+        ReductionContext::new(&BigInt::zero());
+    }
+}
@@ -1,19 +1,12 @@
-use rand::{rngs::OsRng, RngCore};
+use rand::rngs::OsRng;
 
 use super::traits::{BitManipulation, Converter, Samplable, Zero};
 use super::BigInt;
+use crate::RandomSource;
 
 impl Samplable for BigInt {
     fn sample_below(upper: &Self) -> Self {
-        assert!(*upper > Self::zero());
-
-        let bits = upper.bit_length();
-        loop {
-            let n = Self::sample(bits);
-            if n < *upper {
-                return n;
-            }
-        }
+        Self::sample_below_with_rng(upper, &mut OsRng)
     }
 
     fn sample_range(lower: &Self, upper: &Self) -> Self {
@@ -32,14 +25,7 @@ impl Samplable for BigInt {
     }
 
     fn sample(bit_size: usize) -> Self {
-        if bit_size == 0 {
-            return BigInt::zero();
-        }
-        let mut rng = OsRng;
-        let bytes = (bit_size - 1) / 8 + 1;
-        let mut buf: Vec<u8> = vec![0; bytes];
-        rng.fill_bytes(&mut buf);
-        BigInt::from_bytes(&*buf) >> (bytes * 8 - bit_size)
+        Self::sample_with_rng(bit_size, &mut OsRng)
     }
 
     fn strict_sample(bit_size: usize) -> Self {
@@ -53,4 +39,26 @@ impl Samplable for BigInt {
             }
         }
     }
+
+    fn sample_below_with_rng<R: RandomSource + ?Sized>(upper: &Self, rng: &mut R) -> Self {
+        assert!(*upper > Self::zero());
+
+        let bits = upper.bit_length();
+        loop {
+            let n = Self::sample_with_rng(bits, rng);
+            if n < *upper {
+                return n;
+            }
+        }
+    }
+
+    fn sample_with_rng<R: RandomSource + ?Sized>(bit_size: usize, rng: &mut R) -> Self {
+        if bit_size == 0 {
+            return BigInt::zero();
+        }
+        let bytes = (bit_size - 1) / 8 + 1;
+        let mut buf: Vec<u8> = vec![0; bytes];
+        rng.fill_bytes(&mut buf);
+        BigInt::from_bytes(&*buf) >> (bytes * 8 - bit_size)
+    }
 }
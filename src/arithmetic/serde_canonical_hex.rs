@@ -0,0 +1,103 @@
+//! Strict (de)serialization of BigInt as canonical hex string
+//!
+//! With GMP backend, BigInt is serialized as hex string by default, and deserialization accepts
+//! any string [BigInt::from_hex] accepts, eg. `"a"`, `"0a"` and `"A"` all decode to 10. This
+//! module uses the same encoding, but deserialization requires
+//! [canonical form](Converter::from_hex_canonical), so every number has exactly one serialized
+//! representation. Use it when serialized form is signed or hashed. Unlike default serialization,
+//! the encoding doesn't depend on bigint backend.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use curv::BigInt;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! pub struct SignedAmount {
+//!     #[serde(with = "curv::arithmetic::serde_canonical_hex")]
+//!     pub amount: BigInt,
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserializer, Serializer};
+
+use super::traits::Converter;
+use super::BigInt;
+
+/// Serializes BigInt as canonical hex string
+pub fn serialize<S>(n: &BigInt, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&n.to_hex())
+}
+
+/// Deserializes BigInt from canonical hex string
+pub fn deserialize<'de, D>(deserializer: D) -> Result<BigInt, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct CanonicalHexVisitor;
+
+    impl<'de> Visitor<'de> for CanonicalHexVisitor {
+        type Value = BigInt;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "canonical hex string: lowercase, without leading zeroes")
+        }
+
+        fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
+        where
+            Err: Error,
+        {
+            BigInt::from_hex_canonical(v).map_err(Err::custom)
+        }
+    }
+
+    deserializer.deserialize_str(CanonicalHexVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use crate::BigInt;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Amount {
+        #[serde(with = "super")]
+        amount: BigInt,
+    }
+
+    #[test]
+    fn accepts_only_canonical_hex() {
+        let amount = Amount {
+            amount: BigInt::from(10),
+        };
+        let json = serde_json::to_string(&amount).unwrap();
+        assert_eq!(json, r#"{"amount":"a"}"#);
+        assert_eq!(serde_json::from_str::<Amount>(&json).unwrap(), amount);
+
+        for non_canonical in [
+            r#"{"amount":"0a"}"#,
+            r#"{"amount":"0A"}"#,
+            r#"{"amount":"A"}"#,
+        ] {
+            assert!(serde_json::from_str::<Amount>(non_canonical).is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rust-gmp-kzen")]
+    fn default_serialization_is_compatible() {
+        let n = BigInt::from(-1_000_000);
+        let default_json = serde_json::to_string(&n).unwrap();
+        let amount: Amount =
+            serde_json::from_str(&format!(r#"{{"amount":{}}}"#, default_json)).unwrap();
+        assert_eq!(amount.amount, n);
+    }
+}
@@ -14,7 +14,8 @@
     @license GPL-3.0+ <https://github.com/KZen-networks/cryptography-utils/blob/master/LICENSE>
 */
 
-use super::errors::ParseBigIntError;
+use super::errors::{validate_hex, ParseBigIntError, ParseErrorReason};
+use crate::RandomSource;
 
 /// Reuse common traits from [num_integer] crate
 pub use num_integer::{Integer, Roots};
@@ -71,6 +72,82 @@ pub trait Converter: Sized {
         Some(array)
     }
 
+    /// Splits the number into limbs of `limb_bits` bits each, least significant limb first.
+    ///
+    /// Sign is ignored, ie. number is split by absolute value. Zero has no limbs.
+    ///
+    /// ## Panics
+    /// Panics if `limb_bits` is not within `[1; 64]` range
+    ///
+    /// ## Examples
+    /// ```
+    /// # use curv::arithmetic::{BigInt, Converter};
+    /// assert_eq!(BigInt::from(0x1_0002_0003_u64).to_limbs(16), vec![3, 2, 1]);
+    /// assert_eq!(BigInt::from(0).to_limbs(64), Vec::<u64>::new());
+    /// ```
+    fn to_limbs(&self, limb_bits: usize) -> Vec<u64> {
+        assert!(
+            (1..=64).contains(&limb_bits),
+            "limb size must be within [1; 64] bits"
+        );
+        let mask = (1u128 << limb_bits) - 1;
+        let mut limbs = vec![];
+        let (mut acc, mut acc_bits) = (0u128, 0);
+        for byte in self.to_bytes().into_iter().rev() {
+            acc |= u128::from(byte) << acc_bits;
+            acc_bits += 8;
+            while acc_bits >= limb_bits {
+                limbs.push((acc & mask) as u64);
+                acc >>= limb_bits;
+                acc_bits -= limb_bits;
+            }
+        }
+        limbs.push(acc as u64);
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        limbs
+    }
+
+    /// Constructs BigInt from limbs of `limb_bits` bits each, least significant limb first.
+    ///
+    /// Inverse of [to_limbs](Self::to_limbs).
+    ///
+    /// ## Panics
+    /// Panics if `limb_bits` is not within `[1; 64]` range, or if some limb doesn't fit into
+    /// `limb_bits` bits
+    ///
+    /// ## Examples
+    /// ```
+    /// # use curv::arithmetic::{BigInt, Converter};
+    /// assert_eq!(BigInt::from_limbs(&[3, 2, 1], 16), BigInt::from(0x1_0002_0003_u64));
+    /// ```
+    fn from_limbs(limbs: &[u64], limb_bits: usize) -> Self {
+        assert!(
+            (1..=64).contains(&limb_bits),
+            "limb size must be within [1; 64] bits"
+        );
+        let mut bytes = vec![];
+        let (mut acc, mut acc_bits) = (0u128, 0);
+        for &limb in limbs {
+            assert!(
+                limb_bits == 64 || limb >> limb_bits == 0,
+                "limb doesn't fit into {} bits",
+                limb_bits
+            );
+            acc |= u128::from(limb) << acc_bits;
+            acc_bits += limb_bits;
+            while acc_bits >= 8 {
+                bytes.push(acc as u8);
+                acc >>= 8;
+                acc_bits -= 8;
+            }
+        }
+        bytes.push(acc as u8);
+        bytes.reverse();
+        Self::from_bytes(&bytes)
+    }
+
     /// Converts BigInt to hex representation.
     ///
     /// If the number is negative, it will be serialized by absolute value, and minus character
@@ -87,7 +164,10 @@ pub trait Converter: Sized {
     }
     /// Parses given hex string.
     ///
-    /// Follows the same format as was described in [to_hex](Self::to_hex).
+    /// Follows the same format as was described in [to_hex](Self::to_hex), both lowercase and
+    /// uppercase digits are accepted, leading zeroes are allowed. Never panics: empty string
+    /// (or a sole `-`) and any character other than hex digit (including whitespace and `+`
+    /// sign) result into error, so it's safe to use on untrusted input.
     ///
     /// ## Examples
     /// ```
@@ -96,10 +176,37 @@ pub trait Converter: Sized {
     /// assert_eq!(BigInt::from_hex("-1f").unwrap(), BigInt::from(-31));
     /// assert_eq!(BigInt::from_hex("f4240").unwrap(), BigInt::from(1_000_000));
     /// assert_eq!(BigInt::from_hex("-f4240").unwrap(), BigInt::from(-1_000_000));
+    /// assert!(BigInt::from_hex("").is_err());
+    /// assert!(BigInt::from_hex("1 f").is_err());
     /// ```
     fn from_hex(n: &str) -> Result<Self, ParseBigIntError> {
+        validate_hex(n)?;
         Self::from_str_radix(n, 16)
     }
+    /// Parses hex string in canonical form
+    ///
+    /// Unlike [from_hex](Self::from_hex), accepts exactly one representation per number, the one
+    /// produced by [to_hex](Self::to_hex): lowercase digits without leading zeroes (zero is `"0"`),
+    /// negative numbers are prefixed with `-` (`"-0"` is not allowed). Any other form is rejected,
+    /// so distinct strings never decode to the same number.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use curv::arithmetic::{BigInt, Converter};
+    /// assert_eq!(BigInt::from_hex_canonical("a").unwrap(), BigInt::from(10));
+    /// assert!(BigInt::from_hex_canonical("A").is_err());
+    /// assert!(BigInt::from_hex_canonical("0a").is_err());
+    /// ```
+    fn from_hex_canonical(n: &str) -> Result<Self, ParseBigIntError> {
+        let parsed = Self::from_hex(n)?;
+        if parsed.to_hex() != n {
+            return Err(ParseBigIntError {
+                reason: ParseErrorReason::NonCanonical,
+                radix: 16,
+            });
+        }
+        Ok(parsed)
+    }
 
     /// Converts BigInt to radix representation.
     ///
@@ -177,6 +284,13 @@ pub trait Samplable {
     fn sample(bit_size: usize) -> Self;
     /// Generates number within `[2^(bit_size-1); 2^bit_size)` range
     fn strict_sample(bit_size: usize) -> Self;
+    /// Generates random number within `[0; upper)` range using given source of randomness
+    ///
+    /// ## Panics
+    /// Panics if `upper <= 0`
+    fn sample_below_with_rng<R: RandomSource + ?Sized>(upper: &Self, rng: &mut R) -> Self;
+    /// Generates number within `[0; 2^bit_size)` range using given source of randomness
+    fn sample_with_rng<R: RandomSource + ?Sized>(bit_size: usize, rng: &mut R) -> Self;
 }
 
 /// Set of predicates allowing to examine BigInt
@@ -263,4 +377,26 @@ pub trait Primes {
     /// `is_probable_prime` probably return false. The probability of returning true for a randomly
     /// chosen non-prime is at most 4^(-reps).
     fn is_probable_prime(&self, n: u32) -> bool;
+    /// Finds next prime number that passes [is_probable_prime](Self::is_probable_prime) with given
+    /// number of rounds
+    ///
+    /// Number of Miller-Rabin rounds performed by [next_prime](Self::next_prime) is chosen by the
+    /// backend. This method lets caller pick the error probability, eg. 64 rounds for primes used
+    /// in key generation.
+    ///
+    /// ```
+    /// # use curv::arithmetic::*;
+    /// let p = BigInt::from(1_000_000).next_prime_with_rounds(64);
+    /// assert_eq!(p, BigInt::from(1_000_003));
+    /// ```
+    fn next_prime_with_rounds(&self, rounds: u32) -> Self
+    where
+        Self: Sized,
+    {
+        let mut candidate = self.next_prime();
+        while !candidate.is_probable_prime(rounds) {
+            candidate = candidate.next_prime();
+        }
+        candidate
+    }
 }
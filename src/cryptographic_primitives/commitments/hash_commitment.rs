@@ -10,11 +10,12 @@
 /// r is 256bit blinding factor, m is the commited value
 pub struct HashCommitment;
 
-use crate::BigInt;
+use crate::{BigInt, RandomSource};
 
 use super::traits::Commitment;
 use super::SECURITY_BITS;
 use crate::arithmetic::traits::*;
+use rand::rngs::OsRng;
 use sha3::{Digest, Sha3_256};
 //TODO:  using the function with BigInt's as input instead of string's makes it impossible to commit to empty message or use empty randomness
 impl Commitment<BigInt> for HashCommitment {
@@ -31,13 +32,61 @@ impl Commitment<BigInt> for HashCommitment {
     }
 
     fn create_commitment(message: &BigInt) -> (BigInt, BigInt) {
-        let blinding_factor = BigInt::sample(SECURITY_BITS);
+        HashCommitment::create_with_rng(message, &mut OsRng)
+    }
+}
+
+impl HashCommitment {
+    /// Same as [create_commitment](Commitment::create_commitment), but samples blinding factor
+    /// using given source of randomness
+    pub fn create_with_rng<R: RandomSource + ?Sized>(
+        message: &BigInt,
+        rng: &mut R,
+    ) -> (BigInt, BigInt) {
+        let blinding_factor = BigInt::sample_with_rng(SECURITY_BITS, rng);
         let com = HashCommitment::create_commitment_with_user_defined_randomness(
             message,
             &blinding_factor,
         );
         (com, blinding_factor)
     }
+
+    /// Creates commitment c = H(session_id, m, r) bound to the session
+    ///
+    /// Commitment made in one session doesn't verify under another session id, which prevents
+    /// cross-session replay. Returns commitment and blinding factor.
+    pub fn create_commitment_bound(message: &BigInt, session_id: &[u8]) -> (BigInt, BigInt) {
+        let blinding_factor = BigInt::sample(SECURITY_BITS);
+        let com = Self::commitment_bound(message, &blinding_factor, session_id);
+        (com, blinding_factor)
+    }
+
+    /// Verifies commitment created by [create_commitment_bound](Self::create_commitment_bound)
+    pub fn verify_commitment_bound(
+        commitment: &BigInt,
+        message: &BigInt,
+        blinding_factor: &BigInt,
+        session_id: &[u8],
+    ) -> bool {
+        *commitment == Self::commitment_bound(message, blinding_factor, session_id)
+    }
+
+    fn commitment_bound(message: &BigInt, blinding_factor: &BigInt, session_id: &[u8]) -> BigInt {
+        Self::hash_framed(&[session_id, &message.to_bytes(), &blinding_factor.to_bytes()])
+    }
+
+    /// Computes H(len(p_1) || p_1 || ... || len(p_n) || p_n)
+    ///
+    /// Every part is prefixed with its length, so moving bytes from one part to another changes
+    /// the hash.
+    pub(crate) fn hash_framed(parts: &[&[u8]]) -> BigInt {
+        let mut digest = Sha3_256::new();
+        for part in parts {
+            digest.update((part.len() as u64).to_be_bytes());
+            digest.update(part);
+        }
+        BigInt::from_bytes(digest.finalize().as_ref())
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +156,63 @@ mod tests {
         let hash_result = BigInt::from_bytes(digest.finalize().as_ref());
         assert_eq!(&commitment, &hash_result);
     }
+
+    #[test]
+    fn test_commitment_bound_to_session() {
+        let message = BigInt::sample(SECURITY_BITS);
+        let (commitment, blinding_factor) =
+            HashCommitment::create_commitment_bound(&message, b"session A");
+        assert!(HashCommitment::verify_commitment_bound(
+            &commitment,
+            &message,
+            &blinding_factor,
+            b"session A"
+        ));
+        assert!(!HashCommitment::verify_commitment_bound(
+            &commitment,
+            &message,
+            &blinding_factor,
+            b"session B"
+        ));
+        assert_ne!(
+            commitment,
+            HashCommitment::create_commitment_with_user_defined_randomness(
+                &message,
+                &blinding_factor
+            )
+        );
+    }
+
+    #[test]
+    fn test_commitment_bound_rejects_shifted_opening() {
+        let message = BigInt::from(0x01);
+        let blinding_factor = BigInt::from(0x0203);
+        let commitment = HashCommitment::commitment_bound(&message, &blinding_factor, b"session A");
+        assert!(!HashCommitment::verify_commitment_bound(
+            &commitment,
+            &BigInt::from(0x0102),
+            &BigInt::from(0x03),
+            b"session A"
+        ));
+    }
+
+    #[test]
+    fn test_create_with_seeded_rng_is_deterministic() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let message = BigInt::sample(SECURITY_BITS);
+        let (commitment, blinding_factor) =
+            HashCommitment::create_with_rng(&message, &mut StdRng::seed_from_u64(1));
+        assert_eq!(
+            commitment,
+            HashCommitment::create_commitment_with_user_defined_randomness(
+                &message,
+                &blinding_factor
+            )
+        );
+        assert_eq!(
+            (commitment, blinding_factor),
+            HashCommitment::create_with_rng(&message, &mut StdRng::seed_from_u64(1))
+        );
+    }
 }
@@ -5,7 +5,7 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 
-const SECURITY_BITS: usize = 256;
+pub(crate) const SECURITY_BITS: usize = 256;
 
 pub mod hash_commitment;
 pub mod pedersen_commitment;
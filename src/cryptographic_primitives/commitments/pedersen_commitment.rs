@@ -7,11 +7,13 @@
 
 use std::marker::PhantomData;
 
+use serde::{Deserialize, Serialize};
+
 use super::traits::Commitment;
 use super::SECURITY_BITS;
 use crate::arithmetic::traits::*;
 
-use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::elliptic::curves::{Curve, ECScalar, Point, Scalar};
 use crate::BigInt;
 
 /// compute c = mG + rH
@@ -43,3 +45,451 @@ impl<E: Curve> Commitment<Point<E>> for PedersenCommitment<E> {
         (com, blinding_factor)
     }
 }
+
+impl<E: Curve> PedersenCommitment<E> {
+    /// Checks that `commitment = value * g + blinding * h`
+    ///
+    /// Useful for auditing a stored opening. Note that recovering blinding factor from commitment
+    /// and value is infeasible, hence only verification is provided.
+    pub fn check_opening(
+        commitment: &Point<E>,
+        value: &BigInt,
+        blinding: &Scalar<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> bool {
+        let value_scalar: Scalar<E> = Scalar::from(value);
+        *commitment == g * value_scalar + h * blinding
+    }
+
+    /// Computes `sum a_i * C_i` for public coefficients `a_i` and commitments `C_i`
+    ///
+    /// If `C_i = v_i * g + r_i * h`, result is a commitment to `sum a_i * v_i` with blinding
+    /// factor `sum a_i * r_i`, so anyone can derive a commitment to a weighted sum of committed
+    /// values, and only the committers (together) can open it.
+    ///
+    /// ## Panics
+    /// Panics if number of coefficients differs from number of commitments.
+    pub fn linear_combination(coefficients: &[Scalar<E>], commitments: &[Point<E>]) -> Point<E> {
+        assert_eq!(
+            coefficients.len(),
+            commitments.len(),
+            "every commitment must have a coefficient"
+        );
+        coefficients
+            .iter()
+            .zip(commitments)
+            .map(|(a_i, c_i)| c_i * a_i)
+            .sum()
+    }
+
+    /// Checks a batch of openings `(commitment, value, blinding)`, see [check_opening](Self::check_opening)
+    ///
+    /// Openings are checked at once via random linear combination
+    /// `sum ρ_i (commitment_i - value_i * g - blinding_i * h) = O`. If the check fails, the batch is
+    /// bisected and each half is checked with fresh coefficients, so when few openings are bad
+    /// the number of checks is logarithmic in the batch size.
+    ///
+    /// Returns indexes of all invalid openings in ascending order.
+    pub fn batch_verify_openings(
+        openings: &[(Point<E>, BigInt, Scalar<E>)],
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> Result<(), Vec<usize>> {
+        let mut invalid = vec![];
+        Self::bisect_openings(openings, 0, g, h, &mut invalid);
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            Err(invalid)
+        }
+    }
+
+    fn bisect_openings(
+        openings: &[(Point<E>, BigInt, Scalar<E>)],
+        offset: usize,
+        g: &Point<E>,
+        h: &Point<E>,
+        invalid: &mut Vec<usize>,
+    ) {
+        if openings.is_empty() || Self::check_linear_combination(openings, g, h) {
+            return;
+        }
+        if openings.len() == 1 {
+            invalid.push(offset);
+            return;
+        }
+        let (left, right) = openings.split_at(openings.len() / 2);
+        Self::bisect_openings(left, offset, g, h, invalid);
+        Self::bisect_openings(right, offset + left.len(), g, h, invalid);
+    }
+
+    fn check_linear_combination(
+        openings: &[(Point<E>, BigInt, Scalar<E>)],
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> bool {
+        let mut commitments_sum = Point::<E>::zero();
+        let mut value_sum = Scalar::<E>::zero();
+        let mut blinding_sum = Scalar::<E>::zero();
+        for (commitment, value, blinding) in openings {
+            let rho = Scalar::<E>::random();
+            commitments_sum = commitments_sum + commitment * &rho;
+            value_sum = value_sum + Scalar::<E>::from(value) * &rho;
+            blinding_sum = blinding_sum + blinding * &rho;
+        }
+        commitments_sum == g * value_sum + h * blinding_sum
+    }
+}
+
+/// Precomputed table for computing Pedersen commitments `m*g + r*h` with fixed bases
+///
+/// Scalars are split into windows of `window` bits. For every window position `i` the table
+/// holds `a*2^(i*window)*g + b*2^(i*window)*h` for all `a, b < 2^window`, so commitment is a sum
+/// of one table entry per window, without any doublings. Table takes
+/// `ceil(bits / window) * 2^(2*window)` points, where `bits` is bit length of group order, e.g.
+/// 16384 points for 256-bit curve and window 4.
+///
+/// Whether it's faster than two scalar multiplications depends on cost of point addition in
+/// the backend: e.g. with window 4 it's several times faster for ristretto, but slower for
+/// backends that normalize every sum, like secp256k1. Measure with the `pedersen_table` example
+/// before switching to it.
+///
+/// Table lookups depend on the committed values, so [commit](Self::commit) is not constant time.
+pub struct PedersenTable<E: Curve> {
+    window: usize,
+    rows: Vec<Vec<Point<E>>>,
+}
+
+impl<E: Curve> PedersenTable<E> {
+    /// Precomputes the table for bases `g` and `h`
+    ///
+    /// ## Panics
+    /// Panics if `window` is not in range `1..=8`.
+    pub fn new(g: &Point<E>, h: &Point<E>, window: usize) -> Self {
+        assert!(
+            (1..=8).contains(&window),
+            "window must be between 1 and 8 bits"
+        );
+        let bits = Scalar::<E>::group_order().bit_length();
+        let row_size = 1usize << window;
+
+        let mut g_i = g.clone();
+        let mut h_i = h.clone();
+        let mut rows = Vec::with_capacity(bits.div_ceil(window));
+        for _ in (0..bits).step_by(window) {
+            let g_multiples = Self::multiples(&g_i, row_size);
+            let h_multiples = Self::multiples(&h_i, row_size);
+            let row = h_multiples
+                .iter()
+                .flat_map(|h_b| g_multiples.iter().map(move |g_a| g_a + h_b))
+                .collect();
+            rows.push(row);
+
+            g_i = &g_multiples[row_size - 1] + &g_i;
+            h_i = &h_multiples[row_size - 1] + &h_i;
+        }
+        PedersenTable { window, rows }
+    }
+
+    /// Computes commitment `m*g + r*h`
+    pub fn commit(&self, m: &Scalar<E>, r: &Scalar<E>) -> Point<E> {
+        let m_bits = m.as_raw().to_bits();
+        let r_bits = r.as_raw().to_bits();
+        let digit = |bits: &[bool]| {
+            bits.iter()
+                .rev()
+                .fold(0usize, |acc, &bit| (acc << 1) | usize::from(bit))
+        };
+        m_bits
+            .chunks(self.window)
+            .zip(r_bits.chunks(self.window))
+            .zip(&self.rows)
+            .fold(Point::zero(), |acc, ((m_i, r_i), row)| {
+                acc + &row[digit(m_i) + (digit(r_i) << self.window)]
+            })
+    }
+
+    /// Returns `[0, p, 2p, ..., (n-1)p]`
+    fn multiples(p: &Point<E>, n: usize) -> Vec<Point<E>> {
+        let mut multiples = Vec::with_capacity(n);
+        multiples.push(Point::zero());
+        for i in 1..n {
+            let next = &multiples[i - 1] + p;
+            multiples.push(next);
+        }
+        multiples
+    }
+}
+
+const VECTOR_GENERATORS_LABEL: &[u8] = b"curv.vector_pedersen_commitment";
+
+/// Vector Pedersen commitment `r*h + v_0*g_0 + ... + v_(n-1)*g_(n-1)` that can be extended by
+/// appending values
+///
+/// Generator `g_i` of position `i` is derived by [Point::derive_generator] from a fixed label, it
+/// doesn't depend on length of the vector, so appending a value costs two scalar multiplications
+/// regardless of how many values have already been committed. `h` is
+/// [base_point2](Point::base_point2). Every appended value comes with its own blinding factor,
+/// and the commitment is opened with their sum.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VectorCommitment<E: Curve> {
+    commitment: Point<E>,
+    len: usize,
+}
+
+impl<E: Curve> VectorCommitment<E> {
+    /// Commitment to empty vector
+    pub fn new() -> Self {
+        VectorCommitment {
+            commitment: Point::zero(),
+            len: 0,
+        }
+    }
+
+    /// Generator `g_i` of position `i`
+    pub fn generator(i: usize) -> Point<E> {
+        Point::derive_generator(VECTOR_GENERATORS_LABEL, i as u64)
+    }
+
+    /// Commits to all `values` at once with blinding factor `blinding`
+    pub fn create_vector_commitment(values: &[Scalar<E>], blinding: &Scalar<E>) -> Point<E> {
+        Point::derive_generators(VECTOR_GENERATORS_LABEL, values.len())
+            .iter()
+            .zip(values)
+            .fold(Point::base_point2() * blinding, |acc, (g_i, v_i)| {
+                acc + g_i * v_i
+            })
+    }
+
+    /// Appends `value` to the vector and adds `blinding * h` to the blinding term, returns
+    /// updated commitment
+    pub fn append(&mut self, value: &Scalar<E>, blinding: &Scalar<E>) -> &Point<E> {
+        let g_i = Self::generator(self.len);
+        self.commitment = &self.commitment + g_i * value + Point::base_point2() * blinding;
+        self.len += 1;
+        &self.commitment
+    }
+
+    /// Commitment to the values appended so far
+    pub fn current(&self) -> &Point<E> {
+        &self.commitment
+    }
+
+    /// Number of appended values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<E: Curve> Default for VectorCommitment<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const GENERATORS_LABEL: &[u8] = b"curv.pedersen_generators";
+
+/// Pedersen commitment key `(g, h)` derived from a public seed
+///
+/// Binding property of Pedersen commitments relies on discrete log of `h` with respect to `g`
+/// being unknown. Here `g` is the standard [generator](Point::generator), and `h` is obtained by
+/// hashing the seed to the curve (see [Point::derive_generators]): SHA-512 digests of the
+/// domain label, the seed and a counter are decoded as point encodings until one of them is a
+/// point of group order. Nobody picks `h`, it's an output of the hash function, so nobody knows its
+/// discrete log (in the random oracle model).
+///
+/// To verify that published `(g, h)` has no trapdoor, take the published seed and check that
+/// [verify](Self::verify) returns `true`, ie. that [from_seed](Self::from_seed) yields exactly the
+/// same points. The seed should be chosen in a way that party deriving generators couldn't
+/// grind it, eg. a public randomness beacon or a block hash.
+pub struct PedersenGenerators<E: Curve>(PhantomData<E>);
+
+impl<E: Curve> PedersenGenerators<E> {
+    /// Derives commitment key `(g, h)` from the seed
+    pub fn from_seed(seed: &[u8]) -> (Point<E>, Point<E>) {
+        let label = [GENERATORS_LABEL, seed].concat();
+        let h = Point::derive_generators(&label, 1)
+            .pop()
+            .expect("exactly one generator is derived");
+        (Point::generator().to_point(), h)
+    }
+
+    /// Checks that `(g, h)` is derived from the seed by [from_seed](Self::from_seed)
+    pub fn verify(seed: &[u8], g: &Point<E>, h: &Point<E>) -> bool {
+        let (expected_g, expected_h) = Self::from_seed(seed);
+        *g == expected_g && *h == expected_h
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_check_opening);
+    fn test_check_opening<E: Curve>() {
+        let value = BigInt::sample(SECURITY_BITS);
+        let blinding = Scalar::<E>::random();
+        let commitment = PedersenCommitment::<E>::create_commitment_with_user_defined_randomness(
+            &value,
+            &blinding.to_bigint(),
+        );
+        let g = Point::generator().to_point();
+        let h = Point::base_point2();
+
+        assert!(PedersenCommitment::check_opening(
+            &commitment,
+            &value,
+            &blinding,
+            &g,
+            h
+        ));
+
+        let tampered_blinding = blinding + Scalar::from(1);
+        assert!(!PedersenCommitment::check_opening(
+            &commitment,
+            &value,
+            &tampered_blinding,
+            &g,
+            h
+        ));
+    }
+
+    test_for_all_curves!(test_batch_verify_openings);
+    fn test_batch_verify_openings<E: Curve>() {
+        let g = Point::generator().to_point();
+        let h = Point::base_point2();
+        let mut openings: Vec<(Point<E>, BigInt, Scalar<E>)> = (0..64)
+            .map(|_| {
+                let value = BigInt::sample(SECURITY_BITS);
+                let blinding = Scalar::<E>::random();
+                let commitment = &g * Scalar::from(&value) + h * &blinding;
+                (commitment, value, blinding)
+            })
+            .collect();
+
+        assert_eq!(
+            PedersenCommitment::batch_verify_openings(&openings, &g, h),
+            Ok(())
+        );
+
+        openings[17].2 = &openings[17].2 + Scalar::from(1);
+        assert_eq!(
+            PedersenCommitment::batch_verify_openings(&openings, &g, h),
+            Err(vec![17])
+        );
+
+        openings[0].1 += BigInt::one();
+        openings[63].0 = &openings[63].0 + &g;
+        assert_eq!(
+            PedersenCommitment::batch_verify_openings(&openings, &g, h),
+            Err(vec![0, 17, 63])
+        );
+    }
+
+    test_for_all_curves!(test_pedersen_table);
+    fn test_pedersen_table<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2();
+        let table = PedersenTable::new(&g, h, 2);
+        for _ in 0..5 {
+            let m = Scalar::<E>::random();
+            let r = Scalar::<E>::random();
+            assert_eq!(table.commit(&m, &r), &g * &m + h * &r);
+        }
+        assert!(table.commit(&Scalar::zero(), &Scalar::zero()).is_zero());
+        let minus_one = -Scalar::<E>::from(1);
+        assert_eq!(table.commit(&minus_one, &Scalar::from(1)), h - &g);
+    }
+
+    test_for_all_curves!(test_linear_combination);
+    fn test_linear_combination<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2();
+        let values: Vec<Scalar<E>> = (0..4).map(|_| Scalar::random()).collect();
+        let blindings: Vec<Scalar<E>> = (0..4).map(|_| Scalar::random()).collect();
+        let coefficients: Vec<Scalar<E>> = (0..4).map(|_| Scalar::random()).collect();
+        let commitments: Vec<Point<E>> = values
+            .iter()
+            .zip(&blindings)
+            .map(|(v, r)| &g * v + h * r)
+            .collect();
+
+        let weighted = |xs: &[Scalar<E>]| -> Scalar<E> {
+            coefficients.iter().zip(xs).map(|(a, x)| a * x).sum()
+        };
+        let combined = PedersenCommitment::linear_combination(&coefficients, &commitments);
+        assert!(PedersenCommitment::check_opening(
+            &combined,
+            &weighted(&values).to_bigint(),
+            &weighted(&blindings),
+            &g,
+            h
+        ));
+        assert!(PedersenCommitment::<E>::linear_combination(&[], &[]).is_zero());
+    }
+
+    test_for_all_curves!(test_vector_commitment_append);
+    fn test_vector_commitment_append<E: Curve>() {
+        let values: Vec<Scalar<E>> = (0..5).map(|_| Scalar::random()).collect();
+        let blindings: Vec<Scalar<E>> = (0..5).map(|_| Scalar::random()).collect();
+
+        let mut commitment = VectorCommitment::<E>::new();
+        assert!(commitment.is_empty());
+        assert!(commitment.current().is_zero());
+        for (i, (v, r)) in values.iter().zip(&blindings).enumerate() {
+            let updated = commitment.append(v, r).clone();
+            let blinding: Scalar<E> = blindings[..=i].iter().sum();
+            assert_eq!(
+                updated,
+                VectorCommitment::create_vector_commitment(&values[..=i], &blinding)
+            );
+        }
+        assert_eq!(commitment.len(), 5);
+
+        let blinding: Scalar<E> = blindings.iter().sum();
+        assert_eq!(
+            *commitment.current(),
+            VectorCommitment::create_vector_commitment(&values, &blinding)
+        );
+        // Commitment is bound to positions of the values
+        let mut swapped = values.clone();
+        swapped.swap(0, 1);
+        assert_ne!(
+            *commitment.current(),
+            VectorCommitment::create_vector_commitment(&swapped, &blinding)
+        );
+    }
+
+    test_for_all_curves!(test_generators_from_seed);
+    fn test_generators_from_seed<E: Curve>() {
+        use crate::elliptic::curves::ECPoint;
+
+        let (g, h) = PedersenGenerators::<E>::from_seed(b"beacon round 42");
+        assert_eq!(
+            (g.clone(), h.clone()),
+            PedersenGenerators::from_seed(b"beacon round 42")
+        );
+        assert_eq!(g, Point::generator().to_point());
+        assert_ne!(g, h);
+        assert_ne!(&h, Point::base_point2());
+        // On curve and of group order
+        assert!(h.as_raw().is_valid_point().is_ok());
+
+        assert!(PedersenGenerators::verify(b"beacon round 42", &g, &h));
+        let (_, other_h) = PedersenGenerators::<E>::from_seed(b"beacon round 43");
+        assert_ne!(h, other_h);
+        assert!(!PedersenGenerators::verify(
+            b"beacon round 42",
+            &g,
+            &other_h
+        ));
+        assert!(!PedersenGenerators::verify(b"beacon round 42", &h, &g));
+    }
+}
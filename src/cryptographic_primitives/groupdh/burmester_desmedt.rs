@@ -0,0 +1,76 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! n parties P_0, ..., P_{n-1} are arranged in a ring, indices are taken modulo n.
+//! In the first round party i chooses at random a secret "r_i" and broadcasts z_i = r_iG.
+//! In the second round party i broadcasts X_i = r_i(z_{i+1} - z_{i-1}).
+//! Every party computes the group key:
+//! K = n r_i z_{i-1} + (n-1)X_i + (n-2)X_{i+1} + ... + X_{i+n-2} = (r_0r_1 + r_1r_2 + ... + r_{n-1}r_0)G
+
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+/// Computes first round message z_i = r_iG
+pub fn round1_broadcast<E: Curve>(secret: &Scalar<E>) -> Point<E> {
+    Point::generator() * secret
+}
+
+/// Computes second round message X_i = r_i(z_{i+1} - z_{i-1})
+pub fn round2<E: Curve>(
+    secret: &Scalar<E>,
+    left_neighbor: &Point<E>,
+    right_neighbor: &Point<E>,
+) -> Point<E> {
+    (right_neighbor - left_neighbor) * secret
+}
+
+/// Computes group key given all second round messages ordered by party index
+///
+/// `left_neighbor` is first round message of party `party_index - 1 (mod n)`.
+///
+/// ## Panics
+/// Panics if `party_index` is out of bounds of `contributions`.
+pub fn compute_group_key<E: Curve>(
+    secret: &Scalar<E>,
+    left_neighbor: &Point<E>,
+    party_index: usize,
+    contributions: &[Point<E>],
+) -> Point<E> {
+    let n = contributions.len();
+    assert!(party_index < n);
+
+    let key = left_neighbor * (Scalar::from(n as u64) * secret);
+    (0..n - 1).fold(key, |key, j| {
+        let x_j = &contributions[(party_index + j) % n];
+        key + x_j * Scalar::from((n - 1 - j) as u64)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_burmester_desmedt_four_parties);
+    fn test_burmester_desmedt_four_parties<E: Curve>() {
+        let n = 4;
+        let secrets: Vec<Scalar<E>> = (0..n).map(|_| Scalar::random()).collect();
+        let z: Vec<Point<E>> = secrets.iter().map(round1_broadcast).collect();
+        let x: Vec<Point<E>> = (0..n)
+            .map(|i| round2(&secrets[i], &z[(i + n - 1) % n], &z[(i + 1) % n]))
+            .collect();
+
+        let keys: Vec<Point<E>> = (0..n)
+            .map(|i| compute_group_key(&secrets[i], &z[(i + n - 1) % n], i, &x))
+            .collect();
+
+        let expected: Scalar<E> = (0..n).map(|i| &secrets[i] * &secrets[(i + 1) % n]).sum();
+        let expected = Point::generator() * expected;
+        for key in keys {
+            assert_eq!(key, expected);
+        }
+    }
+}
@@ -0,0 +1,13 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+///This is an implementation of the Burmester-Desmedt group key agreement.
+/// n parties arranged in a ring, party i private key is "r_i",
+/// The shared secret is K = (r_1 r_2 + r_2 r_3 + ... + r_n r_1)G
+/// reference: M. Burmester, Y. Desmedt. A secure and efficient conference key distribution
+/// system. EUROCRYPT 1994.
+pub mod burmester_desmedt;
@@ -0,0 +1,102 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Bitcoin-style `HASH160 = RIPEMD160(SHA256(data))` and Base58Check encoding, used for deriving
+//! short public key fingerprints (see [Point::fingerprint](crate::elliptic::curves::Point::fingerprint))
+
+use crypto::digest::Digest as _;
+use crypto::ripemd160::Ripemd160;
+use sha2::{Digest, Sha256};
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Computes `RIPEMD160(SHA256(data))`
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    ripemd160(&Sha256::digest(data))
+}
+
+/// Encodes `version || payload || checksum` in Base58, where checksum is the first 4 bytes of
+/// `SHA256(SHA256(version || payload))`
+///
+/// Each leading zero byte is encoded as `1`, eg. addresses with version `0x00` start with `1`.
+pub fn base58check_encode(version: u8, payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(1 + payload.len() + 4);
+    data.push(version);
+    data.extend_from_slice(payload);
+    let checksum = Sha256::digest(&Sha256::digest(&data));
+    data.extend_from_slice(&checksum[..4]);
+
+    // Base58 digits, least significant first
+    let mut digits: Vec<u8> = vec![];
+    for &byte in &data {
+        let mut carry = u32::from(byte);
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let leading_zeroes = data.iter().take_while(|&&b| b == 0).count();
+    let mut encoded = "1".repeat(leading_zeroes);
+    encoded.extend(
+        digits
+            .iter()
+            .rev()
+            .map(|&d| char::from(BASE58_ALPHABET[usize::from(d)])),
+    );
+    encoded
+}
+
+/// Computes RIPEMD-160 digest
+pub fn ripemd160(data: &[u8]) -> [u8; 20] {
+    let mut hasher = Ripemd160::new();
+    hasher.input(data);
+    let mut digest = [0u8; 20];
+    hasher.result(&mut digest);
+    digest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ripemd160_test_vectors() {
+        let vectors: &[(&[u8], &str)] = &[
+            (b"", "9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+            (b"a", "0bdc9d2d256b3ee9daae347be6f4dc835a467ffe"),
+            (b"abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+            (
+                b"abcdefghijklmnopqrstuvwxyz",
+                "f71c27109c692c1b56bbdceb5b9d2865b3708dbc",
+            ),
+        ];
+        for (message, digest) in vectors {
+            assert_eq!(hex::encode(ripemd160(message)), *digest);
+        }
+        assert_eq!(
+            hex::encode(ripemd160("a".repeat(1_000_000).as_bytes())),
+            "52783243c1697bdbe16d37f97f68f08325dc1528"
+        );
+    }
+
+    #[test]
+    fn base58check_test_vectors() {
+        let hash = hex::decode("751e76e8199196d454941c45d1b3a323f1433bd6").unwrap();
+        assert_eq!(
+            base58check_encode(0, &hash),
+            "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+        );
+        assert_eq!(base58check_encode(0, &[]), "1Wh4bh");
+        assert_eq!(base58check_encode(0, &[0, 0, 1]), "111E1CgqW");
+    }
+}
@@ -0,0 +1,64 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use sha3::Keccak256;
+
+use super::{Digest, DigestExt};
+use crate::BigInt;
+
+/// Keccak-256 hash as used by Ethereum (`keccak256`)
+///
+/// Note that it differs from standardized SHA3-256 in padding, so digests don't match. For generic
+/// code prefer using [DigestExt] with `sha3::Keccak256` directly.
+pub struct HKeccak256;
+
+impl HKeccak256 {
+    /// Hashes big-endian encodings of given integers
+    pub fn create_hash(big_ints: &[&BigInt]) -> BigInt {
+        big_ints
+            .iter()
+            .fold(Keccak256::new(), |hasher, n| hasher.chain_bigint(n))
+            .result_bigint()
+    }
+
+    /// Hashes given bytes
+    pub fn create_hash_from_slice(byte_slice: &[u8]) -> BigInt {
+        Keccak256::new().chain(byte_slice).result_bigint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HKeccak256;
+    use crate::arithmetic::*;
+
+    #[test]
+    fn vector_keccak256_test() {
+        // Empty message
+        let result = HKeccak256::create_hash(&[]);
+        assert_eq!(
+            result.to_hex(),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        let result = HKeccak256::create_hash_from_slice(b"");
+        assert_eq!(
+            result.to_hex(),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+
+        let result = HKeccak256::create_hash_from_slice(b"hello");
+        assert_eq!(
+            result.to_hex(),
+            "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+        );
+        let result = HKeccak256::create_hash(&[&BigInt::from_bytes(b"hello")]);
+        assert_eq!(
+            result.to_hex(),
+            "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8"
+        );
+    }
+}
@@ -0,0 +1,263 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use crate::arithmetic::traits::*;
+use crate::elliptic::curves::bls12_381::scalar::FieldScalar;
+use crate::elliptic::curves::{Curve, ECScalar, Scalar};
+use crate::BigInt;
+
+/// Poseidon hash over BLS12-381 scalar field
+///
+/// Implements Poseidon from: Lorenzo Grassi, Dmitry Khovratovich, Christian Rechberger, Arnab Roy,
+/// and Markus Schofnegger. Poseidon: A New Hash Function for Zero-Knowledge Proof Systems.
+/// In USENIX Security 2021. <https://eprint.iacr.org/2019/458.pdf>
+///
+/// Parameters are the standard ones for 128 bits security: S-box `x^5`, `R_F = 8` full rounds, and
+/// `R_P` partial rounds depending on state width `t = inputs + 1` (56, 57, 56, 60 for `t` from 2 to
+/// 5). Round constants and MDS matrix are generated by Grain LFSR as in reference implementation,
+/// except that the reference script also checks the MDS matrix for invariant subspaces (its
+/// Algorithms 1-3) and samples a new one if the checks fail. These checks are not implemented:
+/// the first sampled matrix is always used. For 2 and 4 inputs the parameters are checked against
+/// test vectors of the reference implementation, so only these input counts are supported (see
+/// [SUPPORTED_INPUTS]).
+///
+/// Hash of `m_1, ..., m_k` is the first element of the permutation of state `[0, m_1, ..., m_k]`.
+pub struct HPoseidon;
+
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: [usize; 4] = [56, 57, 56, 60];
+/// Maximum number of inputs that can be hashed at once
+pub const MAX_INPUTS: usize = PARTIAL_ROUNDS.len();
+/// Numbers of inputs whose parameters match the reference implementation
+pub const SUPPORTED_INPUTS: [usize; 2] = [2, 4];
+
+struct PoseidonParams {
+    round_constants: Vec<FieldScalar>,
+    mds: Vec<Vec<FieldScalar>>,
+}
+
+lazy_static::lazy_static! {
+    static ref PARAMS: Vec<PoseidonParams> = SUPPORTED_INPUTS
+        .iter()
+        .map(|&k| PoseidonParams::generate(k + 1, FULL_ROUNDS, PARTIAL_ROUNDS[k - 1]))
+        .collect();
+}
+
+impl HPoseidon {
+    /// Hashes [SUPPORTED_INPUTS] number of scalars
+    ///
+    /// ## Panics
+    /// Panics if number of `inputs` is not in [SUPPORTED_INPUTS]
+    pub fn hash_scalars<E>(inputs: &[Scalar<E>]) -> Scalar<E>
+    where
+        E: Curve<Scalar = FieldScalar>,
+    {
+        let params = match SUPPORTED_INPUTS.iter().position(|&k| k == inputs.len()) {
+            Some(i) => &PARAMS[i],
+            None => panic!(
+                "Poseidon hash supports {:?} inputs, got {}",
+                SUPPORTED_INPUTS,
+                inputs.len()
+            ),
+        };
+        let mut state = Vec::with_capacity(inputs.len() + 1);
+        state.push(FieldScalar::zero());
+        state.extend(inputs.iter().map(|s| s.as_raw().clone()));
+        permute(params, &mut state);
+        Scalar::from_raw(state.swap_remove(0))
+    }
+}
+
+fn permute(params: &PoseidonParams, state: &mut Vec<FieldScalar>) {
+    let t = state.len();
+    let partial_rounds = params.round_constants.len() / t - FULL_ROUNDS;
+    for (r, constants) in params.round_constants.chunks(t).enumerate() {
+        for (s, c) in state.iter_mut().zip(constants) {
+            *s = s.add(c);
+        }
+        if r < FULL_ROUNDS / 2 || r >= FULL_ROUNDS / 2 + partial_rounds {
+            state.iter_mut().for_each(|s| *s = sbox(s));
+        } else {
+            state[0] = sbox(&state[0]);
+        }
+        *state = params
+            .mds
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(state.iter())
+                    .fold(FieldScalar::zero(), |acc, (m, s)| acc.add(&m.mul(s)))
+            })
+            .collect();
+    }
+}
+
+fn sbox(x: &FieldScalar) -> FieldScalar {
+    let x2 = x.mul(x);
+    let x4 = x2.mul(&x2);
+    x4.mul(x)
+}
+
+impl PoseidonParams {
+    fn generate(t: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let modulus = FieldScalar::group_order();
+        let n = modulus.bit_length();
+        let mut grain = Grain::new(n, t, full_rounds, partial_rounds);
+
+        let mut round_constants = Vec::with_capacity((full_rounds + partial_rounds) * t);
+        while round_constants.len() < (full_rounds + partial_rounds) * t {
+            let c = grain.next_bigint(n);
+            if &c < modulus {
+                round_constants.push(FieldScalar::from_bigint(&c));
+            }
+        }
+
+        // Cauchy matrix M[i][j] = 1 / (x_i + y_j) for distinct x_1..x_t, y_1..y_t
+        let xy = loop {
+            let xy: Vec<BigInt> = (0..2 * t)
+                .map(|_| grain.next_bigint(n).modulus(modulus))
+                .collect();
+            let all_distinct = xy
+                .iter()
+                .enumerate()
+                .all(|(i, a)| xy[i + 1..].iter().all(|b| a != b));
+            if all_distinct {
+                break xy;
+            }
+        };
+        let (xs, ys) = xy.split_at(t);
+        let mds = xs
+            .iter()
+            .map(|x| {
+                ys.iter()
+                    .map(|y| {
+                        let m = BigInt::mod_inv(&BigInt::mod_add(x, y, modulus), modulus)
+                            .expect("x_i + y_j is nonzero");
+                        FieldScalar::from_bigint(&m)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        PoseidonParams {
+            round_constants,
+            mds,
+        }
+    }
+}
+
+/// Grain LFSR in self-shrinking mode, used to generate Poseidon parameters
+struct Grain {
+    state: [bool; 80],
+}
+
+impl Grain {
+    fn new(n: usize, t: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let mut bits = Vec::with_capacity(80);
+        let mut append = |value: usize, len: usize| {
+            bits.extend((0..len).rev().map(|i| (value >> i) & 1 == 1));
+        };
+        // field: prime field
+        append(1, 2);
+        // S-box: x^alpha
+        append(0, 4);
+        append(n, 12);
+        append(t, 12);
+        append(full_rounds, 10);
+        append(partial_rounds, 10);
+        append((1 << 30) - 1, 30);
+
+        let mut state = [false; 80];
+        state.copy_from_slice(&bits);
+        let mut grain = Grain { state };
+        for _ in 0..160 {
+            grain.step();
+        }
+        grain
+    }
+
+    fn step(&mut self) -> bool {
+        let s = &self.state;
+        let bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.rotate_left(1);
+        self.state[79] = bit;
+        bit
+    }
+
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.step();
+            let bit = self.step();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    fn next_bigint(&mut self, bits: usize) -> BigInt {
+        let mut n = BigInt::zero();
+        for _ in 0..bits {
+            n = (n << 1) + BigInt::from(self.next_bit() as u16);
+        }
+        n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HPoseidon;
+    use crate::arithmetic::*;
+    use crate::elliptic::curves::{Bls12_381_1, Bls12_381_2, Scalar};
+
+    fn scalars(values: &[u16]) -> Vec<Scalar<Bls12_381_1>> {
+        values.iter().map(|v| Scalar::from(*v)).collect()
+    }
+
+    #[test]
+    // Test vectors taken from reference implementation:
+    // https://extgit.iaik.tugraz.at/krypto/hadeshash (poseidonperm_x5_255_3, poseidonperm_x5_255_5)
+    fn vector_poseidon_test() {
+        // 2 inputs: permutation of [0, 1, 2]
+        let result = HPoseidon::hash_scalars(&scalars(&[1, 2]));
+        assert_eq!(
+            result.to_bigint().to_hex(),
+            "28ce19420fc246a05553ad1e8c98f5c9d67166be2c18e9e4cb4b4e317dd2a78a"
+        );
+
+        // 4 inputs: permutation of [0, 1, 2, 3, 4]
+        let result = HPoseidon::hash_scalars(&scalars(&[1, 2, 3, 4]));
+        assert_eq!(
+            result.to_bigint().to_hex(),
+            "2a918b9c9f9bd7bb509331c81e297b5707f6fc7393dcee1b13901a0b22202e18"
+        );
+    }
+
+    #[test]
+    fn poseidon_is_the_same_for_g1_and_g2_scalars() {
+        let inputs = scalars(&[3, 5]);
+        let inputs_2: Vec<Scalar<Bls12_381_2>> = inputs
+            .iter()
+            .map(|s| Scalar::from_bigint(&s.to_bigint()))
+            .collect();
+        assert_eq!(
+            HPoseidon::hash_scalars(&inputs).to_bigint(),
+            HPoseidon::hash_scalars(&inputs_2).to_bigint()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn poseidon_rejects_too_many_inputs() {
+        HPoseidon::hash_scalars(&scalars(&[1, 2, 3, 4, 5]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn poseidon_rejects_unverified_number_of_inputs() {
+        HPoseidon::hash_scalars(&scalars(&[1, 2, 3]));
+    }
+}
@@ -8,11 +8,14 @@
 // enabled via feature since it uses rust-crypto.
 
 use std::marker::PhantomData;
+use std::mem;
 
 use crypto::sha3::Sha3;
-use merkle::{MerkleTree, Proof};
+use merkle::{HashUtils, MerkleTree, Proof};
 
-use crate::elliptic::curves::{Curve, Point};
+use crate::cryptographic_primitives::commitments::pedersen_commitment::PedersenCommitment;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::BigInt;
 /*
 pub struct MT256<'a> {
     tree: MerkleTree<GE>,
@@ -28,14 +31,7 @@ pub struct MT256<E: Curve> {
 impl<E: Curve> MT256<E> {
     pub fn create_tree(vec: &[Point<E>]) -> MT256<E> {
         let digest = Sha3::keccak256();
-        let vec_bytes = (0..vec.len())
-            .map(|i| {
-                let mut array = [0u8; 32];
-                let bytes = vec[i].to_bytes(false);
-                array.copy_from_slice(&bytes[0..32]);
-                array
-            })
-            .collect::<Vec<[u8; 32]>>();
+        let vec_bytes = vec.iter().map(leaf).collect::<Vec<[u8; 32]>>();
         let tree = MerkleTree::from_vec::<[u8; 32]>(digest, vec_bytes);
 
         MT256 {
@@ -45,10 +41,7 @@ impl<E: Curve> MT256<E> {
     }
 
     pub fn gen_proof_for_ge(&self, value: &Point<E>) -> Proof<[u8; 32]> {
-        let mut array = [0u8; 32];
-        let pk_slice = value.to_bytes(false);
-        array.copy_from_slice(&pk_slice[0..32]);
-        MerkleTree::gen_proof::<[u8; 32]>(&self.tree, array).expect("not found in tree")
+        MerkleTree::gen_proof::<[u8; 32]>(&self.tree, leaf(value)).expect("not found in tree")
     }
 
     pub fn get_root(&self) -> &Vec<u8> {
@@ -63,12 +56,76 @@ impl<E: Curve> MT256<E> {
             Err(())
         }
     }
+
+    /// Checks that `proof` proves that `commitment` is the `index`-th leaf of the tree with given
+    /// `root`, and that `(value, blinding)` opens it (see [PedersenCommitment::check_opening])
+    ///
+    /// Proof doesn't carry position of the leaf, so it's read from the path: going right at steps
+    /// `b_1, ..., b_d` (from the root) leads to the leaf number `b_1 ... b_d` in binary. This is
+    /// the index of the leaf when number of leaves is a power of two. In other trees `merkle`
+    /// promotes the unpaired node of an odd level, so the last leaves are reached by shorter
+    /// paths and their position differs from their index.
+    #[allow(clippy::too_many_arguments)]
+    pub fn verify_committed_leaf(
+        root: &[u8],
+        index: usize,
+        proof: &Proof<[u8; 32]>,
+        commitment: &Point<E>,
+        value: &BigInt,
+        blinding: &Scalar<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> bool {
+        if proof.root_hash != root
+            || proof.value != leaf(commitment)
+            || MT256::<E>::validate_proof(proof, root).is_err()
+        {
+            return false;
+        }
+
+        // `merkle` doesn't export type of the sibling position, so a left sibling is recognized
+        // by its discriminant, taken from the proof of the right leaf of a two-leaf tree
+        let left_sibling = {
+            let tree = MerkleTree::from_vec::<[u8; 32]>(Sha3::keccak256(), vec![[0; 32], [1; 32]]);
+            let proof =
+                MerkleTree::gen_proof::<[u8; 32]>(&tree, [1; 32]).expect("leaf is in the tree");
+            proof.lemma.sibling_hash.as_ref().map(mem::discriminant)
+        };
+
+        let mut lemma = &proof.lemma;
+        let mut position = 0usize;
+        while let Some(sub_lemma) = &lemma.sub_lemma {
+            let went_right = lemma.sibling_hash.as_ref().map(mem::discriminant) == left_sibling;
+            position = match position.checked_mul(2) {
+                Some(position) => position | usize::from(went_right),
+                None => return false,
+            };
+            lemma = sub_lemma;
+        }
+        // `validate_proof` doesn't check that the path ends at the hash of `proof.value`
+        let leaf_hash: [u8; 32] = Sha3::keccak256().hash_leaf(&proof.value);
+
+        lemma.node_hash == leaf_hash
+            && position == index
+            && PedersenCommitment::check_opening(commitment, value, blinding, g, h)
+    }
+}
+
+/// Leaf of the tree: first 32 bytes of uncompressed encoding of the point
+fn leaf<E: Curve>(point: &Point<E>) -> [u8; 32] {
+    let mut array = [0u8; 32];
+    array.copy_from_slice(&point.to_bytes(false)[0..32]);
+    array
 }
 
 #[cfg(test)]
 mod tests {
-    use super::MT256;
-    use crate::elliptic::curves::{Curve, Point};
+    use crypto::sha3::Sha3;
+    use merkle::{HashUtils, MerkleTree};
+
+    use super::{leaf, MT256};
+    use crate::elliptic::curves::{Curve, Point, Scalar};
+    use crate::BigInt;
 
     use crate::test_for_all_curves;
 
@@ -100,4 +157,100 @@ mod tests {
         let root = mt256.get_root();
         assert!(MT256::<E>::validate_proof(&proof1, root).is_ok());
     }
+
+    test_for_all_curves!(test_verify_committed_leaf);
+
+    fn test_verify_committed_leaf<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let openings: Vec<(BigInt, Scalar<E>)> = (0..8)
+            .map(|i| (BigInt::from(i), Scalar::random()))
+            .collect();
+        let commitments: Vec<Point<E>> = openings
+            .iter()
+            .map(|(v, r)| &g * Scalar::from(v) + &h * r)
+            .collect();
+        let mt256 = MT256::create_tree(&commitments);
+        let proof = mt256.gen_proof_for_ge(&commitments[5]);
+        // Verifier knows only the root
+        let root = mt256.get_root().clone();
+        drop(mt256);
+
+        let (value, blinding) = &openings[5];
+        let verify = |index, commitment: &Point<E>, value: &BigInt, blinding: &Scalar<E>| {
+            MT256::verify_committed_leaf(&root, index, &proof, commitment, value, blinding, &g, &h)
+        };
+        assert!(verify(5, &commitments[5], value, blinding));
+
+        // Wrong position, wrong opening, or other leaf
+        assert!(!verify(4, &commitments[5], value, blinding));
+        assert!(!verify(7, &commitments[5], value, blinding));
+        assert!(!verify(5, &commitments[5], &BigInt::from(6), blinding));
+        assert!(!verify(5, &commitments[5], value, &Scalar::random()));
+        assert!(!verify(6, &commitments[6], &openings[6].0, &openings[6].1));
+        assert!(!MT256::verify_committed_leaf(
+            &[0; 32],
+            5,
+            &proof,
+            &commitments[5],
+            value,
+            blinding,
+            &g,
+            &h
+        ));
+    }
+
+    test_for_all_curves!(test_verify_committed_leaf_duplicate);
+
+    fn test_verify_committed_leaf_duplicate<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let (value, blinding) = (BigInt::from(2), Scalar::<E>::random());
+        let duplicate = &g * Scalar::from(&value) + &h * &blinding;
+        let mut commitments: Vec<Point<E>> = (0..8)
+            .map(|_| Point::generator() * Scalar::random())
+            .collect();
+        commitments[2] = duplicate.clone();
+        commitments[5] = duplicate.clone();
+        let mt256 = MT256::create_tree(&commitments);
+        let root = mt256.get_root().clone();
+
+        // `merkle` generates proof of the first occurrence only
+        let proof_2 = mt256.gen_proof_for_ge(&duplicate);
+        // Proof of the leaf 5 is the proof of its sibling, the leaf 4, with the left sibling
+        // `H(leaf 4)` at the bottom, which is taken from a two-leaf tree
+        let mut proof_5 = mt256.gen_proof_for_ge(&commitments[4]);
+        let tree = MerkleTree::from_vec::<[u8; 32]>(
+            Sha3::keccak256(),
+            vec![leaf(&commitments[4]), [0; 32]],
+        );
+        let left_sibling = MerkleTree::gen_proof::<[u8; 32]>(&tree, [0; 32])
+            .unwrap()
+            .lemma
+            .sibling_hash;
+        let bottom = proof_5
+            .lemma
+            .sub_lemma
+            .as_mut()
+            .unwrap()
+            .sub_lemma
+            .as_mut()
+            .unwrap();
+        bottom.sibling_hash = left_sibling;
+        bottom.sub_lemma.as_mut().unwrap().node_hash =
+            Sha3::keccak256().hash_leaf(&leaf(&duplicate));
+        proof_5.value = leaf(&duplicate);
+        assert!(MT256::<E>::validate_proof(&proof_5, &root).is_ok());
+
+        for (proof, index) in [(&proof_2, 2), (&proof_5, 5)] {
+            for claimed in [2, 5] {
+                assert_eq!(
+                    MT256::verify_committed_leaf(
+                        &root, claimed, proof, &duplicate, &value, &blinding, &g, &h
+                    ),
+                    claimed == index
+                );
+            }
+        }
+    }
 }
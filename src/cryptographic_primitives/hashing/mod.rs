@@ -5,6 +5,9 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 pub mod blake2b512;
+pub mod hash160;
+pub mod hash_keccak256;
+pub mod hash_poseidon;
 pub mod hash_sha256;
 pub mod hash_sha512;
 pub mod hmac_sha512;
@@ -14,3 +17,29 @@ pub mod traits;
 mod ext;
 pub use digest::Digest;
 pub use ext::*;
+
+use std::marker::PhantomData;
+
+/// Marker of hash function `H` chosen for Fiat-Shamir challenge of a proof
+///
+/// Carries no data: it lets proof types be generic over the hash function without storing it,
+/// eg. [DLogProof](crate::cryptographic_primitives::proofs::sigma_dlog::DLogProof).
+pub struct HashChoice<H: Digest + Clone>(PhantomData<H>);
+
+impl<H: Digest + Clone> HashChoice<H> {
+    pub fn new() -> Self {
+        HashChoice(PhantomData)
+    }
+}
+
+impl<H: Digest + Clone> Default for HashChoice<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: Digest + Clone> Clone for HashChoice<H> {
+    fn clone(&self) -> Self {
+        Self::new()
+    }
+}
@@ -6,7 +6,12 @@
 */
 
 pub mod commitments;
+pub mod groupdh;
 pub mod hashing;
+pub mod multiparty;
 pub mod proofs;
+mod proto;
 pub mod secret_sharing;
+pub mod signatures;
 pub mod twoparty;
+pub mod vdf;
@@ -0,0 +1,205 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Protocol runs in two rounds:
+//! 1. Every party calls [CoinFlip::commit] and broadcasts the commitment, keeping the opening
+//!    secret
+//! 2. Once commitments of all parties are received, every party broadcasts its opening
+//!    ([CoinFlip::reveal])
+//!
+//! Then each party computes the shared scalar via [CoinFlip::combine]. A party that doesn't open
+//! its commitment, or opens it to a different value, is reported in [CoinFlipError].
+//!
+//! Openings must not be sent before all the commitments are received, otherwise the last party
+//! to commit can bias the result.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::commitments::hash_commitment::HashCommitment;
+use crate::cryptographic_primitives::commitments::SECURITY_BITS;
+use crate::elliptic::curves::{Curve, Scalar};
+use crate::BigInt;
+
+/// Commitment to party's contribution, broadcasted in the first round
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct CoinFlipCommitment {
+    pub commitment: BigInt,
+}
+
+/// Party's contribution along with blinding factor, broadcasted in the second round
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CoinFlipOpening<E: Curve> {
+    pub value: Scalar<E>,
+    pub blind_factor: BigInt,
+}
+
+/// Parties that failed to open their commitments
+#[derive(Clone, PartialEq, Debug)]
+pub struct CoinFlipError {
+    /// Indexes (in the list of commitments) of parties that didn't send an opening or sent
+    /// an opening that doesn't match their commitment
+    pub misbehaving_parties: Vec<usize>,
+}
+
+impl fmt::Display for CoinFlipError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "parties {:?} failed to open their commitments",
+            self.misbehaving_parties
+        )
+    }
+}
+
+impl std::error::Error for CoinFlipError {}
+
+/// Commit-reveal coin flipping over the scalar field
+pub struct CoinFlip<E: Curve>(PhantomData<E>);
+
+impl<E: Curve> CoinFlip<E> {
+    /// Samples party's contribution and commits to it
+    ///
+    /// Commitment is broadcasted in the first round, opening must be kept secret until
+    /// commitments of all parties are received.
+    pub fn commit() -> (CoinFlipCommitment, CoinFlipOpening<E>) {
+        let value = Scalar::random();
+        let blind_factor = BigInt::sample(SECURITY_BITS);
+        let commitment = Self::commitment(&value, &blind_factor);
+        (
+            CoinFlipCommitment { commitment },
+            CoinFlipOpening {
+                value,
+                blind_factor,
+            },
+        )
+    }
+
+    /// Returns the message to be broadcasted in the second round
+    pub fn reveal(opening: CoinFlipOpening<E>) -> CoinFlipOpening<E> {
+        opening
+    }
+
+    /// Checks that opening matches the commitment
+    pub fn verify(commitment: &CoinFlipCommitment, opening: &CoinFlipOpening<E>) -> bool {
+        Self::commitment(&opening.value, &opening.blind_factor) == commitment.commitment
+    }
+
+    /// Value is encoded at fixed width, and both value and blinding factor are length-prefixed
+    fn commitment(value: &Scalar<E>, blind_factor: &BigInt) -> BigInt {
+        HashCommitment::hash_framed(&[&value.to_bytes(), &blind_factor.to_bytes()])
+    }
+
+    /// Verifies openings of all parties and computes the shared random scalar
+    ///
+    /// `openings[i]` is the opening received from party that sent `commitments[i]`, or `None`
+    /// if the party didn't send it. Missing trailing openings are treated as `None`, too.
+    /// Fails with list of all parties whose openings are missing or invalid.
+    pub fn combine(
+        commitments: &[CoinFlipCommitment],
+        openings: &[Option<CoinFlipOpening<E>>],
+    ) -> Result<Scalar<E>, CoinFlipError> {
+        let misbehaving_parties: Vec<usize> = commitments
+            .iter()
+            .enumerate()
+            .filter(|(i, commitment)| match openings.get(*i) {
+                Some(Some(opening)) => !Self::verify(commitment, opening),
+                _ => true,
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if !misbehaving_parties.is_empty() || openings.len() > commitments.len() {
+            return Err(CoinFlipError {
+                misbehaving_parties,
+            });
+        }
+        Ok(openings
+            .iter()
+            .flatten()
+            .map(|opening| &opening.value)
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_coin_flip_three_parties);
+    fn test_coin_flip_three_parties<E: Curve>() {
+        let (commitments, openings): (Vec<_>, Vec<_>) =
+            (0..3).map(|_| CoinFlip::<E>::commit()).unzip();
+        let expected: Scalar<E> = openings.iter().map(|opening| &opening.value).sum();
+
+        let revealed: Vec<_> = openings
+            .into_iter()
+            .map(|opening| Some(CoinFlip::reveal(opening)))
+            .collect();
+        let result = CoinFlip::combine(&commitments, &revealed).unwrap();
+        assert_eq!(result, expected);
+        // Every party computes the same result
+        assert_eq!(CoinFlip::combine(&commitments, &revealed).unwrap(), result);
+    }
+
+    test_for_all_curves!(test_coin_flip_detects_misbehaving_party);
+    fn test_coin_flip_detects_misbehaving_party<E: Curve>() {
+        let (commitments, openings): (Vec<_>, Vec<_>) =
+            (0..3).map(|_| CoinFlip::<E>::commit()).unzip();
+
+        // Party 1 refuses to open its commitment
+        let mut revealed: Vec<_> = openings.iter().cloned().map(Some).collect();
+        revealed[1] = None;
+        assert_eq!(
+            CoinFlip::combine(&commitments, &revealed),
+            Err(CoinFlipError {
+                misbehaving_parties: vec![1]
+            })
+        );
+        assert_eq!(
+            CoinFlip::combine(&commitments, &revealed[..1]),
+            Err(CoinFlipError {
+                misbehaving_parties: vec![1, 2]
+            })
+        );
+
+        // Party 0 shifts bytes between its value and blinding factor
+        let mut revealed: Vec<_> = openings.iter().cloned().map(Some).collect();
+        let value = openings[0].value.to_bytes();
+        let blind_factor = openings[0].blind_factor.to_bytes();
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(&value[..value.len() - 1]);
+        let mut shifted_blind_factor = value[value.len() - 1..].to_vec();
+        shifted_blind_factor.extend_from_slice(&blind_factor);
+        revealed[0] = Some(CoinFlipOpening {
+            value: Scalar::from_bigint(&BigInt::from_bytes(&shifted)),
+            blind_factor: BigInt::from_bytes(&shifted_blind_factor),
+        });
+        assert_eq!(
+            CoinFlip::combine(&commitments, &revealed),
+            Err(CoinFlipError {
+                misbehaving_parties: vec![0]
+            })
+        );
+
+        // Party 2 opens to a different value
+        let mut revealed: Vec<_> = openings.into_iter().map(Some).collect();
+        if let Some(opening) = &mut revealed[2] {
+            opening.value = &opening.value + Scalar::from(1);
+        }
+        assert_eq!(
+            CoinFlip::combine(&commitments, &revealed),
+            Err(CoinFlipError {
+                misbehaving_parties: vec![2]
+            })
+        );
+    }
+}
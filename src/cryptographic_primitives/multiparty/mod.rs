@@ -0,0 +1,12 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+/// This is an implementation of commit-reveal coin flipping between n parties.
+/// Every party commits to a random scalar r_i using hash commitment, once all commitments are
+/// received, parties open them, and the shared random scalar is r_1 + r_2 + ... + r_n.
+/// It's uniformly distributed as long as at least one party is honest.
+pub mod coin_flip;
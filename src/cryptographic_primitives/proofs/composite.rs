@@ -0,0 +1,185 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Verification of nested proof structures
+//!
+//! [CompositeProof] is a tree of named sub-proofs: its leaves are proofs (or any checks returning
+//! [ProofError]) and its inner nodes are other composites. [CompositeProof::verify] walks the tree
+//! depth-first in order of insertion and stops at the first failed leaf, reporting the names of
+//! components leading to it as [ProofPath], eg. `vss_1/share_2/dlog`.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::cryptographic_primitives::hashing::Digest;
+use crate::elliptic::curves::Curve;
+
+use super::sigma_dlog::DLogProof;
+use super::ProofError;
+
+/// Names of nested components leading to the failed sub-proof, outermost first
+///
+/// Empty path means that the verified proof is a leaf and it failed itself.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ProofPath(pub Vec<String>);
+
+impl fmt::Display for ProofPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "proof verification failed at `{}`", self.0.join("/"))
+    }
+}
+
+impl Error for ProofPath {}
+
+/// Component of [CompositeProof]
+///
+/// Implemented for [DLogProof], for [CompositeProof] itself, and for closures, which allows adding
+/// checks that involve public values outside of the proof, eg. that a proof is about a point
+/// committed in VSS.
+pub trait SubProof {
+    fn verify_subproof(&self) -> Result<(), ProofPath>;
+}
+
+impl<F> SubProof for F
+where
+    F: Fn() -> Result<(), ProofError>,
+{
+    fn verify_subproof(&self) -> Result<(), ProofPath> {
+        self().map_err(|_| ProofPath::default())
+    }
+}
+
+impl<E: Curve, H: Digest + Clone> SubProof for DLogProof<E, H> {
+    fn verify_subproof(&self) -> Result<(), ProofPath> {
+        DLogProof::verify(self).map_err(|_| ProofPath::default())
+    }
+}
+
+/// Container of named sub-proofs verified at once
+#[derive(Default)]
+pub struct CompositeProof<'a> {
+    subproofs: Vec<(String, Box<dyn SubProof + 'a>)>,
+}
+
+impl<'a> CompositeProof<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a component under given name
+    pub fn add_subproof(
+        &mut self,
+        name: impl Into<String>,
+        subproof: impl SubProof + 'a,
+    ) -> &mut Self {
+        self.subproofs.push((name.into(), Box::new(subproof)));
+        self
+    }
+
+    /// Verifies all the components, returns path to the first one that failed
+    pub fn verify(&self) -> Result<(), ProofPath> {
+        for (name, subproof) in &self.subproofs {
+            if let Err(ProofPath(mut path)) = subproof.verify_subproof() {
+                path.insert(0, name.clone());
+                return Err(ProofPath(path));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> SubProof for CompositeProof<'a> {
+    fn verify_subproof(&self) -> Result<(), ProofPath> {
+        self.verify()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+    use crate::elliptic::curves::{Point, Scalar};
+    use crate::test_for_all_curves;
+
+    /// Composite of VSS commitments and proofs of knowledge of every share
+    fn vss_with_share_proofs<E: Curve>(
+        vss: &VerifiableSS<E>,
+        proofs: &[DLogProof<E>],
+    ) -> CompositeProof<'static> {
+        let mut composite = CompositeProof::new();
+        for (index, proof) in (1u16..).zip(proofs) {
+            let expected = vss.get_point_commitment(index);
+            let pk = proof.pk.clone();
+            let mut share = CompositeProof::new();
+            share
+                .add_subproof("commitment", move || {
+                    if pk == expected {
+                        Ok(())
+                    } else {
+                        Err(ProofError)
+                    }
+                })
+                .add_subproof("dlog", proof.clone());
+            composite.add_subproof(format!("share_{}", index), share);
+        }
+        composite
+    }
+
+    test_for_all_curves!(test_composite_proof);
+    fn test_composite_proof<E: Curve>() {
+        let sharings: Vec<_> = (0..2)
+            .map(|_| VerifiableSS::<E>::share(1, 3, &Scalar::random()))
+            .collect();
+        let share_proofs: Vec<Vec<DLogProof<E>>> = sharings
+            .iter()
+            .map(|(_, shares)| shares.iter().map(DLogProof::prove).collect())
+            .collect();
+        let mut dlog_proofs: Vec<DLogProof<E>> = (0..3)
+            .map(|_| DLogProof::prove(&Scalar::random()))
+            .collect();
+
+        let build = |share_proofs: &[Vec<DLogProof<E>>], dlog_proofs: &[DLogProof<E>]| {
+            let mut composite = CompositeProof::new();
+            for (i, ((vss, _), proofs)) in sharings.iter().zip(share_proofs).enumerate() {
+                composite.add_subproof(format!("vss_{}", i), vss_with_share_proofs(vss, proofs));
+            }
+            for (i, proof) in dlog_proofs.iter().enumerate() {
+                composite.add_subproof(format!("dlog_{}", i), proof.clone());
+            }
+            composite
+        };
+        assert_eq!(build(&share_proofs, &dlog_proofs).verify(), Ok(()));
+
+        // Proof of share 2 of the second VSS is valid, but it's about a different point
+        let mut wrong_share = share_proofs.clone();
+        wrong_share[1][1] = DLogProof::prove(&Scalar::random());
+        assert_eq!(
+            build(&wrong_share, &dlog_proofs).verify(),
+            Err(ProofPath(vec![
+                "vss_1".to_string(),
+                "share_2".to_string(),
+                "commitment".to_string()
+            ]))
+        );
+
+        // Proof of share 3 of the first VSS is corrupted
+        let mut corrupted_share = share_proofs.clone();
+        corrupted_share[0][2].challenge_response =
+            &corrupted_share[0][2].challenge_response + Scalar::from(1);
+        let error = build(&corrupted_share, &dlog_proofs).verify().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "proof verification failed at `vss_0/share_3/dlog`"
+        );
+
+        dlog_proofs[2].pk = Point::generator() * Scalar::random();
+        assert_eq!(
+            build(&share_proofs, &dlog_proofs).verify(),
+            Err(ProofPath(vec!["dlog_2".to_string()]))
+        );
+    }
+}
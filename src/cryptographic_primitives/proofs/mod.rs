@@ -8,13 +8,29 @@
 use std::error::Error;
 use std::fmt;
 
+use typenum::Unsigned;
+
+use crate::elliptic::curves::{Curve, ECPoint, ECScalar};
+
+pub mod composite;
 pub mod low_degree_exponent_interpolation;
+pub mod one_out_of_many;
+pub mod proof_of_possession;
+pub mod range_proof_params;
+pub mod sigma_commitment_diff;
+pub mod sigma_commitment_sum;
+pub mod sigma_commitment_to_pubkey;
 pub mod sigma_correct_homomorphic_elgamal_enc;
 pub mod sigma_correct_homomorphic_elgamal_encryption_of_dlog;
 pub mod sigma_dlog;
 pub mod sigma_ec_ddh;
+pub mod sigma_elgamal_pedersen_eq;
+pub mod sigma_reencryption;
+pub mod sigma_representation;
 pub mod sigma_valid_pedersen;
 pub mod sigma_valid_pedersen_blind;
+pub mod small_scalar;
+pub mod verifiable_encryption;
 
 #[derive(Debug, Clone, Copy)]
 pub struct ProofError;
@@ -30,3 +46,28 @@ impl Error for ProofError {
         "Error while verifying"
     }
 }
+
+/// Size of the proof in compact encoding
+///
+/// Compact encoding is the output of `to_bytes` method of the proof: a concatenation of proof
+/// fields in order of declaration, where points are compressed, scalars take fixed number of
+/// bytes, integers are big-endian, and lists are prefixed with their length as 4 bytes big-endian
+/// integer. Size is computed from the curve parameters, so it can be checked before a message is
+/// received or serialized. It's not the size of `to_proto_bytes` output.
+pub trait ProofSize {
+    /// Returns the number of bytes the proof takes in compact encoding
+    fn serialized_size(&self) -> usize;
+}
+
+/// Size of compressed point of curve `E`
+pub(crate) fn point_size<E: Curve>() -> usize {
+    <E::Point as ECPoint>::CompressedPointLength::USIZE
+}
+
+/// Size of scalar of curve `E`
+pub(crate) fn scalar_size<E: Curve>() -> usize {
+    <E::Scalar as ECScalar>::ScalarLength::USIZE
+}
+
+/// Size of list length prefix
+pub(crate) const LIST_LENGTH_PREFIX_SIZE: usize = 4;
@@ -0,0 +1,300 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use std::iter;
+
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::ProofError;
+
+/// This is implementation of one-out-of-many proof from: Jens Groth and Markulf Kohlweiss.
+/// One-out-of-many proofs: Or how to leak a secret and spend a coin. In EUROCRYPT 2015.
+/// <https://eprint.iacr.org/2014/764.pdf>
+///
+/// The statement is a list of Pedersen commitments `C_0, ..., C_{N-1}` (w.r.t. generators `G` and
+/// `H = base_point2`), the witness is index `l` and randomness `r` such that `C_l = rH`, ie. `C_l`
+/// is a commitment to zero. The proof doesn't reveal `l`, and its size is `O(log N)`.
+///
+/// The list of commitments is padded with its last element up to `N = 2^n`. Denote `l_j` the
+/// `j`-th bit of `l`. The protocol:
+/// 1: Prover chooses random `r_j, a_j, s_j, t_j, rho_k` for `j, k in 0..n`, and computes
+///    `c_l_j = l_j G + r_j H`, `c_a_j = a_j G + s_j H`, `c_b_j = l_j a_j G + t_j H`,
+///    `c_d_k = sum_i p_{i,k} C_i + rho_k H`, where `p_{i,k}` is the `k`-th coefficient of
+///    `p_i(x) = prod_j f_{j,i_j}(x)`, `f_{j,1}(x) = l_j x + a_j`, `f_{j,0}(x) = x - f_{j,1}(x)`
+/// prover calculates challenge x = H(C_0, ..., C_{N-1}, c_l, c_a, c_b, c_d)
+/// prover calculates `f_j = l_j x + a_j`, `z_a_j = r_j x + s_j`, `z_b_j = r_j (x - f_j) + t_j`,
+///    `z_d = r x^n - sum_k rho_k x^k`
+///
+/// verifier checks that for each `j`: `x c_l_j + c_a_j = f_j G + z_a_j H`,
+/// `(x - f_j) c_l_j + c_b_j = z_b_j H`, and that
+/// `sum_i (prod_j f_{j,i_j}) C_i - sum_k x^k c_d_k = z_d H`
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct OneOfManyProof<E: Curve> {
+    pub c_l: Vec<Point<E>>,
+    pub c_a: Vec<Point<E>>,
+    pub c_b: Vec<Point<E>>,
+    pub c_d: Vec<Point<E>>,
+    pub f: Vec<Scalar<E>>,
+    pub z_a: Vec<Scalar<E>>,
+    pub z_b: Vec<Scalar<E>>,
+    pub z_d: Scalar<E>,
+}
+
+impl<E: Curve> OneOfManyProof<E> {
+    /// Proves that `commitments[secret_index] = r * H`
+    ///
+    /// ## Panics
+    /// Panics if `commitments` is empty or `secret_index` is out of bounds.
+    pub fn prove(
+        commitments: &[Point<E>],
+        secret_index: usize,
+        r: &Scalar<E>,
+    ) -> OneOfManyProof<E> {
+        assert!(secret_index < commitments.len());
+        let n = bits_count(commitments.len());
+        let commitments = pad_commitments(commitments, n);
+
+        let g = Point::<E>::generator();
+        let h = Point::<E>::base_point2();
+
+        let l: Vec<Scalar<E>> = (0..n)
+            .map(|j| Scalar::from(((secret_index >> j) & 1) as u16))
+            .collect();
+        let r_j: Vec<Scalar<E>> = iter::repeat_with(Scalar::random).take(n).collect();
+        let a: Vec<Scalar<E>> = iter::repeat_with(Scalar::random).take(n).collect();
+        let s: Vec<Scalar<E>> = iter::repeat_with(Scalar::random).take(n).collect();
+        let t: Vec<Scalar<E>> = iter::repeat_with(Scalar::random).take(n).collect();
+        let rho: Vec<Scalar<E>> = iter::repeat_with(Scalar::random).take(n).collect();
+
+        let c_l: Vec<Point<E>> = (0..n).map(|j| g * &l[j] + h * &r_j[j]).collect();
+        let c_a: Vec<Point<E>> = (0..n).map(|j| g * &a[j] + h * &s[j]).collect();
+        let c_b: Vec<Point<E>> = (0..n).map(|j| g * (&l[j] * &a[j]) + h * &t[j]).collect();
+
+        // coefficients of f_{j,1}(x) = l_j x + a_j and f_{j,0}(x) = (1 - l_j) x - a_j
+        let one = Scalar::<E>::from(1);
+        let f_1: Vec<[Scalar<E>; 2]> = (0..n).map(|j| [a[j].clone(), l[j].clone()]).collect();
+        let f_0: Vec<[Scalar<E>; 2]> = (0..n).map(|j| [-&a[j], &one - &l[j]]).collect();
+
+        let p: Vec<Vec<Scalar<E>>> = (0..commitments.len())
+            .map(|i| {
+                (0..n).fold(vec![one.clone()], |acc, j| {
+                    if (i >> j) & 1 == 1 {
+                        poly_mul_linear(&acc, &f_1[j])
+                    } else {
+                        poly_mul_linear(&acc, &f_0[j])
+                    }
+                })
+            })
+            .collect();
+
+        let c_d: Vec<Point<E>> = (0..n)
+            .map(|k| {
+                let sum: Point<E> = commitments
+                    .iter()
+                    .zip(&p)
+                    .map(|(c_i, p_i)| c_i * &p_i[k])
+                    .sum();
+                sum + h * &rho[k]
+            })
+            .collect();
+
+        let x = challenge(&commitments, &c_l, &c_a, &c_b, &c_d);
+
+        let f: Vec<Scalar<E>> = (0..n).map(|j| &l[j] * &x + &a[j]).collect();
+        let z_a: Vec<Scalar<E>> = (0..n).map(|j| &r_j[j] * &x + &s[j]).collect();
+        let z_b: Vec<Scalar<E>> = (0..n).map(|j| &r_j[j] * (&x - &f[j]) + &t[j]).collect();
+
+        let x_pows: Vec<Scalar<E>> = powers(&x, n + 1);
+        let rho_sum: Scalar<E> = rho.iter().zip(&x_pows).map(|(rho, x_k)| rho * x_k).sum();
+        let z_d = r * &x_pows[n] - rho_sum;
+
+        OneOfManyProof {
+            c_l,
+            c_a,
+            c_b,
+            c_d,
+            f,
+            z_a,
+            z_b,
+            z_d,
+        }
+    }
+
+    pub fn verify(&self, commitments: &[Point<E>]) -> Result<(), ProofError> {
+        if commitments.is_empty() {
+            return Err(ProofError);
+        }
+        let n = bits_count(commitments.len());
+        if self.c_l.len() != n
+            || self.c_a.len() != n
+            || self.c_b.len() != n
+            || self.c_d.len() != n
+            || self.f.len() != n
+            || self.z_a.len() != n
+            || self.z_b.len() != n
+        {
+            return Err(ProofError);
+        }
+        let commitments = pad_commitments(commitments, n);
+
+        let g = Point::<E>::generator();
+        let h = Point::<E>::base_point2();
+
+        let x = challenge(&commitments, &self.c_l, &self.c_a, &self.c_b, &self.c_d);
+
+        for j in 0..n {
+            let lhs = &self.c_l[j] * &x + &self.c_a[j];
+            let rhs = g * &self.f[j] + h * &self.z_a[j];
+            if lhs != rhs {
+                return Err(ProofError);
+            }
+            let lhs = &self.c_l[j] * (&x - &self.f[j]) + &self.c_b[j];
+            let rhs = h * &self.z_b[j];
+            if lhs != rhs {
+                return Err(ProofError);
+            }
+        }
+
+        let f_0: Vec<Scalar<E>> = self.f.iter().map(|f_j| &x - f_j).collect();
+        let sum_c: Point<E> = commitments
+            .iter()
+            .enumerate()
+            .map(|(i, c_i)| {
+                let p_i: Scalar<E> = (0..n)
+                    .map(|j| {
+                        if (i >> j) & 1 == 1 {
+                            &self.f[j]
+                        } else {
+                            &f_0[j]
+                        }
+                    })
+                    .product();
+                c_i * p_i
+            })
+            .sum();
+        let x_pows = powers(&x, n);
+        let sum_d: Point<E> = self
+            .c_d
+            .iter()
+            .zip(&x_pows)
+            .map(|(c_d_k, x_k)| c_d_k * x_k)
+            .sum();
+
+        if sum_c - sum_d == h * &self.z_d {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+/// Returns `n = max(1, ceil(log2(len)))`
+fn bits_count(len: usize) -> usize {
+    let mut n = 1;
+    while (1 << n) < len {
+        n += 1;
+    }
+    n
+}
+
+fn pad_commitments<E: Curve>(commitments: &[Point<E>], n: usize) -> Vec<Point<E>> {
+    let last = commitments.last().expect("commitments list is not empty");
+    commitments
+        .iter()
+        .chain(iter::repeat(last))
+        .take(1 << n)
+        .cloned()
+        .collect()
+}
+
+/// Multiplies polynomial `poly` by a linear polynomial `coef[0] + coef[1] x`
+fn poly_mul_linear<E: Curve>(poly: &[Scalar<E>], coef: &[Scalar<E>; 2]) -> Vec<Scalar<E>> {
+    let mut result = vec![Scalar::zero(); poly.len() + 1];
+    for (k, p_k) in poly.iter().enumerate() {
+        result[k] = &result[k] + p_k * &coef[0];
+        result[k + 1] = &result[k + 1] + p_k * &coef[1];
+    }
+    result
+}
+
+/// Returns `[1, x, x^2, ..., x^(count-1)]`
+fn powers<E: Curve>(x: &Scalar<E>, count: usize) -> Vec<Scalar<E>> {
+    iter::successors(Some(Scalar::from(1)), |x_k| Some(x_k * x))
+        .take(count)
+        .collect()
+}
+
+fn challenge<E: Curve>(
+    commitments: &[Point<E>],
+    c_l: &[Point<E>],
+    c_a: &[Point<E>],
+    c_b: &[Point<E>],
+    c_d: &[Point<E>],
+) -> Scalar<E> {
+    Sha256::new()
+        .chain_points(commitments)
+        .chain_points(c_l)
+        .chain_points(c_a)
+        .chain_points(c_b)
+        .chain_points(c_d)
+        .result_scalar()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    fn commitments_with_zero_at<E: Curve>(
+        size: usize,
+        index: usize,
+        r: &Scalar<E>,
+    ) -> Vec<Point<E>> {
+        let g = Point::<E>::generator();
+        let h = Point::<E>::base_point2();
+        (0..size)
+            .map(|i| {
+                if i == index {
+                    h * r
+                } else {
+                    g * Scalar::random() + h * Scalar::random()
+                }
+            })
+            .collect()
+    }
+
+    test_for_all_curves!(test_one_out_of_many_proof);
+    fn test_one_out_of_many_proof<E: Curve>() {
+        let r = Scalar::<E>::random();
+        let commitments = commitments_with_zero_at(8, 5, &r);
+        let proof = OneOfManyProof::prove(&commitments, 5, &r);
+        assert_eq!(proof.c_l.len(), 3);
+        assert!(proof.verify(&commitments).is_ok());
+    }
+
+    test_for_all_curves!(test_one_out_of_many_proof_not_power_of_two);
+    fn test_one_out_of_many_proof_not_power_of_two<E: Curve>() {
+        let r = Scalar::<E>::random();
+        let commitments = commitments_with_zero_at(5, 4, &r);
+        let proof = OneOfManyProof::prove(&commitments, 4, &r);
+        assert!(proof.verify(&commitments).is_ok());
+    }
+
+    test_for_all_curves!(test_wrong_one_out_of_many_proof);
+    fn test_wrong_one_out_of_many_proof<E: Curve>() {
+        let r = Scalar::<E>::random();
+        let commitments = commitments_with_zero_at(8, 5, &r);
+        let wrong_r = Scalar::<E>::random();
+        let proof = OneOfManyProof::prove(&commitments, 5, &wrong_r);
+        assert!(proof.verify(&commitments).is_err());
+    }
+}
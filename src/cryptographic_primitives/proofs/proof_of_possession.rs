@@ -0,0 +1,87 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, ECScalar, Point, Scalar};
+
+use super::ProofError;
+
+const DOMAIN_LABEL: &[u8] = b"curv.proof_of_possession";
+
+/// Proof of possession of secret key `sk` corresponding to public key `pk = sk * G`
+///
+/// Schnorr identification protocol made non-interactive by Fiat-Shamir transform: prover
+/// commits to A = k*G, challenge is c = H(G, pk, A), response is z = k + c*sk, and verifier
+/// checks z*G = A + c*pk.
+///
+/// Unlike [DLogProof](super::sigma_dlog::DLogProof), the public key is not carried by the proof,
+/// verifier supplies it, and challenge is computed under a separate domain label. So the proof
+/// can't be replayed for another key, and proofs of other protocols can't be passed off as
+/// a proof of possession.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ProofOfPossession<E: Curve> {
+    pub commitment: Point<E>,
+    pub response: Scalar<E>,
+}
+
+impl<E: Curve> ProofOfPossession<E> {
+    pub fn prove(sk: &Scalar<E>) -> ProofOfPossession<E> {
+        let pk = Point::generator() * sk;
+        let k = Scalar::random();
+        let commitment = Point::generator() * &k;
+        let c = challenge(&pk, &commitment);
+        ProofOfPossession {
+            response: k + c * sk,
+            commitment,
+        }
+    }
+
+    pub fn verify(&self, pk: &Point<E>) -> Result<(), ProofError> {
+        if pk.is_zero() {
+            return Err(ProofError);
+        }
+        let c = challenge(pk, &self.commitment);
+        if Point::generator() * &self.response == &self.commitment + pk * c {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+fn challenge<E: Curve>(pk: &Point<E>, commitment: &Point<E>) -> Scalar<E> {
+    let g = Point::<E>::generator();
+    Scalar::from_raw(E::Scalar::challenge(
+        &[g.as_raw(), pk.as_raw(), commitment.as_raw()],
+        &[],
+        DOMAIN_LABEL,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_proof_of_possession);
+    fn test_proof_of_possession<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let proof = ProofOfPossession::prove(&sk);
+        assert!(proof.verify(&pk).is_ok());
+    }
+
+    test_for_all_curves!(test_proof_of_possession_for_other_key);
+    fn test_proof_of_possession_for_other_key<E: Curve>() {
+        let proof = ProofOfPossession::<E>::prove(&Scalar::random());
+        let other_pk = Point::generator() * Scalar::random();
+        assert!(proof.verify(&other_pk).is_err());
+        assert!(proof.verify(&Point::zero()).is_err());
+    }
+}
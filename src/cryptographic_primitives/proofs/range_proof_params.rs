@@ -0,0 +1,70 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, Point};
+
+const DOMAIN_LABEL: &[u8] = b"curv.range_proof_params";
+
+/// Generator table for range proofs over `n_bits`-bit values
+///
+/// Range proofs in Bulletproofs style (Bünz et al., <https://eprint.iacr.org/2017/1066.pdf>) need
+/// vectors of generators `g_1, ..., g_n` and `h_1, ..., h_n` whose discrete logs are unknown.
+/// Deriving them is the dominant setup cost, so the table is computed once by [new](Self::new)
+/// and is meant to be shared by all proofs over the same bit size.
+///
+/// Generators are derived by [Point::derive_generators] from the seed, so the same `n_bits` and
+/// seed always give the same table, and prover and verifier can build it independently.
+///
+/// Note that range proof itself is not a part of this crate: the table is consumed by external
+/// range proof implementations.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct RangeProofParams<E: Curve> {
+    pub n_bits: usize,
+    pub g_vec: Vec<Point<E>>,
+    pub h_vec: Vec<Point<E>>,
+}
+
+impl<E: Curve> RangeProofParams<E> {
+    /// Derives `2 * n_bits` generators from the seed
+    pub fn new(n_bits: usize, seed: &[u8]) -> Self {
+        let label = [DOMAIN_LABEL, seed].concat();
+        let mut g_vec = Point::derive_generators(&label, 2 * n_bits);
+        let h_vec = g_vec.split_off(n_bits);
+        RangeProofParams {
+            n_bits,
+            g_vec,
+            h_vec,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_range_proof_params_are_deterministic);
+    fn test_range_proof_params_are_deterministic<E: Curve>() {
+        let params = RangeProofParams::<E>::new(8, b"session seed");
+        assert_eq!(params.g_vec.len(), 8);
+        assert_eq!(params.h_vec.len(), 8);
+        assert_eq!(params, RangeProofParams::new(8, b"session seed"));
+
+        let generators: Vec<&Point<E>> = params.g_vec.iter().chain(&params.h_vec).collect();
+        for (i, g_i) in generators.iter().enumerate() {
+            assert!(!g_i.is_zero());
+            assert!(generators[i + 1..].iter().all(|g_j| g_i != g_j));
+        }
+
+        let other = RangeProofParams::<E>::new(8, b"other seed");
+        assert_ne!(params.g_vec, other.g_vec);
+        assert_ne!(params.h_vec, other.h_vec);
+    }
+}
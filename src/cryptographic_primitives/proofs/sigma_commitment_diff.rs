@@ -0,0 +1,73 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::sigma_dlog::DLogProof;
+use super::ProofError;
+
+/// Proof that two Pedersen commitments hide values that differ by public `delta`
+///
+/// Commitments are C1 = v1*g + r1*h and C2 = v2*g + r2*h. The statement is (C1, C2, delta, g, h),
+/// the witness is (r1, r2). The relation outputs 1 if v2 = v1 + delta, which is the case iff
+/// C2 - C1 - delta*g = (r2 - r1)*h. Prover only needs to know the discrete log of the difference
+/// with respect to h, which is proven using [DLogProof] with base h. Committed values are neither
+/// part of the witness nor revealed by the proof.
+///
+/// Soundness relies on the discrete log of h with respect to g being unknown.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CommitmentDiffProof<E: Curve> {
+    pub dlog_proof: DLogProof<E>,
+}
+
+impl<E: Curve> CommitmentDiffProof<E> {
+    pub fn prove(r1: &Scalar<E>, r2: &Scalar<E>, h: &Point<E>) -> CommitmentDiffProof<E> {
+        CommitmentDiffProof {
+            dlog_proof: DLogProof::prove_with_base(&(r2 - r1), h),
+        }
+    }
+
+    pub fn verify(
+        &self,
+        c1: &Point<E>,
+        c2: &Point<E>,
+        delta: &Scalar<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> Result<(), ProofError> {
+        let blinding_diff = c2 - c1 - g * delta;
+        self.dlog_proof.verify_with_base(&blinding_diff, h)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_commitment_diff_proof);
+    fn test_commitment_diff_proof<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let commit = |v: &Scalar<E>, r: &Scalar<E>| &g * v + &h * r;
+
+        let v1 = Scalar::random();
+        let delta = Scalar::random();
+        let (r1, r2) = (Scalar::random(), Scalar::random());
+        let c1 = commit(&v1, &r1);
+        let c2 = commit(&(&v1 + &delta), &r2);
+
+        let proof = CommitmentDiffProof::prove(&r1, &r2, &h);
+        assert!(proof.verify(&c1, &c2, &delta, &g, &h).is_ok());
+
+        let wrong_delta = &delta + Scalar::from(1);
+        assert!(proof.verify(&c1, &c2, &wrong_delta, &g, &h).is_err());
+    }
+}
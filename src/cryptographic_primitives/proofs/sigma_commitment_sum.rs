@@ -0,0 +1,83 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::sigma_dlog::DLogProof;
+use super::ProofError;
+
+/// Proof that Pedersen commitments hide values that sum up to the value hidden in total commitment
+///
+/// Commitments are C_i = v_i*g + r_i*h and C_total = v_total*g + r_total*h. The statement is
+/// (C_1, ..., C_n, C_total, g, h), the witness is (r_1, ..., r_n, r_total). The relation outputs 1
+/// if v_1 + ... + v_n = v_total, which is the case iff C_1 + ... + C_n - C_total =
+/// (r_1 + ... + r_n - r_total)*h. As in [CommitmentDiffProof](super::sigma_commitment_diff::CommitmentDiffProof),
+/// prover only needs to know the discrete log of the aggregate with respect to h, which is proven
+/// using [DLogProof] with base h. Committed values are not revealed by the proof.
+///
+/// Soundness relies on the discrete log of h with respect to g being unknown.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CommitmentSumProof<E: Curve> {
+    pub dlog_proof: DLogProof<E>,
+}
+
+impl<E: Curve> CommitmentSumProof<E> {
+    pub fn prove(
+        blindings: &[Scalar<E>],
+        total_blinding: &Scalar<E>,
+        h: &Point<E>,
+    ) -> CommitmentSumProof<E> {
+        let blinding_diff = blindings.iter().sum::<Scalar<E>>() - total_blinding;
+        CommitmentSumProof {
+            dlog_proof: DLogProof::prove_with_base(&blinding_diff, h),
+        }
+    }
+
+    pub fn verify(
+        &self,
+        commitments: &[Point<E>],
+        total: &Point<E>,
+        h: &Point<E>,
+    ) -> Result<(), ProofError> {
+        let blinding_diff = commitments.iter().sum::<Point<E>>() - total;
+        self.dlog_proof.verify_with_base(&blinding_diff, h)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_commitment_sum_proof);
+    fn test_commitment_sum_proof<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let commit = |v: &Scalar<E>, r: &Scalar<E>| &g * v + &h * r;
+
+        let values: Vec<Scalar<E>> = (0..4).map(|_| Scalar::random()).collect();
+        let blindings: Vec<Scalar<E>> = (0..4).map(|_| Scalar::random()).collect();
+        let commitments: Vec<Point<E>> = values
+            .iter()
+            .zip(&blindings)
+            .map(|(v, r)| commit(v, r))
+            .collect();
+        let total_blinding = Scalar::random();
+        let total = commit(&values.iter().sum(), &total_blinding);
+
+        let proof = CommitmentSumProof::prove(&blindings, &total_blinding, &h);
+        assert!(proof.verify(&commitments, &total, &h).is_ok());
+
+        let mut perturbed = commitments.clone();
+        perturbed[2] = commit(&(&values[2] + Scalar::from(1)), &blindings[2]);
+        assert!(proof.verify(&perturbed, &total, &h).is_err());
+        assert!(proof.verify(&commitments[..3], &total, &h).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::ProofError;
+
+/// protocol for proving that Pedersen commitment C and public key P share the same secret x.
+/// witness: (x,r), statement: (C, P, G, H). The Relation R outputs 1 if C = xG + rH and P = xG.
+/// The protocol:
+/// 1: Prover chooses A1 = s1*G + s2*H, A2 = s1*G for random s1,s2
+/// prover calculates challenge e = H(G,H,C,P,A1,A2)
+/// prover calculates z1 = s1 + ex, z2 = s2 + er
+/// prover sends pi = {A1,A2,z1,z2}
+///
+/// verifier checks that z1*G + z2*H = A1 + eC and z1*G = A2 + eP
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct CommitmentToPubkeyProof<E: Curve> {
+    pub a1: Point<E>,
+    pub a2: Point<E>,
+    pub z1: Scalar<E>,
+    pub z2: Scalar<E>,
+}
+
+impl<E: Curve> CommitmentToPubkeyProof<E> {
+    pub fn prove(
+        x: &Scalar<E>,
+        r: &Scalar<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> CommitmentToPubkeyProof<E> {
+        let pk = g * x;
+        let com = &pk + h * r;
+
+        let s1 = Scalar::random();
+        let s2 = Scalar::random();
+        let a2 = g * &s1;
+        let a1 = &a2 + h * &s2;
+
+        let e = Sha256::new()
+            .chain_points([g, h, &com, &pk, &a1, &a2])
+            .result_scalar();
+
+        let z1 = &s1 + &e * x;
+        let z2 = &s2 + &e * r;
+
+        CommitmentToPubkeyProof { a1, a2, z1, z2 }
+    }
+
+    pub fn verify(
+        &self,
+        com: &Point<E>,
+        pk: &Point<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> Result<(), ProofError> {
+        let e = Sha256::new()
+            .chain_points([g, h, com, pk, &self.a1, &self.a2])
+            .result_scalar();
+
+        let z1g = g * &self.z1;
+        let z2h = h * &self.z2;
+        let com_check = &z1g + z2h == &self.a1 + com * &e;
+        let pk_check = z1g == &self.a2 + pk * &e;
+
+        if com_check && pk_check {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::test_for_all_curves!(test_commitment_to_pubkey_proof);
+    fn test_commitment_to_pubkey_proof<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2();
+        let x = Scalar::random();
+        let r = Scalar::random();
+        let com = &g * &x + h * &r;
+        let pk = &g * &x;
+
+        let proof = CommitmentToPubkeyProof::prove(&x, &r, &g, h);
+        assert!(proof.verify(&com, &pk, &g, h).is_ok());
+    }
+
+    crate::test_for_all_curves!(test_wrong_commitment_to_pubkey_proof);
+    fn test_wrong_commitment_to_pubkey_proof<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2();
+        let x = Scalar::random();
+        let r = Scalar::random();
+        let com = &g * &x + h * &r;
+        let pk = &g * Scalar::random();
+
+        let proof = CommitmentToPubkeyProof::prove(&x, &r, &g, h);
+        assert!(proof.verify(&com, &pk, &g, h).is_err());
+    }
+}
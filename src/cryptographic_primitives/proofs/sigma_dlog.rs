@@ -5,13 +5,16 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 
+use std::fmt;
+
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 
-use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
-use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt, HashChoice};
+use crate::elliptic::curves::{error::DeserializationError, Curve, Point, Scalar};
 
-use super::ProofError;
+use super::{point_size, scalar_size, ProofError, ProofSize};
+use crate::cryptographic_primitives::proto::{self, ProtoWriter};
 
 /// This is implementation of Schnorr's identification protocol for elliptic curve groups or a
 /// sigma protocol for Proof of knowledge of the discrete log of an Elliptic-curve point:
@@ -23,52 +26,264 @@ use super::ProofError;
 /// How to prove yourself: Practical solutions to identification and signature problems.
 /// In Advances in Cryptology - CRYPTO ’86, Santa Barbara, California, USA, 1986, Proceedings,
 /// pages 186–194, 1986.
-#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+///
+/// Challenge is computed with hash function `H` (SHA-256 by default). Prover and verifier must
+/// agree on it: the hash function is not a part of serialized proof, and proof made with one
+/// hash function doesn't verify with another.
+#[derive(Serialize, Deserialize)]
 #[serde(bound = "")]
-pub struct DLogProof<E: Curve> {
+pub struct DLogProof<E: Curve, H: Digest + Clone = Sha256> {
     pub pk: Point<E>,
     pub pk_t_rand_commitment: Point<E>,
     pub challenge_response: Scalar<E>,
+    #[serde(skip)]
+    hash_choice: HashChoice<H>,
 }
 
-impl<E: Curve> DLogProof<E> {
-    pub fn prove(sk: &Scalar<E>) -> DLogProof<E> {
-        let generator = Point::<E>::generator();
-
-        let sk_t_rand_commitment = Scalar::random();
-        let pk_t_rand_commitment = generator * &sk_t_rand_commitment;
+impl<E: Curve, H: Digest + Clone> Clone for DLogProof<E, H> {
+    fn clone(&self) -> Self {
+        DLogProof {
+            pk: self.pk.clone(),
+            pk_t_rand_commitment: self.pk_t_rand_commitment.clone(),
+            challenge_response: self.challenge_response.clone(),
+            hash_choice: self.hash_choice.clone(),
+        }
+    }
+}
 
-        let pk = Point::generator() * sk;
+impl<E: Curve, H: Digest + Clone> PartialEq for DLogProof<E, H> {
+    fn eq(&self, other: &Self) -> bool {
+        self.pk == other.pk
+            && self.pk_t_rand_commitment == other.pk_t_rand_commitment
+            && self.challenge_response == other.challenge_response
+    }
+}
 
-        let challenge = Sha256::new()
-            .chain_point(&pk_t_rand_commitment)
-            .chain_point(&generator.to_point())
-            .chain_point(&pk)
-            .result_scalar();
+impl<E: Curve, H: Digest + Clone> fmt::Debug for DLogProof<E, H> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("DLogProof")
+            .field("pk", &self.pk)
+            .field("pk_t_rand_commitment", &self.pk_t_rand_commitment)
+            .field("challenge_response", &self.challenge_response)
+            .finish()
+    }
+}
 
-        let challenge_mul_sk = challenge * sk;
-        let challenge_response = &sk_t_rand_commitment - &challenge_mul_sk;
+impl<E: Curve, H: Digest + Clone> DLogProof<E, H> {
+    /// Constructs the proof from its components
+    pub fn new(
+        pk: Point<E>,
+        pk_t_rand_commitment: Point<E>,
+        challenge_response: Scalar<E>,
+    ) -> DLogProof<E, H> {
         DLogProof {
             pk,
             pk_t_rand_commitment,
             challenge_response,
+            hash_choice: HashChoice::new(),
         }
     }
 
-    pub fn verify(proof: &DLogProof<E>) -> Result<(), ProofError> {
-        let generator = Point::<E>::generator();
+    pub fn prove(sk: &Scalar<E>) -> DLogProof<E, H> {
+        Self::prove_with_base(sk, &Point::generator().to_point())
+    }
+
+    /// Proves knowledge of `sk` such that `pk = base * sk` for arbitrary `base`
+    ///
+    /// Base is absorbed into the challenge, so the proof is bound to the specific base.
+    pub fn prove_with_base(sk: &Scalar<E>, base: &Point<E>) -> DLogProof<E, H> {
+        Self::prove_bound(sk, base, None)
+    }
+
+    /// Proves knowledge of `sk` such that `pk = sk * G`, binding the proof to a value of public
+    /// random beacon
+    ///
+    /// Beacon is absorbed into the challenge, so the proof can't be computed before the beacon
+    /// value is published. Such proof verifies only with [verify_with_beacon](Self::verify_with_beacon)
+    /// given the same beacon value, and never with [verify](Self::verify).
+    pub fn prove_with_beacon(sk: &Scalar<E>, beacon: &[u8]) -> DLogProof<E, H> {
+        Self::prove_bound(sk, &Point::generator().to_point(), Some(beacon))
+    }
+
+    fn prove_bound(sk: &Scalar<E>, base: &Point<E>, beacon: Option<&[u8]>) -> DLogProof<E, H> {
+        let sk_t_rand_commitment = Scalar::random();
+        let pk_t_rand_commitment = base * &sk_t_rand_commitment;
 
-        let challenge = Sha256::new()
-            .chain_point(&proof.pk_t_rand_commitment)
-            .chain_point(&generator.to_point())
-            .chain_point(&proof.pk)
-            .result_scalar();
+        let pk = base * sk;
+
+        let challenge = Self::challenge(&pk_t_rand_commitment, base, &pk, beacon);
+
+        let challenge_mul_sk = challenge * sk;
+        let challenge_response = &sk_t_rand_commitment - &challenge_mul_sk;
+        DLogProof::new(pk, pk_t_rand_commitment, challenge_response)
+    }
+
+    pub fn verify(proof: &DLogProof<E, H>) -> Result<(), ProofError> {
+        proof.verify_bound(&Point::generator().to_point(), None)
+    }
+
+    /// Verifies proof produced by [prove_with_beacon](Self::prove_with_beacon)
+    ///
+    /// Fails if the proof is bound to a beacon value other than `beacon`.
+    pub fn verify_with_beacon(&self, beacon: &[u8]) -> Result<(), ProofError> {
+        self.verify_bound(&Point::generator().to_point(), Some(beacon))
+    }
+
+    /// Verifies proof of knowledge of discrete log of `pk` with respect to `base`
+    pub fn verify_with_base(&self, pk: &Point<E>, base: &Point<E>) -> Result<(), ProofError> {
+        if self.pk != *pk {
+            return Err(ProofError);
+        }
+        self.verify_bound(base, None)
+    }
+
+    /// Verifies many proofs of knowledge of discrete log of the same `statement` at once
+    ///
+    /// Verification equations `challenge_response_i * G + challenge_i * statement =
+    /// pk_t_rand_commitment_i` are combined with random coefficients `ρ_i` into a single check
+    /// `(sum ρ_i challenge_response_i) * G + (sum ρ_i challenge_i) * statement =
+    /// sum ρ_i pk_t_rand_commitment_i`, which holds (with overwhelming probability) only if every
+    /// proof is valid. Returns error if any proof is invalid or is given for another statement,
+    /// without telling which one, use [verify](Self::verify) to find it out.
+    pub fn batch_verify_same_statement(
+        proofs: &[DLogProof<E, H>],
+        statement: &Point<E>,
+    ) -> Result<(), ProofError> {
+        let g = Point::generator().to_point();
+        let mut response_sum = Scalar::<E>::zero();
+        let mut challenge_sum = Scalar::<E>::zero();
+        let mut commitments_sum = Point::<E>::zero();
+        for proof in proofs {
+            if proof.pk != *statement {
+                return Err(ProofError);
+            }
+            let rho = Scalar::<E>::random();
+            let challenge = Self::challenge(&proof.pk_t_rand_commitment, &g, &proof.pk, None);
+            response_sum = response_sum + &rho * &proof.challenge_response;
+            challenge_sum = challenge_sum + &rho * challenge;
+            commitments_sum = commitments_sum + &proof.pk_t_rand_commitment * &rho;
+        }
+
+        if &g * response_sum + statement * challenge_sum == commitments_sum {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+
+    fn challenge(
+        pk_t_rand_commitment: &Point<E>,
+        base: &Point<E>,
+        pk: &Point<E>,
+        beacon: Option<&[u8]>,
+    ) -> Scalar<E> {
+        let hash = H::new()
+            .chain_point(pk_t_rand_commitment)
+            .chain_point(base)
+            .chain_point(pk);
+        match beacon {
+            None => hash.result_scalar(),
+            Some(beacon) => hash
+                .chain((beacon.len() as u64).to_be_bytes())
+                .chain(beacon)
+                .result_scalar(),
+        }
+    }
+
+    fn verify_bound(&self, base: &Point<E>, beacon: Option<&[u8]>) -> Result<(), ProofError> {
+        let challenge = Self::challenge(&self.pk_t_rand_commitment, base, &self.pk, beacon);
+
+        let pk_challenge = &self.pk * &challenge;
+
+        let pk_verifier = base * &self.challenge_response + pk_challenge;
+
+        if pk_verifier == self.pk_t_rand_commitment {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+
+    /// Serializes the proof into fixed-layout byte array
+    ///
+    /// Layout is `pk || pk_t_rand_commitment || challenge_response`, where points are compressed
+    /// and scalar is encoded in fixed number of bytes, so the length is known in advance (see
+    /// [ProofSize]).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.serialized_size());
+        bytes.extend_from_slice(&self.pk.to_bytes(true));
+        bytes.extend_from_slice(&self.pk_t_rand_commitment.to_bytes(true));
+        bytes.extend_from_slice(&self.challenge_response.to_bytes());
+        bytes
+    }
+
+    /// Deserializes the proof from byte array produced by [to_bytes](Self::to_bytes)
+    ///
+    /// Returns error if length of `bytes` doesn't match the layout, or if any of points or scalar
+    /// is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<DLogProof<E, H>, DeserializationError> {
+        let (point_len, scalar_len) = (point_size::<E>(), scalar_size::<E>());
+        if bytes.len() != 2 * point_len + scalar_len {
+            return Err(DeserializationError);
+        }
+        let (pk, rest) = bytes.split_at(point_len);
+        let (pk_t_rand_commitment, challenge_response) = rest.split_at(point_len);
+        Ok(DLogProof::new(
+            Point::from_bytes(pk).map_err(|_| DeserializationError)?,
+            Point::from_bytes(pk_t_rand_commitment).map_err(|_| DeserializationError)?,
+            Scalar::from_bytes(challenge_response).map_err(|_| DeserializationError)?,
+        ))
+    }
+}
 
-        let pk_challenge = &proof.pk * &challenge;
+impl<E: Curve> DLogProof<E> {
+    /// Proves that statements `Y_i = bases[i] * x` share the same witness `x`
+    ///
+    /// This is Chaum-Pedersen protocol generalized to any number of bases: a single challenge
+    /// and a single response prove all the relations at once, which is smaller than a separate
+    /// [DLogProof] for each base. Statements are computed from the witness and aren't included
+    /// into the proof, verifier gets them separately (see
+    /// [verify_shared_witness](SharedWitnessDLogProof::verify_shared_witness)).
+    pub fn prove_shared_witness(x: &Scalar<E>, bases: &[Point<E>]) -> SharedWitnessDLogProof<E> {
+        let r = Scalar::random();
+        let statements: Vec<Point<E>> = bases.iter().map(|g| g * x).collect();
+        let commitments: Vec<Point<E>> = bases.iter().map(|g| g * &r).collect();
+        let challenge = shared_witness_challenge(bases, &statements, &commitments);
+        SharedWitnessDLogProof {
+            commitments,
+            challenge_response: r - challenge * x,
+        }
+    }
+}
 
-        let pk_verifier = generator * &proof.challenge_response + pk_challenge;
+/// Proof that several discrete logs are equal, produced by [DLogProof::prove_shared_witness]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SharedWitnessDLogProof<E: Curve> {
+    pub commitments: Vec<Point<E>>,
+    pub challenge_response: Scalar<E>,
+}
 
-        if pk_verifier == proof.pk_t_rand_commitment {
+impl<E: Curve> SharedWitnessDLogProof<E> {
+    /// Verifies that `statements[i] = bases[i] * x` for the same `x` known by the prover
+    pub fn verify_shared_witness(
+        &self,
+        statements: &[Point<E>],
+        bases: &[Point<E>],
+    ) -> Result<(), ProofError> {
+        if bases.is_empty()
+            || statements.len() != bases.len()
+            || self.commitments.len() != bases.len()
+        {
+            return Err(ProofError);
+        }
+        let challenge = shared_witness_challenge(bases, statements, &self.commitments);
+        let valid = bases
+            .iter()
+            .zip(statements)
+            .zip(&self.commitments)
+            .all(|((g, y), a)| g * &self.challenge_response + y * &challenge == *a);
+        if valid {
             Ok(())
         } else {
             Err(ProofError)
@@ -76,9 +291,100 @@ impl<E: Curve> DLogProof<E> {
     }
 }
 
+fn shared_witness_challenge<E: Curve>(
+    bases: &[Point<E>],
+    statements: &[Point<E>],
+    commitments: &[Point<E>],
+) -> Scalar<E> {
+    Sha256::new()
+        .chain_points(bases)
+        .chain_points(statements)
+        .chain_points(commitments)
+        .result_scalar()
+}
+
+impl<E: Curve, H: Digest + Clone> DLogProof<E, H> {
+    /// Serializes the proof into protobuf message `DLogProof` defined in `proto/curv.proto`
+    ///
+    /// Points are compressed, scalar is encoded in big-endian, so the message can be parsed by
+    /// code generated from the schema in other languages.
+    pub fn to_proto_bytes(&self) -> Vec<u8> {
+        let mut writer = ProtoWriter::new();
+        writer.write_point(1, &self.pk);
+        writer.write_point(2, &self.pk_t_rand_commitment);
+        writer.write_scalar(3, &self.challenge_response);
+        writer.into_bytes()
+    }
+
+    /// Deserializes the proof from protobuf message produced by
+    /// [to_proto_bytes](Self::to_proto_bytes)
+    ///
+    /// Unknown fields are ignored. Returns error if message is malformed, any of the fields is
+    /// missing, or point or scalar is invalid.
+    pub fn from_proto_bytes(bytes: &[u8]) -> Result<DLogProof<E, H>, DeserializationError> {
+        let (mut pk, mut pk_t_rand_commitment, mut challenge_response) = (None, None, None);
+        for (field, value) in proto::read_fields(bytes)? {
+            match field {
+                1 => pk = Some(proto::read_point(&value)?),
+                2 => pk_t_rand_commitment = Some(proto::read_point(&value)?),
+                3 => challenge_response = Some(proto::read_scalar(&value)?),
+                _ => (),
+            }
+        }
+        Ok(DLogProof::new(
+            pk.ok_or(DeserializationError)?,
+            pk_t_rand_commitment.ok_or(DeserializationError)?,
+            challenge_response.ok_or(DeserializationError)?,
+        ))
+    }
+}
+
+impl<E: Curve, H: Digest + Clone> ProofSize for DLogProof<E, H> {
+    fn serialized_size(&self) -> usize {
+        2 * point_size::<E>() + scalar_size::<E>()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::elliptic::curves::Secp256k1;
+
+    crate::test_for_all_curves!(test_dlog_proof_proto_round_trip);
+    fn test_dlog_proof_proto_round_trip<E: Curve>() {
+        let proof = DLogProof::<E>::prove(&Scalar::random());
+        let bytes = proof.to_proto_bytes();
+        let decoded = DLogProof::<E>::from_proto_bytes(&bytes).unwrap();
+        assert_eq!(proof, decoded);
+        assert!(DLogProof::verify(&decoded).is_ok());
+
+        // Unknown fields are skipped
+        let mut extended = bytes.clone();
+        extended.extend_from_slice(&[0x20, 0x01, 0x2a, 0x01, 0xff]);
+        assert_eq!(DLogProof::<E>::from_proto_bytes(&extended).unwrap(), proof);
+
+        assert!(DLogProof::<E>::from_proto_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(DLogProof::<E>::from_proto_bytes(&[]).is_err());
+    }
+
+    #[test]
+    fn test_dlog_proof_proto_golden_bytes() {
+        let g = Point::<Secp256k1>::generator();
+        let proof = DLogProof::<Secp256k1>::new(g.to_point(), g * Scalar::from(2), Scalar::from(3));
+        let golden = concat!(
+            "0a21",
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "1221",
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+            "1a20",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+        );
+        assert_eq!(hex::encode(proof.to_proto_bytes()), golden);
+        assert_eq!(
+            DLogProof::from_proto_bytes(&hex::decode(golden).unwrap()).unwrap(),
+            proof
+        );
+    }
 
     crate::test_for_all_curves!(test_dlog_proof);
     fn test_dlog_proof<E: Curve>() {
@@ -86,4 +392,126 @@ mod tests {
         let dlog_proof = DLogProof::<E>::prove(&witness);
         assert!(DLogProof::verify(&dlog_proof).is_ok());
     }
+
+    crate::test_for_all_curves!(test_dlog_proof_batch_verify_same_statement);
+    fn test_dlog_proof_batch_verify_same_statement<E: Curve>() {
+        let x = Scalar::<E>::random();
+        let statement = Point::generator() * &x;
+        let mut proofs: Vec<DLogProof<E>> = (0..32).map(|_| DLogProof::prove(&x)).collect();
+        assert!(DLogProof::batch_verify_same_statement(&proofs, &statement).is_ok());
+
+        let mut tampered = proofs.clone();
+        tampered[17].challenge_response = &tampered[17].challenge_response + Scalar::from(1);
+        assert!(DLogProof::verify(&tampered[17]).is_err());
+        assert!(DLogProof::batch_verify_same_statement(&tampered, &statement).is_err());
+
+        // Valid proof of another statement
+        proofs[5] = DLogProof::prove(&Scalar::random());
+        assert!(DLogProof::batch_verify_same_statement(&proofs, &statement).is_err());
+    }
+
+    crate::test_for_all_curves!(test_dlog_proof_with_beacon);
+    fn test_dlog_proof_with_beacon<E: Curve>() {
+        let proof = DLogProof::<E>::prove_with_beacon(&Scalar::random(), b"beacon A");
+        assert!(proof.verify_with_beacon(b"beacon A").is_ok());
+        assert!(proof.verify_with_beacon(b"beacon B").is_err());
+        assert!(proof.verify_with_beacon(b"").is_err());
+        assert!(DLogProof::verify(&proof).is_err());
+
+        // Proof without beacon doesn't verify under any beacon
+        let proof = DLogProof::<E>::prove(&Scalar::random());
+        assert!(proof.verify_with_beacon(b"").is_err());
+    }
+
+    crate::test_for_all_curves!(test_dlog_proof_with_keccak);
+    fn test_dlog_proof_with_keccak<E: Curve>() {
+        use sha3::Keccak256;
+
+        let proof = DLogProof::<E, Keccak256>::prove(&Scalar::random());
+        assert!(DLogProof::verify(&proof).is_ok());
+
+        // Proof made with Keccak256 doesn't verify under default hash function
+        let proof = DLogProof::<E, Sha256>::new(
+            proof.pk,
+            proof.pk_t_rand_commitment,
+            proof.challenge_response,
+        );
+        assert!(DLogProof::verify(&proof).is_err());
+    }
+
+    crate::test_for_all_curves!(test_dlog_proof_with_base);
+    fn test_dlog_proof_with_base<E: Curve>() {
+        let base = Point::<E>::generator() * Scalar::random();
+        let witness = Scalar::random();
+        let pk = &base * &witness;
+        let dlog_proof = DLogProof::<E>::prove_with_base(&witness, &base);
+        assert!(dlog_proof.verify_with_base(&pk, &base).is_ok());
+    }
+
+    crate::test_for_all_curves!(test_dlog_proof_with_wrong_base);
+    fn test_dlog_proof_with_wrong_base<E: Curve>() {
+        let base = Point::<E>::generator() * Scalar::random();
+        let other_base = Point::<E>::generator() * Scalar::random();
+        let witness = Scalar::random();
+        let pk = &base * &witness;
+        let dlog_proof = DLogProof::<E>::prove_with_base(&witness, &base);
+        assert!(dlog_proof.verify_with_base(&pk, &other_base).is_err());
+        assert!(DLogProof::verify(&dlog_proof).is_err());
+    }
+
+    crate::test_for_all_curves!(test_dlog_proof_bytes_round_trip);
+    fn test_dlog_proof_bytes_round_trip<E: Curve>() {
+        let proof = DLogProof::<E>::prove(&Scalar::random());
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), proof.serialized_size());
+
+        let proof_deserialized = DLogProof::<E>::from_bytes(&bytes).unwrap();
+        assert_eq!(proof, proof_deserialized);
+        assert!(DLogProof::verify(&proof_deserialized).is_ok());
+
+        for len in [0, 1, bytes.len() - 1] {
+            assert!(DLogProof::<E>::from_bytes(&bytes[..len]).is_err());
+        }
+        let mut extended = bytes;
+        extended.push(0);
+        assert!(DLogProof::<E>::from_bytes(&extended).is_err());
+    }
+
+    crate::test_for_all_curves!(test_shared_witness_dlog_proof);
+    fn test_shared_witness_dlog_proof<E: Curve>() {
+        let bases: Vec<Point<E>> = (0..3)
+            .map(|_| Point::generator() * Scalar::random())
+            .collect();
+        let x = Scalar::random();
+        let statements: Vec<Point<E>> = bases.iter().map(|g| g * &x).collect();
+
+        let proof = DLogProof::prove_shared_witness(&x, &bases);
+        assert!(proof.verify_shared_witness(&statements, &bases).is_ok());
+        assert!(proof
+            .verify_shared_witness(&statements[..2], &bases[..2])
+            .is_err());
+    }
+
+    crate::test_for_all_curves!(test_shared_witness_dlog_proof_with_different_witnesses);
+    fn test_shared_witness_dlog_proof_with_different_witnesses<E: Curve>() {
+        let bases: Vec<Point<E>> = (0..3)
+            .map(|_| Point::generator() * Scalar::random())
+            .collect();
+        let x = Scalar::random();
+        let mut statements: Vec<Point<E>> = bases.iter().map(|g| g * &x).collect();
+        statements[1] = &bases[1] * Scalar::random();
+
+        let proof = DLogProof::prove_shared_witness(&x, &bases);
+        assert!(proof.verify_shared_witness(&statements, &bases).is_err());
+    }
+
+    #[test]
+    fn test_dlog_proofs_on_different_curves_in_one_function() {
+        use crate::elliptic::curves::{Ed25519, Secp256k1};
+
+        let secp256k1_proof = DLogProof::<Secp256k1>::prove(&Scalar::random());
+        let ed25519_proof = DLogProof::<Ed25519>::prove(&Scalar::random());
+        assert!(DLogProof::verify(&secp256k1_proof).is_ok());
+        assert!(DLogProof::verify(&ed25519_proof).is_ok());
+    }
 }
@@ -11,7 +11,7 @@ use sha2::Sha256;
 use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
 use crate::elliptic::curves::{Curve, Point, Scalar};
 
-use super::ProofError;
+use super::{point_size, scalar_size, ProofError, ProofSize};
 
 /// This protocol is the elliptic curve form of the protocol from :
 ///  D. Chaum, T. P. Pedersen. Transferred cash grows in size. In Advances in Cryptology, EUROCRYPT , volume 658 of Lecture Notes in Computer Science, pages 390 - 407, 1993.
@@ -82,6 +82,24 @@ impl<E: Curve> ECDDHProof<E> {
             Err(ProofError)
         }
     }
+
+    /// Serializes the proof into fixed-layout byte array
+    ///
+    /// Layout is `a1 || a2 || z`, where points are compressed and scalar is encoded in fixed
+    /// number of bytes (see [ProofSize]).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.serialized_size());
+        bytes.extend_from_slice(&self.a1.to_bytes(true));
+        bytes.extend_from_slice(&self.a2.to_bytes(true));
+        bytes.extend_from_slice(&self.z.to_bytes());
+        bytes
+    }
+}
+
+impl<E: Curve> ProofSize for ECDDHProof<E> {
+    fn serialized_size(&self) -> usize {
+        2 * point_size::<E>() + scalar_size::<E>()
+    }
 }
 
 #[cfg(test)]
@@ -126,4 +144,17 @@ mod tests {
         let proof = ECDDHProof::prove(&w, &delta);
         assert!(!proof.verify(&delta).is_ok());
     }
+
+    test_for_all_curves!(test_ecddh_proof_serialized_size);
+    fn test_ecddh_proof_serialized_size<E: Curve>() {
+        let x = Scalar::<E>::random();
+        let delta = ECDDHStatement {
+            g1: Point::generator().to_point(),
+            h1: Point::generator() * &x,
+            g2: Point::base_point2().clone(),
+            h2: Point::base_point2() * &x,
+        };
+        let proof = ECDDHProof::prove(&ECDDHWitness { x }, &delta);
+        assert_eq!(proof.serialized_size(), proof.to_bytes().len());
+    }
 }
@@ -0,0 +1,140 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::ProofError;
+
+/// Proof that exponential ElGamal ciphertext and Pedersen commitment hide the same value
+///
+/// Ciphertext is a pair (D, E) where D = v*g + r_elg*pk, E = r_elg*g (same notation as in
+/// [HomoELGamalProof](super::sigma_correct_homomorphic_elgamal_enc::HomoELGamalProof)), commitment
+/// is C = v*g + r_ped*h. The witness is (v, r_elg, r_ped), the statement is (D, E, C, pk, g, h).
+/// The protocol:
+/// 1: prover chooses random s_v, s_elg, s_ped and computes
+///    A1 = s_v*g + s_elg*pk, A2 = s_elg*g, A3 = s_v*g + s_ped*h
+/// 2: prover calculates challenge e = H(g, h, pk, D, E, C, A1, A2, A3)
+/// 3: prover calculates z_v = s_v + e*v, z_elg = s_elg + e*r_elg, z_ped = s_ped + e*r_ped
+/// 4: verifier checks that z_v*g + z_elg*pk = A1 + e*D, z_elg*g = A2 + e*E, and
+///    z_v*g + z_ped*h = A3 + e*C
+///
+/// Response z_v is shared by the first and the last equations, which ties the value across the
+/// two schemes.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ElGamalPedersenEqProof<E: Curve> {
+    pub a1: Point<E>,
+    pub a2: Point<E>,
+    pub a3: Point<E>,
+    pub z_v: Scalar<E>,
+    pub z_elg: Scalar<E>,
+    pub z_ped: Scalar<E>,
+}
+
+impl<E: Curve> ElGamalPedersenEqProof<E> {
+    pub fn prove(
+        v: &Scalar<E>,
+        r_elg: &Scalar<E>,
+        r_ped: &Scalar<E>,
+        pk: &Point<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> ElGamalPedersenEqProof<E> {
+        let ct = (g * v + pk * r_elg, g * r_elg);
+        let c = g * v + h * r_ped;
+
+        let s_v = Scalar::random();
+        let s_elg = Scalar::random();
+        let s_ped = Scalar::random();
+        let a1 = g * &s_v + pk * &s_elg;
+        let a2 = g * &s_elg;
+        let a3 = g * &s_v + h * &s_ped;
+
+        let e = challenge(&ct, &c, pk, g, h, [&a1, &a2, &a3]);
+        ElGamalPedersenEqProof {
+            a1,
+            a2,
+            a3,
+            z_v: s_v + &e * v,
+            z_elg: s_elg + &e * r_elg,
+            z_ped: s_ped + &e * r_ped,
+        }
+    }
+
+    pub fn verify(
+        &self,
+        ct: &(Point<E>, Point<E>),
+        c: &Point<E>,
+        pk: &Point<E>,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> Result<(), ProofError> {
+        let e = challenge(ct, c, pk, g, h, [&self.a1, &self.a2, &self.a3]);
+        let z_v_g = g * &self.z_v;
+        let check1 = &z_v_g + pk * &self.z_elg == &self.a1 + &ct.0 * &e;
+        let check2 = g * &self.z_elg == &self.a2 + &ct.1 * &e;
+        let check3 = z_v_g + h * &self.z_ped == &self.a3 + c * &e;
+        if check1 && check2 && check3 {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+fn challenge<E: Curve>(
+    ct: &(Point<E>, Point<E>),
+    c: &Point<E>,
+    pk: &Point<E>,
+    g: &Point<E>,
+    h: &Point<E>,
+    a: [&Point<E>; 3],
+) -> Scalar<E> {
+    Sha256::new()
+        .chain_points([g, h, pk, &ct.0, &ct.1, c])
+        .chain_points(a)
+        .result_scalar()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_consistent_ciphertext_and_commitment);
+    fn test_consistent_ciphertext_and_commitment<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let pk = &g * Scalar::random();
+        let (v, r_elg, r_ped) = (Scalar::random(), Scalar::random(), Scalar::random());
+        let ct = (&g * &v + &pk * &r_elg, &g * &r_elg);
+        let c = &g * &v + &h * &r_ped;
+
+        let proof = ElGamalPedersenEqProof::prove(&v, &r_elg, &r_ped, &pk, &g, &h);
+        assert!(proof.verify(&ct, &c, &pk, &g, &h).is_ok());
+    }
+
+    test_for_all_curves!(test_inconsistent_ciphertext_and_commitment);
+    fn test_inconsistent_ciphertext_and_commitment<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let pk = &g * Scalar::random();
+        let (v, r_elg, r_ped) = (Scalar::random(), Scalar::random(), Scalar::random());
+        let ct = (&g * &v + &pk * &r_elg, &g * &r_elg);
+        let other_v = &v + Scalar::from(1);
+        let c = &g * &other_v + &h * &r_ped;
+
+        let proof = ElGamalPedersenEqProof::prove(&v, &r_elg, &r_ped, &pk, &g, &h);
+        assert!(proof.verify(&ct, &c, &pk, &g, &h).is_err());
+        let proof = ElGamalPedersenEqProof::prove(&other_v, &r_elg, &r_ped, &pk, &g, &h);
+        assert!(proof.verify(&ct, &c, &pk, &g, &h).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::sigma_ec_ddh::{ECDDHProof, ECDDHStatement, ECDDHWitness};
+use super::ProofError;
+
+/// Proof of correct re-encryption of homomorphic ElGamal ciphertext
+///
+/// Ciphertext is a pair (D, E) where D = xG + rY, E = rG, and Y is the public key (same
+/// notation as in [HomoELGamalProof](super::sigma_correct_homomorphic_elgamal_enc::HomoELGamalProof)).
+/// Re-encryption adds encryption of zero: (D', E') = (D + δY, E + δG), so the plaintext is
+/// preserved while randomness is refreshed.
+///
+/// The statement is (G, Y, (D, E), (D', E')), the witness is δ. The relation outputs 1 if
+/// E' - E = δG and D' - D = δY, ie. (G, E' - E, Y, D' - D) is a DDH tuple, which is proven using
+/// [ECDDHProof].
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ReencryptionProof<E: Curve> {
+    pub ddh_proof: ECDDHProof<E>,
+}
+
+impl<E: Curve> ReencryptionProof<E> {
+    pub fn prove(
+        ct_in: &(Point<E>, Point<E>),
+        ct_out: &(Point<E>, Point<E>),
+        pk: &Point<E>,
+        delta_r: &Scalar<E>,
+    ) -> ReencryptionProof<E> {
+        let ddh_proof = ECDDHProof::prove(
+            &ECDDHWitness { x: delta_r.clone() },
+            &statement(ct_in, ct_out, pk),
+        );
+        ReencryptionProof { ddh_proof }
+    }
+
+    pub fn verify(
+        &self,
+        ct_in: &(Point<E>, Point<E>),
+        ct_out: &(Point<E>, Point<E>),
+        pk: &Point<E>,
+    ) -> Result<(), ProofError> {
+        self.ddh_proof.verify(&statement(ct_in, ct_out, pk))
+    }
+}
+
+fn statement<E: Curve>(
+    ct_in: &(Point<E>, Point<E>),
+    ct_out: &(Point<E>, Point<E>),
+    pk: &Point<E>,
+) -> ECDDHStatement<E> {
+    ECDDHStatement {
+        g1: Point::generator().to_point(),
+        h1: &ct_out.1 - &ct_in.1,
+        g2: pk.clone(),
+        h2: &ct_out.0 - &ct_in.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    fn encrypt<E: Curve>(x: &Scalar<E>, r: &Scalar<E>, pk: &Point<E>) -> (Point<E>, Point<E>) {
+        let g = Point::<E>::generator();
+        (g * x + pk * r, g * r)
+    }
+
+    test_for_all_curves!(test_correct_reencryption);
+    fn test_correct_reencryption<E: Curve>() {
+        let pk = Point::<E>::generator() * Scalar::random();
+        let ct_in = encrypt(&Scalar::random(), &Scalar::random(), &pk);
+
+        let delta_r = Scalar::random();
+        let zero = encrypt(&Scalar::zero(), &delta_r, &pk);
+        let ct_out = (&ct_in.0 + &zero.0, &ct_in.1 + &zero.1);
+
+        let proof = ReencryptionProof::prove(&ct_in, &ct_out, &pk, &delta_r);
+        assert!(proof.verify(&ct_in, &ct_out, &pk).is_ok());
+    }
+
+    test_for_all_curves!(test_reencryption_of_different_message);
+    fn test_reencryption_of_different_message<E: Curve>() {
+        let pk = Point::<E>::generator() * Scalar::random();
+        let ct_in = encrypt(&Scalar::random(), &Scalar::random(), &pk);
+
+        let delta_r = Scalar::random();
+        let non_zero = encrypt(&Scalar::from(1), &delta_r, &pk);
+        let ct_out = (&ct_in.0 + &non_zero.0, &ct_in.1 + &non_zero.1);
+
+        let proof = ReencryptionProof::prove(&ct_in, &ct_out, &pk, &delta_r);
+        assert!(proof.verify(&ct_in, &ct_out, &pk).is_err());
+    }
+}
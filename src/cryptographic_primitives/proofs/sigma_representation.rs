@@ -0,0 +1,112 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::ProofError;
+
+/// Proof of knowledge of representation of a point with respect to several bases
+///
+/// Generalization of Schnorr's proof (see [DLogProof](super::sigma_dlog::DLogProof)) to multiple
+/// bases: witness is (x_1, ..., x_k), statement is (P, g_1, ..., g_k). The relation outputs 1 if
+/// P = x_1 * g_1 + ... + x_k * g_k. The protocol:
+/// 1: prover chooses random s_1, ..., s_k and computes A = s_1 * g_1 + ... + s_k * g_k
+/// 2: prover calculates challenge e = H(g_1, ..., g_k, P, A)
+/// 3: prover calculates z_i = s_i + e * x_i for every i
+/// 4: verifier checks that z_1 * g_1 + ... + z_k * g_k = A + e * P
+///
+/// Pedersen opening proof is a special case with k = 2.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct RepresentationProof<E: Curve> {
+    pub a: Point<E>,
+    pub z: Vec<Scalar<E>>,
+}
+
+impl<E: Curve> RepresentationProof<E> {
+    /// Proves knowledge of `witnesses` such that `P = sum witnesses[i] * bases[i]`
+    ///
+    /// ## Panics
+    /// Panics if `witnesses` and `bases` have different lengths or are empty.
+    pub fn prove(witnesses: &[Scalar<E>], bases: &[Point<E>]) -> RepresentationProof<E> {
+        assert_eq!(witnesses.len(), bases.len());
+        assert!(!bases.is_empty());
+
+        let p = linear_combination(witnesses, bases);
+        let s: Vec<Scalar<E>> = witnesses.iter().map(|_| Scalar::random()).collect();
+        let a = linear_combination(&s, bases);
+        let e = challenge(&p, bases, &a);
+        let z = s
+            .iter()
+            .zip(witnesses)
+            .map(|(s_i, x_i)| s_i + &e * x_i)
+            .collect();
+        RepresentationProof { a, z }
+    }
+
+    pub fn verify(&self, p: &Point<E>, bases: &[Point<E>]) -> Result<(), ProofError> {
+        if bases.is_empty() || self.z.len() != bases.len() {
+            return Err(ProofError);
+        }
+        let e = challenge(p, bases, &self.a);
+        if linear_combination(&self.z, bases) == &self.a + p * &e {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+fn linear_combination<E: Curve>(scalars: &[Scalar<E>], points: &[Point<E>]) -> Point<E> {
+    scalars.iter().zip(points).map(|(s, p)| p * s).sum()
+}
+
+fn challenge<E: Curve>(p: &Point<E>, bases: &[Point<E>], a: &Point<E>) -> Scalar<E> {
+    Sha256::new()
+        .chain_points(bases)
+        .chain_points([p, a])
+        .result_scalar()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    fn random_bases<E: Curve>(k: usize) -> Vec<Point<E>> {
+        (0..k)
+            .map(|_| Point::generator() * Scalar::random())
+            .collect()
+    }
+
+    test_for_all_curves!(test_representation_proof);
+    fn test_representation_proof<E: Curve>() {
+        let bases = random_bases::<E>(3);
+        let witnesses: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let p = linear_combination(&witnesses, &bases);
+
+        let proof = RepresentationProof::prove(&witnesses, &bases);
+        assert!(proof.verify(&p, &bases).is_ok());
+        assert!(proof.verify(&p, &bases[..2]).is_err());
+    }
+
+    test_for_all_curves!(test_representation_proof_wrong_witness);
+    fn test_representation_proof_wrong_witness<E: Curve>() {
+        let bases = random_bases::<E>(3);
+        let witnesses: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let p = linear_combination(&witnesses, &bases);
+
+        let mut wrong_witnesses = witnesses;
+        wrong_witnesses[1] = Scalar::random();
+        let proof = RepresentationProof::prove(&wrong_witnesses, &bases);
+        assert!(proof.verify(&p, &bases).is_err());
+    }
+}
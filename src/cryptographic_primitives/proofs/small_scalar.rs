@@ -0,0 +1,230 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::BigInt;
+
+use super::ProofError;
+
+/// Proof that Pedersen commitment C = x*g + r*h hides a small value 0 <= x < 2^k
+///
+/// The value is decomposed into bits x = sum 2^i * b_i, and every bit is committed separately:
+/// C_i = b_i*g + r_i*h, where blinding factors are chosen so that sum 2^i * r_i = r, and so
+/// sum 2^i * C_i = C. For every bit commitment the prover shows that either C_i = r_i*h or
+/// C_i - g = r_i*h, using OR-composition of Schnorr proofs with base h (R. Cramer, I. Damgård,
+/// B. Schoenmakers. Proofs of partial knowledge and simplified design of witness hiding
+/// protocols. CRYPTO 1994).
+///
+/// The proof size is linear in k. Soundness relies on the discrete log of h with respect to g
+/// being unknown.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct SmallScalarProof<E: Curve> {
+    pub bit_commitments: Vec<Point<E>>,
+    pub bit_proofs: Vec<BitProof<E>>,
+}
+
+/// Proof that a commitment hides either 0 or 1, see [SmallScalarProof]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct BitProof<E: Curve> {
+    a0: Point<E>,
+    a1: Point<E>,
+    e0: Scalar<E>,
+    z0: Scalar<E>,
+    z1: Scalar<E>,
+}
+
+impl<E: Curve> SmallScalarProof<E> {
+    /// Proves that `C = x*g + r*h` commits to `x < 2^k`
+    ///
+    /// Only the lowest `k` bits of `x` are proven, so if `x >= 2^k` the resulting proof doesn't
+    /// verify.
+    ///
+    /// ## Panics
+    /// Panics if `k` is zero or not less than bit length of the group order.
+    pub fn prove(
+        x: &Scalar<E>,
+        r: &Scalar<E>,
+        k: usize,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> SmallScalarProof<E> {
+        assert!(k > 0 && k < Scalar::<E>::group_order().bit_length());
+        let x = x.to_bigint();
+
+        // r_i are random except for the last one, which is fixed by sum 2^i * r_i = r
+        let mut blindings: Vec<Scalar<E>> = (0..k - 1).map(|_| Scalar::random()).collect();
+        let partial_sum = blindings
+            .iter()
+            .enumerate()
+            .fold(Scalar::zero(), |acc, (i, r_i)| {
+                acc + power_of_two::<E>(i) * r_i
+            });
+        let last_weight_inv = power_of_two::<E>(k - 1)
+            .invert()
+            .expect("power of two is invertible");
+        blindings.push((r - partial_sum) * last_weight_inv);
+
+        let (bit_commitments, bit_proofs) = blindings
+            .iter()
+            .enumerate()
+            .map(|(i, r_i)| {
+                let bit = x.test_bit(i);
+                let c_i = if bit { g + h * r_i } else { h * r_i };
+                let proof = BitProof::prove(bit, r_i, &c_i, g, h);
+                (c_i, proof)
+            })
+            .unzip();
+        SmallScalarProof {
+            bit_commitments,
+            bit_proofs,
+        }
+    }
+
+    pub fn verify(
+        &self,
+        c: &Point<E>,
+        k: usize,
+        g: &Point<E>,
+        h: &Point<E>,
+    ) -> Result<(), ProofError> {
+        if k == 0
+            || k >= Scalar::<E>::group_order().bit_length()
+            || self.bit_commitments.len() != k
+            || self.bit_proofs.len() != k
+        {
+            return Err(ProofError);
+        }
+        for (c_i, proof) in self.bit_commitments.iter().zip(&self.bit_proofs) {
+            proof.verify(c_i, g, h)?;
+        }
+        let sum = self
+            .bit_commitments
+            .iter()
+            .enumerate()
+            .fold(Point::zero(), |acc, (i, c_i)| {
+                acc + c_i * power_of_two::<E>(i)
+            });
+        if sum == *c {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+impl<E: Curve> BitProof<E> {
+    fn prove(bit: bool, r: &Scalar<E>, c: &Point<E>, g: &Point<E>, h: &Point<E>) -> BitProof<E> {
+        // Branch matching the bit is proven honestly, the other one is simulated
+        let simulated_statement = if bit { c.clone() } else { c - g };
+        let e_sim = Scalar::random();
+        let z_sim = Scalar::random();
+        let a_sim = h * &z_sim - simulated_statement * &e_sim;
+
+        let s = Scalar::random();
+        let a_real = h * &s;
+
+        let (a0, a1) = if bit {
+            (a_sim, a_real)
+        } else {
+            (a_real, a_sim)
+        };
+        let e = challenge(c, g, h, &a0, &a1);
+        let e_real = e - &e_sim;
+        let z_real = s + &e_real * r;
+
+        if bit {
+            BitProof {
+                a0,
+                a1,
+                e0: e_sim,
+                z0: z_sim,
+                z1: z_real,
+            }
+        } else {
+            BitProof {
+                a0,
+                a1,
+                e0: e_real,
+                z0: z_real,
+                z1: z_sim,
+            }
+        }
+    }
+
+    fn verify(&self, c: &Point<E>, g: &Point<E>, h: &Point<E>) -> Result<(), ProofError> {
+        let e = challenge(c, g, h, &self.a0, &self.a1);
+        let e1 = e - &self.e0;
+        let branch0 = h * &self.z0 == &self.a0 + c * &self.e0;
+        let branch1 = h * &self.z1 == &self.a1 + (c - g) * &e1;
+        if branch0 && branch1 {
+            Ok(())
+        } else {
+            Err(ProofError)
+        }
+    }
+}
+
+fn challenge<E: Curve>(
+    c: &Point<E>,
+    g: &Point<E>,
+    h: &Point<E>,
+    a0: &Point<E>,
+    a1: &Point<E>,
+) -> Scalar<E> {
+    Sha256::new()
+        .chain_points([g, h, c, a0, a1])
+        .result_scalar()
+}
+
+fn power_of_two<E: Curve>(i: usize) -> Scalar<E> {
+    Scalar::from_bigint(&(BigInt::one() << i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    fn commit<E: Curve>(x: &Scalar<E>, r: &Scalar<E>, g: &Point<E>, h: &Point<E>) -> Point<E> {
+        g * x + h * r
+    }
+
+    test_for_all_curves!(test_max_small_scalar);
+    fn test_max_small_scalar<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let k = 8;
+        let x = Scalar::from(255);
+        let r = Scalar::random();
+        let c = commit(&x, &r, &g, &h);
+
+        let proof = SmallScalarProof::prove(&x, &r, k, &g, &h);
+        assert!(proof.verify(&c, k, &g, &h).is_ok());
+        assert!(proof.verify(&c, k - 1, &g, &h).is_err());
+        assert!(proof.verify(&(&c + &g), k, &g, &h).is_err());
+    }
+
+    test_for_all_curves!(test_out_of_range_scalar);
+    fn test_out_of_range_scalar<E: Curve>() {
+        let g = Point::<E>::generator().to_point();
+        let h = Point::<E>::base_point2().clone();
+        let k = 8;
+        let x = Scalar::from(256);
+        let r = Scalar::random();
+        let c = commit(&x, &r, &g, &h);
+
+        let proof = SmallScalarProof::prove(&x, &r, k, &g, &h);
+        assert!(proof.verify(&c, k, &g, &h).is_err());
+    }
+}
@@ -0,0 +1,307 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::BigInt;
+
+use super::sigma_ec_ddh::{ECDDHProof, ECDDHStatement, ECDDHWitness};
+use super::ProofError;
+
+/// Verifiable encryption of a discrete log under recipient public key Y.
+///
+/// The statement is (G, Y, Q), the witness is x such that Q = xG. Secret x is decomposed into bits
+/// b_k, each bit is encrypted with ElGamal "in the exponent": D_k = b_kG + r_kY, E_k = r_kG.
+/// Recipient knowing y (Y = yG) recovers each bit by checking whether D_k - yE_k equals O or G.
+///
+/// The proof consists of:
+/// 1: for each k, OR-proof that (G, E_k, Y, D_k) or (G, E_k, Y, D_k - G) is a DDH tuple, ie. that
+///    b_k ∈ {0, 1}
+/// 2: proof that (G, sum 2^k E_k, Y, sum 2^k D_k - Q) is a DDH tuple with witness R = sum 2^k r_k,
+///    ie. that sum 2^k b_k = x
+///
+/// verifier is convinced that decrypting the ciphertext yields x such that Q = xG
+pub struct VerifiableEncryption;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifiableEncryptionCiphertext<E: Curve> {
+    pub d: Vec<Point<E>>,
+    pub e: Vec<Point<E>>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct VerifiableEncryptionProof<E: Curve> {
+    pub bit_proofs: Vec<BitEncryptionProof<E>>,
+    pub sum_proof: ECDDHProof<E>,
+}
+
+/// Chaum-Pedersen OR-proof that ElGamal ciphertext (D, E) encrypts either 0 or 1
+///
+/// For each branch j ∈ {0, 1} the relation is E = rG, D - jG = rY. Prover simulates the branch
+/// that doesn't correspond to the encrypted bit by choosing c_j, z_j at random and setting
+/// A1_j = z_jG - c_jE, A2_j = z_jY - c_j(D - jG). Challenges of both branches sum up to
+/// e = H(G, Y, D, E, A1_0, A1_1, A2_0, A2_1).
+///
+/// verifier computes c_1 = e - c_0, and checks that z_jG = A1_j + c_jE, z_jY = A2_j + c_j(D - jG)
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct BitEncryptionProof<E: Curve> {
+    pub a1: [Point<E>; 2],
+    pub a2: [Point<E>; 2],
+    pub c0: Scalar<E>,
+    pub z: [Scalar<E>; 2],
+}
+
+impl VerifiableEncryption {
+    pub fn encrypt<E: Curve>(
+        secret: &Scalar<E>,
+        recipient_pk: &Point<E>,
+        statement: &Point<E>,
+    ) -> (
+        VerifiableEncryptionCiphertext<E>,
+        VerifiableEncryptionProof<E>,
+    ) {
+        let g = Point::<E>::generator().to_point();
+        let x = secret.to_bigint();
+
+        let mut d = vec![];
+        let mut e = vec![];
+        let mut bit_proofs = vec![];
+        let mut r_sum = Scalar::<E>::zero();
+        for k in 0..bits_count::<E>() {
+            let bit = x.test_bit(k);
+            let r = Scalar::<E>::random();
+            let d_k = if bit {
+                &g + recipient_pk * &r
+            } else {
+                recipient_pk * &r
+            };
+            let e_k = &g * &r;
+            bit_proofs.push(BitEncryptionProof::prove(bit, &r, recipient_pk, &d_k, &e_k));
+            r_sum = r_sum + r * power_of_two(k);
+            d.push(d_k);
+            e.push(e_k);
+        }
+
+        let ct = VerifiableEncryptionCiphertext { d, e };
+        let sum_proof = ECDDHProof::prove(
+            &ECDDHWitness { x: r_sum },
+            &sum_statement(&ct, recipient_pk, statement),
+        );
+        (
+            ct,
+            VerifiableEncryptionProof {
+                bit_proofs,
+                sum_proof,
+            },
+        )
+    }
+
+    pub fn verify<E: Curve>(
+        ct: &VerifiableEncryptionCiphertext<E>,
+        proof: &VerifiableEncryptionProof<E>,
+        recipient_pk: &Point<E>,
+        statement: &Point<E>,
+    ) -> Result<(), ProofError> {
+        let n = bits_count::<E>();
+        if ct.d.len() != n || ct.e.len() != n || proof.bit_proofs.len() != n {
+            return Err(ProofError);
+        }
+        for ((d_k, e_k), bit_proof) in ct.d.iter().zip(&ct.e).zip(&proof.bit_proofs) {
+            bit_proof.verify(recipient_pk, d_k, e_k)?;
+        }
+        proof
+            .sum_proof
+            .verify(&sum_statement(ct, recipient_pk, statement))
+    }
+}
+
+impl<E: Curve> VerifiableEncryptionCiphertext<E> {
+    /// Decrypts the ciphertext using recipient secret key
+    ///
+    /// Returns `None` if some of encrypted values is neither 0 nor 1
+    pub fn decrypt(&self, secret_key: &Scalar<E>) -> Option<Scalar<E>> {
+        let g = Point::<E>::generator();
+        let mut x = Scalar::<E>::zero();
+        for (k, (d_k, e_k)) in self.d.iter().zip(&self.e).enumerate() {
+            let m = d_k - e_k * secret_key;
+            if m == *g {
+                x = x + power_of_two(k);
+            } else if !m.is_zero() {
+                return None;
+            }
+        }
+        Some(x)
+    }
+}
+
+impl<E: Curve> BitEncryptionProof<E> {
+    fn prove(
+        bit: bool,
+        r: &Scalar<E>,
+        y: &Point<E>,
+        d: &Point<E>,
+        e: &Point<E>,
+    ) -> BitEncryptionProof<E> {
+        let g = Point::<E>::generator().to_point();
+        let real = bit as usize;
+        let fake = 1 - real;
+
+        let s = Scalar::<E>::random();
+        let c_fake = Scalar::<E>::random();
+        let z_fake = Scalar::<E>::random();
+
+        let mut a1: [Point<E>; 2] = [Point::zero(), Point::zero()];
+        let mut a2: [Point<E>; 2] = [Point::zero(), Point::zero()];
+        a1[real] = &g * &s;
+        a2[real] = y * &s;
+        a1[fake] = &g * &z_fake - e * &c_fake;
+        a2[fake] = y * &z_fake - shifted(d, fake) * &c_fake;
+
+        let challenge = bit_challenge(y, d, e, &a1, &a2);
+        let c_real = challenge - &c_fake;
+        let z_real = &s + &c_real * r;
+
+        let (c0, z) = if bit {
+            (c_fake, [z_fake, z_real])
+        } else {
+            (c_real, [z_real, z_fake])
+        };
+        BitEncryptionProof { a1, a2, c0, z }
+    }
+
+    fn verify(&self, y: &Point<E>, d: &Point<E>, e: &Point<E>) -> Result<(), ProofError> {
+        let g = Point::<E>::generator();
+        let challenge = bit_challenge(y, d, e, &self.a1, &self.a2);
+        let c = [self.c0.clone(), challenge - &self.c0];
+        for (j, c_j) in c.iter().enumerate() {
+            let a1_check = g * &self.z[j] == &self.a1[j] + e * c_j;
+            let a2_check = y * &self.z[j] == &self.a2[j] + shifted(d, j) * c_j;
+            if !a1_check || !a2_check {
+                return Err(ProofError);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns `D - jG`
+fn shifted<E: Curve>(d: &Point<E>, j: usize) -> Point<E> {
+    if j == 0 {
+        d.clone()
+    } else {
+        d - Point::generator()
+    }
+}
+
+fn bit_challenge<E: Curve>(
+    y: &Point<E>,
+    d: &Point<E>,
+    e: &Point<E>,
+    a1: &[Point<E>; 2],
+    a2: &[Point<E>; 2],
+) -> Scalar<E> {
+    Sha256::new()
+        .chain_points([&Point::generator().to_point(), y, d, e])
+        .chain_points(a1)
+        .chain_points(a2)
+        .result_scalar()
+}
+
+fn sum_statement<E: Curve>(
+    ct: &VerifiableEncryptionCiphertext<E>,
+    recipient_pk: &Point<E>,
+    statement: &Point<E>,
+) -> ECDDHStatement<E> {
+    let weighted_sum = |points: &[Point<E>]| -> Point<E> {
+        points
+            .iter()
+            .enumerate()
+            .map(|(k, p)| p * power_of_two(k))
+            .sum()
+    };
+    ECDDHStatement {
+        g1: Point::generator().to_point(),
+        h1: weighted_sum(&ct.e),
+        g2: recipient_pk.clone(),
+        h2: weighted_sum(&ct.d) - statement,
+    }
+}
+
+fn bits_count<E: Curve>() -> usize {
+    Scalar::<E>::group_order().bit_length()
+}
+
+fn power_of_two<E: Curve>(k: usize) -> Scalar<E> {
+    Scalar::from(BigInt::one() << k)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::elliptic::curves::{Ed25519, Ristretto, Secp256k1, Secp256r1};
+
+    // Proof consists of hundreds of sigma proofs, so tests are not run for BLS curves which are
+    // too slow in debug builds
+
+    fn test_verifiable_encryption<E: Curve>() {
+        let recipient_sk = Scalar::<E>::random();
+        let recipient_pk = Point::generator() * &recipient_sk;
+        let x = Scalar::<E>::random();
+        let statement = Point::generator() * &x;
+
+        let (ct, proof) = VerifiableEncryption::encrypt(&x, &recipient_pk, &statement);
+        assert!(VerifiableEncryption::verify(&ct, &proof, &recipient_pk, &statement).is_ok());
+        assert_eq!(ct.decrypt(&recipient_sk), Some(x));
+    }
+
+    fn test_verifiable_encryption_of_wrong_secret<E: Curve>() {
+        let recipient_pk = Point::generator() * Scalar::<E>::random();
+        let x = Scalar::<E>::random();
+        let statement = Point::generator() * &x;
+
+        let wrong_x = Scalar::<E>::random();
+        let (ct, proof) = VerifiableEncryption::encrypt(&wrong_x, &recipient_pk, &statement);
+        assert!(VerifiableEncryption::verify(&ct, &proof, &recipient_pk, &statement).is_err());
+    }
+
+    #[test]
+    fn test_verifiable_encryption_secp256k1() {
+        test_verifiable_encryption::<Secp256k1>()
+    }
+
+    #[test]
+    fn test_verifiable_encryption_p256() {
+        test_verifiable_encryption::<Secp256r1>()
+    }
+
+    #[test]
+    fn test_verifiable_encryption_ed25519() {
+        test_verifiable_encryption::<Ed25519>()
+    }
+
+    #[test]
+    fn test_verifiable_encryption_ristretto() {
+        test_verifiable_encryption::<Ristretto>()
+    }
+
+    #[test]
+    fn test_verifiable_encryption_of_wrong_secret_secp256k1() {
+        test_verifiable_encryption_of_wrong_secret::<Secp256k1>()
+    }
+
+    #[test]
+    fn test_verifiable_encryption_of_wrong_secret_ed25519() {
+        test_verifiable_encryption_of_wrong_secret::<Ed25519>()
+    }
+}
@@ -0,0 +1,149 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Minimal protobuf encoding used by `to_proto_bytes`/`from_proto_bytes` methods
+//!
+//! Messages are described in `proto/curv.proto`. Only varint and length-delimited fields are
+//! written; fields of other wire types are skipped when reading, as required by proto3.
+
+use std::convert::TryFrom;
+
+use crate::arithmetic::Converter;
+use crate::cryptographic_primitives::proofs::scalar_size;
+use crate::elliptic::curves::{Curve, DeserializationError, Point, Scalar};
+use crate::BigInt;
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_FIXED64: u64 = 1;
+const WIRE_LENGTH_DELIMITED: u64 = 2;
+const WIRE_FIXED32: u64 = 5;
+
+/// Value of a decoded field
+pub(crate) enum FieldValue<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+}
+
+#[derive(Default)]
+pub(crate) struct ProtoWriter {
+    buffer: Vec<u8>,
+}
+
+impl ProtoWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn write_uint32(&mut self, field: u32, value: u32) {
+        // proto3 omits fields with default value
+        if value != 0 {
+            self.write_tag(field, WIRE_VARINT);
+            self.write_varint(u64::from(value));
+        }
+    }
+
+    pub fn write_bytes(&mut self, field: u32, bytes: &[u8]) {
+        self.write_tag(field, WIRE_LENGTH_DELIMITED);
+        self.write_varint(bytes.len() as u64);
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    pub fn write_point<E: Curve>(&mut self, field: u32, point: &Point<E>) {
+        self.write_bytes(field, &point.to_bytes(true))
+    }
+
+    pub fn write_scalar<E: Curve>(&mut self, field: u32, scalar: &Scalar<E>) {
+        self.write_bytes(field, &scalar.to_be_bytes())
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.buffer
+    }
+
+    fn write_tag(&mut self, field: u32, wire_type: u64) {
+        self.write_varint((u64::from(field) << 3) | wire_type)
+    }
+
+    fn write_varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buffer.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buffer.push(value as u8);
+    }
+}
+
+/// Splits message into `(field number, value)` pairs in order of appearance
+pub(crate) fn read_fields(
+    mut bytes: &[u8],
+) -> Result<Vec<(u32, FieldValue<'_>)>, DeserializationError> {
+    let mut fields = vec![];
+    while !bytes.is_empty() {
+        let tag = read_varint(&mut bytes)?;
+        let field = u32::try_from(tag >> 3).map_err(|_| DeserializationError)?;
+        if field == 0 {
+            return Err(DeserializationError);
+        }
+        match tag & 0b111 {
+            WIRE_VARINT => fields.push((field, FieldValue::Varint(read_varint(&mut bytes)?))),
+            WIRE_LENGTH_DELIMITED => {
+                let len =
+                    usize::try_from(read_varint(&mut bytes)?).map_err(|_| DeserializationError)?;
+                if len > bytes.len() {
+                    return Err(DeserializationError);
+                }
+                let (value, rest) = bytes.split_at(len);
+                fields.push((field, FieldValue::Bytes(value)));
+                bytes = rest;
+            }
+            WIRE_FIXED64 => bytes = bytes.get(8..).ok_or(DeserializationError)?,
+            WIRE_FIXED32 => bytes = bytes.get(4..).ok_or(DeserializationError)?,
+            _ => return Err(DeserializationError),
+        }
+    }
+    Ok(fields)
+}
+
+pub(crate) fn read_point<E: Curve>(value: &FieldValue) -> Result<Point<E>, DeserializationError> {
+    match value {
+        FieldValue::Bytes(bytes) => Point::from_bytes(bytes).map_err(|_| DeserializationError),
+        FieldValue::Varint(_) => Err(DeserializationError),
+    }
+}
+
+pub(crate) fn read_scalar<E: Curve>(value: &FieldValue) -> Result<Scalar<E>, DeserializationError> {
+    match value {
+        FieldValue::Bytes(bytes) if bytes.len() == scalar_size::<E>() => {
+            let n = BigInt::from_bytes(bytes);
+            if n >= *Scalar::<E>::group_order() {
+                return Err(DeserializationError);
+            }
+            Ok(Scalar::from_bigint(&n))
+        }
+        _ => Err(DeserializationError),
+    }
+}
+
+pub(crate) fn read_uint32(value: &FieldValue) -> Result<u32, DeserializationError> {
+    match value {
+        FieldValue::Varint(v) => u32::try_from(*v).map_err(|_| DeserializationError),
+        FieldValue::Bytes(_) => Err(DeserializationError),
+    }
+}
+
+fn read_varint(bytes: &mut &[u8]) -> Result<u64, DeserializationError> {
+    let mut value = 0u64;
+    for i in 0..10 {
+        let (&byte, rest) = bytes.split_first().ok_or(DeserializationError)?;
+        *bytes = rest;
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(DeserializationError)
+}
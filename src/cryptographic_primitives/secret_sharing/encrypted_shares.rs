@@ -0,0 +1,159 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+//! Dealer encrypts share of party `i` to its public key `Y_i` with ECIES: it samples ephemeral
+//! key `r`, publishes `R = rG`, and encrypts the share with AES-256-GCM under key derived from
+//! `R` and the shared point `rY_i`. Party `i` recovers the shared point as `y_i R`. Index of the
+//! share is authenticated along with the ciphertext, so an encrypted share can't be passed off
+//! as a share of other party.
+//!
+//! Encryption only hides shares from eavesdroppers, recipient still needs to validate decrypted
+//! share against VSS commitments (see [VerifiableSS::validate_share]).
+//!
+//! Requires `encryption` feature to be enabled.
+
+use std::convert::TryFrom;
+
+use aes_gcm::aead::{Aead, NewAead, Payload};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::ErrorSS::{self, DecryptionError};
+
+const KEY_DERIVATION_LABEL: &[u8] = b"curv.feldman_vss.encrypted_share";
+
+/// Every encryption key is derived from fresh ephemeral key, so a constant nonce is never reused
+const NONCE: [u8; 12] = [0; 12];
+
+/// Secret share encrypted to public key of its recipient
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct EncryptedShare<E: Curve> {
+    pub index: u16,
+    pub ephemeral_pk: Point<E>,
+    pub ciphertext: Vec<u8>,
+}
+
+impl<E: Curve> VerifiableSS<E> {
+    /// Shares a secret and encrypts share of party `i` to `recipient_pks[i - 1]`
+    ///
+    /// Number of shares is the number of recipients.
+    ///
+    /// ## Panics
+    /// Panics if any of the recipients' public keys is zero point.
+    pub fn share_encrypted(
+        t: u16,
+        secret: &Scalar<E>,
+        recipient_pks: &[Point<E>],
+    ) -> (VerifiableSS<E>, Vec<EncryptedShare<E>>) {
+        let n = u16::try_from(recipient_pks.len()).expect("too many recipients");
+        let (vss, shares) = Self::share(t, n, secret);
+        let encrypted = (1u16..)
+            .zip(shares.iter().zip(recipient_pks))
+            .map(|(index, (share, pk))| encrypt_share(share, index, pk))
+            .collect();
+        (vss, encrypted)
+    }
+
+    /// Decrypts share encrypted by [share_encrypted](Self::share_encrypted) with recipient's
+    /// secret key
+    ///
+    /// Fails with [DecryptionError] if the share isn't encrypted to `sk` or has been tampered with.
+    pub fn decrypt_share(
+        sk: &Scalar<E>,
+        encrypted: &EncryptedShare<E>,
+    ) -> Result<Scalar<E>, ErrorSS> {
+        let key = derive_key(&encrypted.ephemeral_pk, &(&encrypted.ephemeral_pk * sk));
+        let plaintext = Aes256Gcm::new(Key::from_slice(&key[..]))
+            .decrypt(
+                Nonce::from_slice(&NONCE),
+                Payload {
+                    msg: &encrypted.ciphertext,
+                    aad: &encrypted.index.to_be_bytes(),
+                },
+            )
+            .map(Zeroizing::new)
+            .map_err(|_| DecryptionError)?;
+        Scalar::from_bytes(&plaintext).map_err(|_| DecryptionError)
+    }
+}
+
+fn encrypt_share<E: Curve>(share: &Scalar<E>, index: u16, pk: &Point<E>) -> EncryptedShare<E> {
+    assert!(!pk.is_zero(), "recipient public key is zero point");
+    let r = Scalar::<E>::random();
+    let ephemeral_pk = Point::generator() * &r;
+    let key = derive_key(&ephemeral_pk, &(pk * &r));
+    let plaintext = Zeroizing::new(share.to_bytes().to_vec());
+    let ciphertext = Aes256Gcm::new(Key::from_slice(&key[..]))
+        .encrypt(
+            Nonce::from_slice(&NONCE),
+            Payload {
+                msg: &plaintext[..],
+                aad: &index.to_be_bytes(),
+            },
+        )
+        .expect("encryption of a scalar never fails");
+    EncryptedShare {
+        index,
+        ephemeral_pk,
+        ciphertext,
+    }
+}
+
+fn derive_key<E: Curve>(ephemeral_pk: &Point<E>, shared: &Point<E>) -> Zeroizing<Vec<u8>> {
+    Zeroizing::new(
+        Sha256::new()
+            .chain(KEY_DERIVATION_LABEL)
+            .chain_point(ephemeral_pk)
+            .chain_point(shared)
+            .finalize()
+            .to_vec(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_encrypted_shares);
+    fn test_encrypted_shares<E: Curve>() {
+        let sks: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let pks: Vec<Point<E>> = sks.iter().map(|sk| Point::generator() * sk).collect();
+        let secret = Scalar::random();
+        let (vss, encrypted) = VerifiableSS::share_encrypted(1, &secret, &pks);
+        assert_eq!(encrypted.len(), 3);
+
+        let shares: Vec<Scalar<E>> = sks
+            .iter()
+            .zip(&encrypted)
+            .map(|(sk, e)| VerifiableSS::decrypt_share(sk, e).ok().unwrap())
+            .collect();
+        for (share, e) in shares.iter().zip(&encrypted) {
+            assert!(vss.validate_share(share, e.index).is_ok());
+        }
+        assert_eq!(
+            vss.reconstruct(&[0, 2], &[shares[0].clone(), shares[2].clone()]),
+            secret
+        );
+
+        // Share can't be decrypted with a key of other recipient
+        assert!(VerifiableSS::decrypt_share(&sks[1], &encrypted[0]).is_err());
+        // Share can't be relabeled as a share of other party
+        let mut relabeled = encrypted[0].clone();
+        relabeled.index = 2;
+        assert!(VerifiableSS::decrypt_share(&sks[0], &relabeled).is_err());
+        let mut tampered = encrypted[0].clone();
+        tampered.ciphertext[0] ^= 1;
+        assert!(VerifiableSS::decrypt_share(&sks[0], &tampered).is_err());
+    }
+}
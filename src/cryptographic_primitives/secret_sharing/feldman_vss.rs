@@ -9,11 +9,21 @@
 use std::convert::{TryFrom, TryInto};
 use std::{fmt, ops};
 
+use hmac::{Hmac, Mac, NewMac};
 use serde::{Deserialize, Serialize};
+use sha2::Sha512;
 
+use crate::arithmetic::Converter;
+use crate::cryptographic_primitives::proofs::{point_size, ProofSize, LIST_LENGTH_PREFIX_SIZE};
+use crate::cryptographic_primitives::proto::{self, ProtoWriter};
 use crate::cryptographic_primitives::secret_sharing::Polynomial;
-use crate::elliptic::curves::{Curve, Point, Scalar};
-use crate::ErrorSS::{self, VerifyShareError};
+use crate::elliptic::curves::{Curve, DeserializationError, Point, Scalar};
+use crate::ErrorSS::{
+    self, CommitmentsLengthMismatch, InsufficientShares, TooLarge, VerifyShareError,
+};
+use crate::{BigInt, RandomSource};
+
+const DETERMINISTIC_SHARING_LABEL: &[u8] = b"curv.feldman_vss.share_deterministic";
 
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct ShamirSecretSharing {
@@ -48,6 +58,35 @@ pub struct SecretShares<E: Curve> {
     polynomial: Polynomial<E>,
 }
 
+/// Dealer's response to a complaint about the share of party `index`
+///
+/// Produced by [VerifiableSS::prove_evaluation] and checked by
+/// [VerifiableSS::verify_evaluation]. In Feldman VSS the commitments bind the dealer to the
+/// polynomial, so the response simply opens the disputed evaluation: anyone can check it against
+/// the public commitments, no knowledge of the polynomial is required.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct EvalProof<E: Curve> {
+    pub index: u16,
+    pub share: Scalar<E>,
+}
+
+/// Upper bounds on parameters of VSS received from untrusted party
+///
+/// Cost of validating a share grows linearly with the number of commitments, so a malicious
+/// dealer could send a sharing of enormous degree to exhaust verifier's resources. Limits are
+/// checked by [check_limits](VerifiableSS::check_limits) and
+/// [from_proto_bytes_with_limits](VerifiableSS::from_proto_bytes_with_limits) before any
+/// elliptic curve operation is performed.
+///
+/// Serde deserialization of [VerifiableSS] doesn't know the limits: VSS deserialized with serde
+/// must be passed to [check_limits](VerifiableSS::check_limits) before it's used.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VssLimits {
+    pub max_threshold: u16,
+    pub max_share_count: u16,
+}
+
 impl<E: Curve> VerifiableSS<E> {
     pub fn reconstruct_limit(&self) -> u16 {
         self.parameters.threshold + 1
@@ -57,14 +96,100 @@ impl<E: Curve> VerifiableSS<E> {
     pub fn share(t: u16, n: u16, secret: &Scalar<E>) -> (VerifiableSS<E>, SecretShares<E>) {
         assert!(t < n);
         let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
+        Self::share_polynomial(t, n, polynomial)
+    }
+
+    /// Same as [share](Self::share), but additionally returns coefficients of the sharing
+    /// polynomial, constant term (the secret) first
+    ///
+    /// Shares are distributed among parties and erased by the dealer, while coefficients can be
+    /// kept aside to reveal the whole polynomial later, eg. to prove honest dealing in a dispute.
+    /// Anyone can check revealed coefficients with [verify_polynomial](Self::verify_polynomial).
+    /// Coefficients determine the secret, so they must be kept as secret as the secret itself.
+    pub fn share_with_polynomial(
+        t: u16,
+        n: u16,
+        secret: &Scalar<E>,
+    ) -> (VerifiableSS<E>, SecretShares<E>, Vec<Scalar<E>>) {
+        let (vss, shares) = Self::share(t, n, secret);
+        let coefficients = shares.polynomial().coefficients().to_vec();
+        (vss, shares, coefficients)
+    }
+
+    /// Same as [share](Self::share), but samples the polynomial using given source of randomness
+    pub fn share_with_rng<R: RandomSource + ?Sized>(
+        t: u16,
+        n: u16,
+        secret: &Scalar<E>,
+        rng: &mut R,
+    ) -> (VerifiableSS<E>, SecretShares<E>) {
+        assert!(t < n);
+        let polynomial =
+            Polynomial::<E>::sample_exact_with_fixed_const_term_with_rng(t, secret.clone(), rng);
+        Self::share_polynomial(t, n, polynomial)
+    }
+
+    /// Same as [share](Self::share), but derives polynomial coefficients from the seed
+    ///
+    /// Coefficient $a_j$ for $j \ge 1$ is HMAC-SHA512 keyed with the seed over a domain label,
+    /// $j$ and a retry counter, reduced modulo group order (counter is incremented in the unlikely
+    /// case the result is zero). The same `(secret, seed)` always yields identical shares and
+    /// commitments, so a past sharing can be reproduced and audited.
+    ///
+    /// Seed must be kept as secret as the secret itself: anyone knowing the seed and a single
+    /// share learns the secret. Never reuse a seed to share different secrets.
+    pub fn share_deterministic(
+        t: u16,
+        n: u16,
+        secret: &Scalar<E>,
+        seed: &[u8; 32],
+    ) -> (VerifiableSS<E>, SecretShares<E>) {
+        assert!(t < n);
+        let coefficients = std::iter::once(secret.clone())
+            .chain((1..=t).map(|j| derive_coefficient(seed, j)))
+            .collect();
+        Self::share_polynomial(t, n, Polynomial::from_coefficients(coefficients))
+    }
+
+    fn share_polynomial(
+        t: u16,
+        n: u16,
+        polynomial: Polynomial<E>,
+    ) -> (VerifiableSS<E>, SecretShares<E>) {
         let shares = polynomial.evaluate_many_bigint(1..=n).collect();
 
-        let g = Point::<E>::generator();
-        let commitments = polynomial
-            .coefficients()
-            .iter()
-            .map(|coef| g * coef)
-            .collect::<Vec<_>>();
+        let commitments = commit_polynomial(&polynomial);
+        (
+            VerifiableSS {
+                parameters: ShamirSecretSharing {
+                    threshold: t,
+                    share_count: n,
+                },
+                commitments,
+            },
+            SecretShares { shares, polynomial },
+        )
+    }
+
+    /// Same as [share](Self::share), but computes commitments to polynomial coefficients in
+    /// parallel
+    ///
+    /// Commitment vector takes t+1 independent scalar multiplications, which dominates the cost
+    /// of sharing for large t. Output is identical to the output of `share` for the same polynomial.
+    ///
+    /// Requires `rayon` feature. Work is split between std scoped threads, one chunk of
+    /// coefficients per available CPU.
+    #[cfg(feature = "rayon")]
+    pub fn share_parallel(t: u16, n: u16, secret: &Scalar<E>) -> (VerifiableSS<E>, SecretShares<E>)
+    where
+        E::Point: Send,
+        E::Scalar: Sync,
+    {
+        assert!(t < n);
+        let polynomial = Polynomial::<E>::sample_exact_with_fixed_const_term(t, secret.clone());
+        let shares = polynomial.evaluate_many_bigint(1..=n).collect();
+
+        let commitments = commit_polynomial_parallel(&polynomial);
         (
             VerifiableSS {
                 parameters: ShamirSecretSharing {
@@ -168,6 +293,33 @@ impl<E: Curve> VerifiableSS<E> {
         VerifiableSS::<E>::lagrange_interpolation_at_zero(&points, shares)
     }
 
+    /// Reconstructs the secret from `(index, share)` pairs, skipping shares that don't match
+    /// the commitments
+    ///
+    /// Unlike [reconstruct](Self::reconstruct), `index` is the point at which the polynomial was
+    /// evaluated (ie. it's 1-based, as in [validate_share](Self::validate_share)). Every share
+    /// is validated against the commitments, invalid ones and repeated indexes are dropped.
+    /// Fails with [InsufficientShares] if less than
+    /// [reconstruct_limit](Self::reconstruct_limit) valid shares remain.
+    pub fn robust_reconstruct(&self, shares: &[(u16, Scalar<E>)]) -> Result<Scalar<E>, ErrorSS> {
+        let mut points = vec![];
+        let mut values = vec![];
+        for (index, share) in shares {
+            let point = Scalar::from(*index);
+            if points.contains(&point) || self.validate_share(share, *index).is_err() {
+                continue;
+            }
+            points.push(point);
+            values.push(share.clone());
+        }
+        if points.len() < usize::from(self.reconstruct_limit()) {
+            return Err(InsufficientShares);
+        }
+        Ok(VerifiableSS::<E>::lagrange_interpolation_at_zero(
+            &points, &values,
+        ))
+    }
+
     // Performs a Lagrange interpolation in field Zp at the origin
     // for a polynomial defined by `points` and `values`.
     // `points` and `values` are expected to be two arrays of the same size, containing
@@ -216,6 +368,25 @@ impl<E: Curve> VerifiableSS<E> {
         tail.fold(head.clone(), |acc, x| acc + x)
     }
 
+    /// Checks that VSS parameters are within the limits and match the number of commitments
+    ///
+    /// Performs no elliptic curve operations, so it's cheap to call on VSS received from untrusted
+    /// party before [validate_share](Self::validate_share). Fails with [TooLarge] if threshold,
+    /// number of parties, or number of commitments exceeds the limits, and with
+    /// [CommitmentsLengthMismatch] if number of commitments is not `threshold + 1`.
+    pub fn check_limits(&self, limits: &VssLimits) -> Result<(), ErrorSS> {
+        if self.parameters.threshold > limits.max_threshold
+            || self.parameters.share_count > limits.max_share_count
+            || self.commitments.len() > usize::from(limits.max_threshold) + 1
+        {
+            return Err(TooLarge);
+        }
+        if self.commitments.len() != usize::from(self.parameters.threshold) + 1 {
+            return Err(CommitmentsLengthMismatch);
+        }
+        Ok(())
+    }
+
     pub fn validate_share(&self, secret_share: &Scalar<E>, index: u16) -> Result<(), ErrorSS> {
         let g = Point::generator();
         let ss_point = g * secret_share;
@@ -231,6 +402,186 @@ impl<E: Curve> VerifiableSS<E> {
         }
     }
 
+    /// Answers a complaint of party `index` by publicly opening its share
+    ///
+    /// A dealer accused of sending a bad share publishes the share it claims to have sent. If
+    /// [verify_evaluation](Self::verify_evaluation) accepts the response, the complaint is
+    /// rejected and the accuser uses the published share, otherwise the dealer is disqualified.
+    pub fn prove_evaluation(share: &Scalar<E>, index: u16) -> EvalProof<E> {
+        EvalProof {
+            index,
+            share: share.clone(),
+        }
+    }
+
+    /// Adjudicates a complaint: checks that `claimed_share` opened by the dealer is the
+    /// evaluation of the committed polynomial at `index`
+    pub fn verify_evaluation(
+        commitments: &[Point<E>],
+        index: u16,
+        claimed_share: &Scalar<E>,
+        proof: &EvalProof<E>,
+    ) -> Result<(), ErrorSS> {
+        if commitments.is_empty() || proof.index != index || proof.share != *claimed_share {
+            return Err(VerifyShareError);
+        }
+        VssVerifier::new(commitments).validate(&proof.share, index)
+    }
+
+    /// Checks that revealed polynomial coefficients match the commitments
+    ///
+    /// Fails with [CommitmentsLengthMismatch] if number of coefficients differs from number of
+    /// commitments, and with [VerifyShareError] if any coefficient doesn't match its commitment.
+    /// If check passes, every share is the evaluation of the revealed polynomial.
+    pub fn verify_polynomial(&self, coefficients: &[Scalar<E>]) -> Result<(), ErrorSS> {
+        if coefficients.len() != self.commitments.len() {
+            return Err(CommitmentsLengthMismatch);
+        }
+        let g = Point::<E>::generator();
+        if coefficients
+            .iter()
+            .zip(&self.commitments)
+            .all(|(coef, commitment)| g * coef == *commitment)
+        {
+            Ok(())
+        } else {
+            Err(VerifyShareError)
+        }
+    }
+
+    /// Checks that commitment to the constant term equals publicly known `expected_public = g*secret`
+    ///
+    /// Shares that pass [validate_share](Self::validate_share) are consistent with the
+    /// commitments, but not necessarily with the secret the dealer was supposed to share. This
+    /// check catches a dealer that shares a wrong secret.
+    pub fn verify_commitment_to_public(
+        commitments: &[Point<E>],
+        expected_public: &Point<E>,
+    ) -> bool {
+        commitments.first() == Some(expected_public)
+    }
+
+    /// Serializes parameters and commitments into compact byte array (see [ProofSize])
+    ///
+    /// Layout is `threshold || share_count || len(commitments) || commitments`, where threshold
+    /// and share count are 2 bytes big-endian, number of commitments is 4 bytes big-endian, and
+    /// commitments are compressed points.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.serialized_size());
+        bytes.extend_from_slice(&self.parameters.threshold.to_be_bytes());
+        bytes.extend_from_slice(&self.parameters.share_count.to_be_bytes());
+        let commitments_len =
+            u32::try_from(self.commitments.len()).expect("number of commitments fits into u32");
+        bytes.extend_from_slice(&commitments_len.to_be_bytes());
+        for commitment in &self.commitments {
+            bytes.extend_from_slice(&commitment.to_bytes(true));
+        }
+        bytes
+    }
+
+    /// Serializes parameters and commitments into protobuf message `VerifiableSS` defined in
+    /// `proto/curv.proto`
+    pub fn to_proto_bytes(&self) -> Vec<u8> {
+        let mut writer = ProtoWriter::new();
+        writer.write_uint32(1, self.parameters.threshold.into());
+        writer.write_uint32(2, self.parameters.share_count.into());
+        for commitment in &self.commitments {
+            writer.write_point(3, commitment);
+        }
+        writer.into_bytes()
+    }
+
+    /// Deserializes VSS from protobuf message produced by [to_proto_bytes](Self::to_proto_bytes)
+    ///
+    /// Unknown fields are ignored. Returns error if message is malformed, parameters don't fit
+    /// into `u16`, commitments are missing, or any of the points is invalid.
+    pub fn from_proto_bytes(bytes: &[u8]) -> Result<VerifiableSS<E>, DeserializationError> {
+        Self::from_proto_fields(proto::read_fields(bytes)?)
+    }
+
+    /// Deserializes VSS from protobuf message, rejecting VSS that doesn't fit into the limits
+    ///
+    /// Same as [from_proto_bytes](Self::from_proto_bytes), but also performs
+    /// [check_limits](Self::check_limits). Number of commitments is checked before any of them is
+    /// decoded. Fails with [TooLarge] or [CommitmentsLengthMismatch] if the limits check fails,
+    /// and with [DeserializationError](ErrorSS::DeserializationError) if message is malformed.
+    pub fn from_proto_bytes_with_limits(
+        bytes: &[u8],
+        limits: &VssLimits,
+    ) -> Result<VerifiableSS<E>, ErrorSS> {
+        let fields = proto::read_fields(bytes).map_err(|_| ErrorSS::DeserializationError)?;
+        let commitments_count = fields.iter().filter(|(field, _)| *field == 3).count();
+        if commitments_count > usize::from(limits.max_threshold) + 1 {
+            return Err(TooLarge);
+        }
+        let vss = Self::from_proto_fields(fields).map_err(|_| ErrorSS::DeserializationError)?;
+        vss.check_limits(limits)?;
+        Ok(vss)
+    }
+
+    /// Deserializes VSS received from the dealer and validates share of party `index` against it
+    ///
+    /// Combines [from_proto_bytes_with_limits](Self::from_proto_bytes_with_limits) and
+    /// [validate_share](Self::validate_share), so VSS is never used before its size is checked.
+    pub fn from_proto_bytes_validate_share(
+        bytes: &[u8],
+        limits: &VssLimits,
+        secret_share: &Scalar<E>,
+        index: u16,
+    ) -> Result<VerifiableSS<E>, ErrorSS> {
+        let vss = Self::from_proto_bytes_with_limits(bytes, limits)?;
+        vss.validate_share(secret_share, index)?;
+        Ok(vss)
+    }
+
+    fn from_proto_fields(
+        fields: Vec<(u32, proto::FieldValue)>,
+    ) -> Result<VerifiableSS<E>, DeserializationError> {
+        let (mut threshold, mut share_count, mut commitments) = (0, 0, vec![]);
+        for (field, value) in fields {
+            match field {
+                1 => threshold = proto::read_uint32(&value)?,
+                2 => share_count = proto::read_uint32(&value)?,
+                3 => commitments.push(proto::read_point(&value)?),
+                _ => (),
+            }
+        }
+        if commitments.is_empty() {
+            return Err(DeserializationError);
+        }
+        Ok(VerifiableSS {
+            parameters: ShamirSecretSharing {
+                threshold: u16::try_from(threshold).map_err(|_| DeserializationError)?,
+                share_count: u16::try_from(share_count).map_err(|_| DeserializationError)?,
+            },
+            commitments,
+        })
+    }
+
+    /// Element-wise sums commitment vectors of several parties
+    ///
+    /// In distributed key generation every party shares its own secret via VSS. Sum of their
+    /// commitments is a commitment to the sum of their polynomials, and its first element is the
+    /// group public key.
+    ///
+    /// Returns error if the list is empty or commitment vectors are of different lengths.
+    pub fn aggregate_commitments(
+        all_commitments: &[Vec<Point<E>>],
+    ) -> Result<Vec<Point<E>>, ErrorSS> {
+        let (head, tail) = all_commitments
+            .split_first()
+            .ok_or(CommitmentsLengthMismatch)?;
+        if tail.iter().any(|c| c.len() != head.len()) {
+            return Err(CommitmentsLengthMismatch);
+        }
+        Ok(tail.iter().fold(head.clone(), |acc, commitments| {
+            acc.into_iter()
+                .zip(commitments)
+                .map(|(a, c)| a + c)
+                .collect()
+        }))
+    }
+
     pub fn get_point_commitment(&self, index: u16) -> Point<E> {
         let index_fe = Scalar::from(index);
         let mut comm_iterator = self.commitments.iter().rev();
@@ -255,6 +606,134 @@ impl<E: Curve> VerifiableSS<E> {
     }
 }
 
+/// Verifier of shares against a single commitment vector
+///
+/// Validating shares one by one with [VerifiableSS::validate_share] costs t+1 scalar
+/// multiplications per share. `VssVerifier` keeps the commitments and validates many shares at
+/// once via [validate_batch](Self::validate_batch): shares are combined with random weights
+/// $\rho_i$, and a single check
+///
+/// $$G \cdot \sum_i \rho_i s_i = \sum_j C_j \cdot \sum_i \rho_i i^j$$
+///
+/// takes t+2 scalar multiplications regardless of the number of shares, the rest is scalar
+/// arithmetic. If the check fails, shares are validated individually to find the invalid ones.
+#[derive(Clone, Debug)]
+pub struct VssVerifier<E: Curve> {
+    commitments: Vec<Point<E>>,
+}
+
+impl<E: Curve> VssVerifier<E> {
+    pub fn new(commitments: &[Point<E>]) -> Self {
+        assert!(!commitments.is_empty());
+        VssVerifier {
+            commitments: commitments.to_vec(),
+        }
+    }
+
+    /// Validates a single share, same as [VerifiableSS::validate_share]
+    pub fn validate(&self, secret_share: &Scalar<E>, index: u16) -> Result<(), ErrorSS> {
+        let index = Scalar::from(index);
+        let mut commitments = self.commitments.iter().rev();
+        let head = commitments.next().unwrap().clone();
+        let expected = commitments.fold(head, |acc, c| c + acc * &index);
+        if Point::generator() * secret_share == expected {
+            Ok(())
+        } else {
+            Err(VerifyShareError)
+        }
+    }
+
+    /// Validates `(index, share)` pairs at once
+    ///
+    /// Returns indices of positions in `shares` that didn't pass validation.
+    pub fn validate_batch(&self, shares: &[(u16, Scalar<E>)]) -> Result<(), Vec<usize>> {
+        let mut share_sum = Scalar::zero();
+        let mut coefficients = vec![Scalar::<E>::zero(); self.commitments.len()];
+        for (index, share) in shares {
+            let rho = Scalar::<E>::random();
+            share_sum = share_sum + &rho * share;
+            let index = Scalar::from(*index);
+            let mut power = rho;
+            for coefficient in coefficients.iter_mut() {
+                *coefficient = &*coefficient + &power;
+                power = power * &index;
+            }
+        }
+        let expected: Point<E> = self
+            .commitments
+            .iter()
+            .zip(&coefficients)
+            .map(|(c, coefficient)| c * coefficient)
+            .sum();
+        if Point::generator() * share_sum == expected {
+            return Ok(());
+        }
+        Err(shares
+            .iter()
+            .enumerate()
+            .filter(|(_, (index, share))| self.validate(share, *index).is_err())
+            .map(|(i, _)| i)
+            .collect())
+    }
+}
+
+fn derive_coefficient<E: Curve>(seed: &[u8; 32], j: u16) -> Scalar<E> {
+    for counter in 0u32.. {
+        let mut prf = Hmac::<Sha512>::new_from_slice(seed).expect("HMAC takes key of any length");
+        prf.update(DETERMINISTIC_SHARING_LABEL);
+        prf.update(&j.to_be_bytes());
+        prf.update(&counter.to_be_bytes());
+        let output = BigInt::from_bytes(&prf.finalize().into_bytes());
+        let coefficient = Scalar::from_bigint(&output);
+        if !coefficient.is_zero() {
+            return coefficient;
+        }
+    }
+    unreachable!("PRF output is zero modulo group order for 2^32 counters in a row")
+}
+
+fn commit_polynomial<E: Curve>(polynomial: &Polynomial<E>) -> Vec<Point<E>> {
+    let g = Point::<E>::generator();
+    polynomial
+        .coefficients()
+        .iter()
+        .map(|coef| g * coef)
+        .collect()
+}
+
+#[cfg(feature = "rayon")]
+fn commit_polynomial_parallel<E: Curve>(polynomial: &Polynomial<E>) -> Vec<Point<E>>
+where
+    E::Point: Send,
+    E::Scalar: Sync,
+{
+    let coefficients = polynomial.coefficients();
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = coefficients.len().div_ceil(threads);
+    std::thread::scope(|s| {
+        let handles: Vec<_> = coefficients
+            .chunks(chunk_size.max(1))
+            .map(|chunk| {
+                s.spawn(move || {
+                    let g = Point::<E>::generator();
+                    chunk.iter().map(|coef| g * coef).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().expect("commitment thread panicked"))
+            .collect()
+    })
+}
+
+impl<E: Curve> ProofSize for VerifiableSS<E> {
+    fn serialized_size(&self) -> usize {
+        // threshold and share_count are u16
+        2 + 2 + LIST_LENGTH_PREFIX_SIZE + self.commitments.len() * point_size::<E>()
+    }
+}
+
 impl<E: Curve> SecretShares<E> {
     /// Polynomial that was used to derive secret shares
     pub fn polynomial(&self) -> &Polynomial<E> {
@@ -281,6 +760,164 @@ mod tests {
     use super::*;
     use crate::test_for_all_curves;
 
+    test_for_all_curves!(test_vss_proto_round_trip);
+    fn test_vss_proto_round_trip<E: Curve>() {
+        let (vss, _) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+        let bytes = vss.to_proto_bytes();
+        assert_eq!(VerifiableSS::<E>::from_proto_bytes(&bytes).unwrap(), vss);
+        assert!(VerifiableSS::<E>::from_proto_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_vss_proto_golden_bytes() {
+        use crate::elliptic::curves::Secp256k1;
+
+        let g = Point::<Secp256k1>::generator();
+        let vss = VerifiableSS {
+            parameters: ShamirSecretSharing {
+                threshold: 1,
+                share_count: 3,
+            },
+            commitments: vec![g.to_point(), g * Scalar::from(2)],
+        };
+        let golden = concat!(
+            "0801",
+            "1003",
+            "1a21",
+            "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "1a21",
+            "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        );
+        assert_eq!(hex::encode(vss.to_proto_bytes()), golden);
+        assert_eq!(
+            VerifiableSS::from_proto_bytes(&hex::decode(golden).unwrap()).unwrap(),
+            vss
+        );
+    }
+
+    test_for_all_curves!(test_complaint_resolution);
+    fn test_complaint_resolution<E: Curve>() {
+        let (vss, shares) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+
+        // Honest dealer opens the share it actually derived from the polynomial
+        let proof = VerifiableSS::prove_evaluation(&shares[3], 4);
+        assert!(VerifiableSS::verify_evaluation(&vss.commitments, 4, &shares[3], &proof).is_ok());
+        assert!(VerifiableSS::verify_evaluation(&vss.commitments, 3, &shares[3], &proof).is_err());
+
+        // Dishonest dealer sent a share that doesn't lie on the committed polynomial
+        let bad_share = &shares[3] + Scalar::from(1);
+        let proof = VerifiableSS::prove_evaluation(&bad_share, 4);
+        assert!(VerifiableSS::verify_evaluation(&vss.commitments, 4, &bad_share, &proof).is_err());
+        assert!(VerifiableSS::verify_evaluation(&vss.commitments, 4, &shares[3], &proof).is_err());
+    }
+
+    test_for_all_curves!(test_share_deterministic);
+    fn test_share_deterministic<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (vss1, shares1) = VerifiableSS::share_deterministic(3, 6, &secret, &[1u8; 32]);
+        let (vss2, shares2) = VerifiableSS::share_deterministic(3, 6, &secret, &[1u8; 32]);
+        assert_eq!(
+            serde_json::to_vec(&vss1).unwrap(),
+            serde_json::to_vec(&vss2).unwrap()
+        );
+        let share_bytes = |shares: &SecretShares<E>| -> Vec<Vec<u8>> {
+            shares.iter().map(|s| s.to_bytes().to_vec()).collect()
+        };
+        assert_eq!(share_bytes(&shares1), share_bytes(&shares2));
+        assert_eq!(shares1.polynomial().degree(), 3);
+        for (i, share) in (1..).zip(shares1.iter()) {
+            assert!(vss1.validate_share(share, i).is_ok());
+        }
+
+        let (vss3, shares3) = VerifiableSS::share_deterministic(3, 6, &secret, &[2u8; 32]);
+        assert_ne!(vss1.commitments[1..], vss3.commitments[1..]);
+        assert_ne!(share_bytes(&shares1), share_bytes(&shares3));
+    }
+
+    test_for_all_curves!(test_vss_verifier_matches_validate_share);
+    fn test_vss_verifier_matches_validate_share<E: Curve>() {
+        let (vss, shares) = VerifiableSS::<E>::share(2, 50, &Scalar::random());
+        let verifier = VssVerifier::new(&vss.commitments);
+
+        let mut indexed: Vec<(u16, Scalar<E>)> = (1..).zip(shares.iter().cloned()).collect();
+        assert_eq!(verifier.validate_batch(&indexed), Ok(()));
+
+        indexed[3].1 = &indexed[3].1 + Scalar::from(1);
+        indexed[41].0 = 43;
+        for (index, share) in &indexed {
+            assert_eq!(
+                vss.validate_share(share, *index).is_ok(),
+                verifier.validate(share, *index).is_ok()
+            );
+        }
+        assert_eq!(verifier.validate_batch(&indexed), Err(vec![3, 41]));
+    }
+
+    test_for_all_curves!(test_share_with_seeded_rng_is_deterministic);
+    fn test_share_with_seeded_rng_is_deterministic<E: Curve>() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let secret = Scalar::random_with_rng(&mut StdRng::seed_from_u64(0));
+        let (vss1, shares1) =
+            VerifiableSS::<E>::share_with_rng(2, 5, &secret, &mut StdRng::seed_from_u64(1));
+        let (vss2, shares2) =
+            VerifiableSS::<E>::share_with_rng(2, 5, &secret, &mut StdRng::seed_from_u64(1));
+        assert_eq!(vss1, vss2);
+        assert_eq!(*shares1, *shares2);
+        assert_eq!(
+            vss1.reconstruct(
+                &[0, 2, 4],
+                &[shares1[0].clone(), shares1[2].clone(), shares1[4].clone()]
+            ),
+            secret
+        );
+
+        let (vss3, _) =
+            VerifiableSS::<E>::share_with_rng(2, 5, &secret, &mut StdRng::seed_from_u64(2));
+        assert_ne!(vss1, vss3);
+    }
+
+    #[cfg(feature = "rayon")]
+    test_for_all_curves!(test_parallel_commitments_match_serial);
+    #[cfg(feature = "rayon")]
+    fn test_parallel_commitments_match_serial<E: Curve>()
+    where
+        E::Point: Send,
+        E::Scalar: Sync,
+    {
+        use crate::arithmetic::traits::*;
+        use crate::BigInt;
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(20);
+        let coefficients = (0..=20)
+            .map(|_| {
+                let mut bytes = [0u8; 32];
+                rng.fill_bytes(&mut bytes);
+                Scalar::from_bigint(&BigInt::from_bytes(&bytes))
+            })
+            .collect();
+        let polynomial = Polynomial::<E>::from_coefficients(coefficients);
+        assert_eq!(polynomial.degree(), 20);
+
+        let serial: Vec<_> = commit_polynomial(&polynomial)
+            .iter()
+            .map(|c| c.to_bytes(true).to_vec())
+            .collect();
+        let parallel: Vec<_> = commit_polynomial_parallel(&polynomial)
+            .iter()
+            .map(|c| c.to_bytes(true).to_vec())
+            .collect();
+        assert_eq!(serial, parallel);
+
+        let secret = Scalar::random();
+        let (vss, shares) = VerifiableSS::<E>::share_parallel(20, 25, &secret);
+        assert_eq!(vss.commitments, commit_polynomial(shares.polynomial()));
+        for (i, share) in (1..).zip(shares.iter()) {
+            assert!(vss.validate_share(share, i).is_ok());
+        }
+    }
+
     test_for_all_curves!(test_secret_sharing_3_out_of_5_at_indices);
 
     fn test_secret_sharing_3_out_of_5_at_indices<E: Curve>() {
@@ -455,6 +1092,50 @@ mod tests {
         assert_eq!(w, secret_reconstructed);
     }
 
+    test_for_all_curves!(test_aggregate_commitments);
+
+    fn test_aggregate_commitments<E: Curve>() {
+        let secrets = [Scalar::random(), Scalar::random(), Scalar::random()];
+        let sharings: Vec<_> = secrets
+            .iter()
+            .map(|s| VerifiableSS::<E>::share(2, 5, s))
+            .collect();
+        let all_commitments: Vec<_> = sharings
+            .iter()
+            .map(|(vss, _)| vss.commitments.clone())
+            .collect();
+
+        let aggregated = VerifiableSS::aggregate_commitments(&all_commitments)
+            .unwrap_or_else(|_| panic!("commitments are of the same length"));
+
+        // group public key
+        let g = Point::<E>::generator();
+        assert_eq!(aggregated[0], g * secrets.iter().sum::<Scalar<E>>());
+
+        // sum of shares is validated against aggregated commitments
+        let aggregated_vss = VerifiableSS {
+            parameters: sharings[0].0.parameters.clone(),
+            commitments: aggregated,
+        };
+        for i in 0..5 {
+            let share: Scalar<E> = sharings.iter().map(|(_, shares)| &shares[i]).sum();
+            assert!(aggregated_vss.validate_share(&share, i as u16 + 1).is_ok());
+        }
+    }
+
+    test_for_all_curves!(test_aggregate_commitments_length_mismatch);
+
+    fn test_aggregate_commitments_length_mismatch<E: Curve>() {
+        let (vss1, _) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+        let (vss2, _) = VerifiableSS::<E>::share(3, 5, &Scalar::random());
+        let result = VerifiableSS::aggregate_commitments(&[vss1.commitments, vss2.commitments]);
+        assert!(matches!(result, Err(CommitmentsLengthMismatch)));
+        assert!(matches!(
+            VerifiableSS::<E>::aggregate_commitments(&[]),
+            Err(CommitmentsLengthMismatch)
+        ));
+    }
+
     test_for_all_curves!(test_secret_resharing);
 
     fn test_secret_resharing<E: Curve>() {
@@ -482,4 +1163,181 @@ mod tests {
         assert!(valid2.is_ok());
         assert!(valid3.is_ok());
     }
+
+    test_for_all_curves!(test_revealed_polynomial_matches_sharing);
+    fn test_revealed_polynomial_matches_sharing<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (vss_scheme, shares, coefficients) =
+            VerifiableSS::<E>::share_with_polynomial(2, 5, &secret);
+        assert_eq!(coefficients.len(), 3);
+        assert_eq!(coefficients[0], secret);
+        assert!(vss_scheme.verify_polynomial(&coefficients).is_ok());
+
+        let polynomial = Polynomial::<E>::from_coefficients(coefficients.clone());
+        for (i, share) in (1u16..).zip(shares.iter()) {
+            assert_eq!(polynomial.evaluate(&Scalar::from(i)), *share);
+        }
+
+        let mut tampered = coefficients.clone();
+        tampered[1] = &tampered[1] + Scalar::from(1);
+        assert!(matches!(
+            vss_scheme.verify_polynomial(&tampered),
+            Err(VerifyShareError)
+        ));
+        assert!(matches!(
+            vss_scheme.verify_polynomial(&coefficients[..2]),
+            Err(CommitmentsLengthMismatch)
+        ));
+    }
+
+    test_for_all_curves!(test_commitment_to_public_value);
+    fn test_commitment_to_public_value<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let expected_public = Point::generator() * &secret;
+
+        let (vss_scheme, _) = VerifiableSS::share(2, 5, &secret);
+        assert!(VerifiableSS::verify_commitment_to_public(
+            &vss_scheme.commitments,
+            &expected_public
+        ));
+
+        let (wrong_scheme, _) = VerifiableSS::share(2, 5, &(secret + Scalar::from(1)));
+        assert!(!VerifiableSS::verify_commitment_to_public(
+            &wrong_scheme.commitments,
+            &expected_public
+        ));
+        assert!(!VerifiableSS::verify_commitment_to_public(
+            &[],
+            &expected_public
+        ));
+    }
+
+    test_for_all_curves!(test_robust_reconstruct);
+    fn test_robust_reconstruct<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (vss, secret_shares) = VerifiableSS::<E>::share(2, 5, &secret);
+        let mut shares: Vec<(u16, Scalar<E>)> =
+            (1u16..).zip(secret_shares.iter().cloned()).collect();
+
+        // Two of five shares are corrupt, three valid ones are still enough
+        shares[1].1 = &shares[1].1 + Scalar::from(1);
+        shares[3].1 = Scalar::random();
+        assert_eq!(vss.robust_reconstruct(&shares).ok(), Some(secret.clone()));
+        // Only two valid shares among the first four
+        assert!(matches!(
+            vss.robust_reconstruct(&shares[..4]),
+            Err(InsufficientShares)
+        ));
+
+        // Repeated share is counted once
+        let repeated = [shares[0].clone(), shares[0].clone(), shares[2].clone()];
+        assert!(matches!(
+            vss.robust_reconstruct(&repeated),
+            Err(InsufficientShares)
+        ));
+        // Order of shares doesn't matter
+        let reordered: Vec<_> = shares.iter().skip(2).chain(&shares[..1]).cloned().collect();
+        assert_eq!(vss.robust_reconstruct(&reordered).ok(), Some(secret));
+    }
+
+    test_for_all_curves!(test_vss_limits);
+    fn test_vss_limits<E: Curve>() {
+        let limits = VssLimits {
+            max_threshold: 10,
+            max_share_count: 20,
+        };
+        let (vss_scheme, _) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+        assert!(vss_scheme.check_limits(&limits).is_ok());
+        assert_eq!(
+            VerifiableSS::<E>::from_proto_bytes_with_limits(&vss_scheme.to_proto_bytes(), &limits)
+                .ok(),
+            Some(vss_scheme.clone())
+        );
+
+        // Dealer claims a polynomial of huge degree
+        let oversized = VerifiableSS {
+            parameters: ShamirSecretSharing {
+                threshold: u16::MAX - 1,
+                share_count: u16::MAX,
+            },
+            commitments: vec![Point::<E>::generator().to_point(); 1000],
+        };
+        assert!(matches!(oversized.check_limits(&limits), Err(TooLarge)));
+        assert!(matches!(
+            VerifiableSS::<E>::from_proto_bytes_with_limits(&oversized.to_proto_bytes(), &limits),
+            Err(TooLarge)
+        ));
+        assert!(matches!(
+            VerifiableSS::<E>::from_proto_bytes_with_limits(&[0xff], &limits),
+            Err(ErrorSS::DeserializationError)
+        ));
+
+        // Parameters are within the limits, but commitments aren't
+        let mut too_many_commitments = vss_scheme.clone();
+        too_many_commitments.commitments = oversized.commitments.clone();
+        assert!(matches!(
+            too_many_commitments.check_limits(&limits),
+            Err(TooLarge)
+        ));
+
+        let mut too_many_parties = vss_scheme.clone();
+        too_many_parties.parameters.share_count = 21;
+        assert!(matches!(
+            too_many_parties.check_limits(&limits),
+            Err(TooLarge)
+        ));
+
+        let mut inconsistent = vss_scheme;
+        inconsistent.commitments.pop();
+        assert!(matches!(
+            inconsistent.check_limits(&limits),
+            Err(CommitmentsLengthMismatch)
+        ));
+        assert!(matches!(
+            VerifiableSS::<E>::from_proto_bytes_with_limits(
+                &inconsistent.to_proto_bytes(),
+                &limits
+            ),
+            Err(CommitmentsLengthMismatch)
+        ));
+    }
+
+    test_for_all_curves!(test_from_proto_bytes_validate_share);
+    fn test_from_proto_bytes_validate_share<E: Curve>() {
+        let limits = VssLimits {
+            max_threshold: 10,
+            max_share_count: 20,
+        };
+        let (vss_scheme, shares) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+        let bytes = vss_scheme.to_proto_bytes();
+        assert_eq!(
+            VerifiableSS::from_proto_bytes_validate_share(&bytes, &limits, &shares[1], 2).ok(),
+            Some(vss_scheme)
+        );
+        assert!(matches!(
+            VerifiableSS::<E>::from_proto_bytes_validate_share(&bytes, &limits, &shares[1], 3),
+            Err(VerifyShareError)
+        ));
+        let tight_limits = VssLimits {
+            max_threshold: 1,
+            ..limits
+        };
+        assert!(matches!(
+            VerifiableSS::<E>::from_proto_bytes_validate_share(
+                &bytes,
+                &tight_limits,
+                &shares[1],
+                2
+            ),
+            Err(TooLarge)
+        ));
+    }
+
+    test_for_all_curves!(test_vss_serialized_size);
+    fn test_vss_serialized_size<E: Curve>() {
+        let (vss_scheme, _) = VerifiableSS::<E>::share(2, 5, &Scalar::random());
+        let bytes = vss_scheme.to_bytes();
+        assert_eq!(vss_scheme.serialized_size(), bytes.len());
+        assert_eq!(bytes[..8], [0, 2, 0, 5, 0, 0, 0, 3]);
+    }
 }
@@ -5,7 +5,11 @@
     License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
 */
 
+#[cfg(feature = "encryption")]
+pub mod encrypted_shares;
 pub mod feldman_vss;
 mod polynomial;
+pub mod threshold_elgamal;
+pub mod weighted;
 
 pub use polynomial::Polynomial;
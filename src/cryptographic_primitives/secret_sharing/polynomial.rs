@@ -4,6 +4,7 @@ use std::{iter, ops};
 use serde::{Deserialize, Serialize};
 
 use crate::elliptic::curves::{Curve, Scalar};
+use crate::RandomSource;
 
 /// Polynomial of some degree $n$
 ///
@@ -83,6 +84,18 @@ impl<E: Curve> Polynomial<E> {
         }
     }
 
+    /// Same as [sample_exact_with_fixed_const_term](Self::sample_exact_with_fixed_const_term), but
+    /// takes randomness from given source
+    pub fn sample_exact_with_fixed_const_term_with_rng<R: RandomSource + ?Sized>(
+        n: u16,
+        const_term: Scalar<E>,
+        rng: &mut R,
+    ) -> Self {
+        let random_coefficients =
+            iter::repeat_with(|| Scalar::random_with_rng(rng)).take(usize::from(n));
+        Self::from_coefficients(iter::once(const_term).chain(random_coefficients).collect())
+    }
+
     /// Returns degree $d$ of polynomial $f(x)$: $d = \deg f$
     ///
     /// ```rust
@@ -0,0 +1,186 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use serde::{Deserialize, Serialize};
+
+use crate::cryptographic_primitives::proofs::sigma_ec_ddh::{
+    ECDDHProof, ECDDHStatement, ECDDHWitness,
+};
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::cryptographic_primitives::secret_sharing::Polynomial;
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::ErrorSS::{self, InsufficientShares, VerifyShareError};
+
+/// Threshold ElGamal decryption with secret key shared via Feldman VSS
+///
+/// Ciphertext is a pair (D, E) where D = M + rY, E = rG, and Y is the public key (same notation
+/// as in [ReencryptionProof](crate::cryptographic_primitives::proofs::sigma_reencryption::ReencryptionProof)).
+/// Secret key x is shared with [VerifiableSS], party `i` holds share x_i = f(i) and its public
+/// key share Y_i = x_i G is derived from the VSS commitments.
+///
+/// To decrypt, each party publishes a decryption share D_i = x_i E along with [ECDDHProof] that
+/// (G, Y_i, E, D_i) is a DDH tuple, ie. that D_i is computed with the same x_i as committed in
+/// VSS. Any t+1 valid shares are combined as M = D - sum λ_i D_i, where λ_i are Lagrange
+/// coefficients at zero.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ThresholdElGamal<E: Curve> {
+    pub vss: VerifiableSS<E>,
+}
+
+/// Partial decryption produced by [ThresholdElGamal::decrypt_share]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct DecryptionShare<E: Curve> {
+    pub index: u16,
+    pub share: Point<E>,
+    pub proof: ECDDHProof<E>,
+}
+
+impl<E: Curve> ThresholdElGamal<E> {
+    /// Public key `Y = xG` corresponding to the shared secret key
+    pub fn public_key(&self) -> Point<E> {
+        self.vss.commitments[0].clone()
+    }
+
+    /// Encrypts point `m` under the [public key](Self::public_key)
+    pub fn encrypt(&self, m: &Point<E>) -> (Point<E>, Point<E>) {
+        let r = Scalar::random();
+        (m + self.public_key() * &r, Point::generator() * r)
+    }
+
+    /// Computes decryption share of party `index` holding secret key share `key_share`
+    ///
+    /// `index` is the point at which the VSS polynomial was evaluated to obtain `key_share`
+    /// (ie. it's 1-based, as in [VerifiableSS::validate_share]).
+    pub fn decrypt_share(
+        key_share: &Scalar<E>,
+        index: u16,
+        ct: &(Point<E>, Point<E>),
+    ) -> DecryptionShare<E> {
+        let share = &ct.1 * key_share;
+        let statement = ECDDHStatement {
+            g1: Point::generator().to_point(),
+            h1: Point::generator() * key_share,
+            g2: ct.1.clone(),
+            h2: share.clone(),
+        };
+        let proof = ECDDHProof::prove(
+            &ECDDHWitness {
+                x: key_share.clone(),
+            },
+            &statement,
+        );
+        DecryptionShare {
+            index,
+            share,
+            proof,
+        }
+    }
+
+    /// Checks that decryption share is computed with key share committed in VSS
+    pub fn verify_share(
+        &self,
+        share: &DecryptionShare<E>,
+        ct: &(Point<E>, Point<E>),
+    ) -> Result<(), ErrorSS> {
+        if share.index == 0 {
+            return Err(VerifyShareError);
+        }
+        let statement = ECDDHStatement {
+            g1: Point::generator().to_point(),
+            h1: self.vss.get_point_commitment(share.index),
+            g2: ct.1.clone(),
+            h2: share.share.clone(),
+        };
+        share.proof.verify(&statement).map_err(|_| VerifyShareError)
+    }
+
+    /// Verifies decryption shares and combines them into the plaintext
+    ///
+    /// Fails with [VerifyShareError] if any share is invalid or if two shares have the same
+    /// index, and with [InsufficientShares] if there are less than
+    /// [reconstruct_limit](VerifiableSS::reconstruct_limit) shares.
+    pub fn combine_shares(
+        &self,
+        shares: &[DecryptionShare<E>],
+        ct: &(Point<E>, Point<E>),
+    ) -> Result<Point<E>, ErrorSS> {
+        for (i, share) in shares.iter().enumerate() {
+            if shares[i + 1..].iter().any(|s| s.index == share.index) {
+                return Err(VerifyShareError);
+            }
+            self.verify_share(share, ct)?;
+        }
+        if shares.len() < usize::from(self.vss.reconstruct_limit()) {
+            return Err(InsufficientShares);
+        }
+
+        let xs: Vec<Scalar<E>> = shares.iter().map(|s| Scalar::from(s.index)).collect();
+        let masking = (0u16..).zip(shares).fold(Point::zero(), |acc, (j, s)| {
+            acc + &s.share * Polynomial::lagrange_basis(&Scalar::zero(), j, &xs)
+        });
+        Ok(&ct.0 - masking)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_threshold_decryption_2_of_3);
+    fn test_threshold_decryption_2_of_3<E: Curve>() {
+        let (vss, key_shares) = VerifiableSS::<E>::share(1, 3, &Scalar::random());
+        let scheme = ThresholdElGamal { vss };
+        let m = Point::generator() * Scalar::random();
+        let ct = scheme.encrypt(&m);
+
+        let shares: Vec<_> = (1u16..)
+            .zip(key_shares.iter())
+            .map(|(i, x_i)| ThresholdElGamal::decrypt_share(x_i, i, &ct))
+            .collect();
+        for pair in [[0, 1], [0, 2], [1, 2]] {
+            let pair = [shares[pair[0]].clone(), shares[pair[1]].clone()];
+            assert_eq!(scheme.combine_shares(&pair, &ct).ok(), Some(m.clone()));
+        }
+        assert_eq!(scheme.combine_shares(&shares, &ct).ok(), Some(m));
+
+        assert!(matches!(
+            scheme.combine_shares(&shares[..1], &ct),
+            Err(InsufficientShares)
+        ));
+        assert!(matches!(
+            scheme.combine_shares(&[shares[0].clone(), shares[0].clone()], &ct),
+            Err(VerifyShareError)
+        ));
+    }
+
+    test_for_all_curves!(test_threshold_decryption_rejects_bad_share);
+    fn test_threshold_decryption_rejects_bad_share<E: Curve>() {
+        let (vss, key_shares) = VerifiableSS::<E>::share(1, 3, &Scalar::random());
+        let scheme = ThresholdElGamal { vss };
+        let ct = scheme.encrypt(&(Point::generator() * Scalar::random()));
+
+        let good = ThresholdElGamal::decrypt_share(&key_shares[0], 1, &ct);
+        // Share computed with a wrong key, proof is valid for that key but not for VSS commitment
+        let wrong_key = ThresholdElGamal::decrypt_share(&Scalar::random(), 2, &ct);
+        assert!(scheme.verify_share(&wrong_key, &ct).is_err());
+        assert!(matches!(
+            scheme.combine_shares(&[good.clone(), wrong_key], &ct),
+            Err(VerifyShareError)
+        ));
+
+        // Tampered decryption share doesn't match the proof
+        let mut tampered = ThresholdElGamal::decrypt_share(&key_shares[1], 2, &ct);
+        tampered.share = &tampered.share + Point::generator();
+        assert!(matches!(
+            scheme.combine_shares(&[good, tampered], &ct),
+            Err(VerifyShareError)
+        ));
+    }
+}
@@ -0,0 +1,182 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: https://github.com/KZen-networks/curv/blob/master/LICENSE
+*/
+
+use std::convert::TryFrom;
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS;
+use crate::elliptic::curves::{Curve, Scalar};
+use crate::ErrorSS::{self, InsufficientWeight, VerifyShareError};
+
+pub type PartyId = u16;
+
+/// Weighted threshold secret sharing on top of Feldman VSS
+///
+/// Every party is assigned a weight, and the secret can be reconstructed by any set of parties
+/// whose total weight is at least `threshold_weight`. Party of weight `w` receives `w` shares of
+/// a single Shamir sharing with threshold `threshold_weight - 1`, so it counts as `w` ordinary
+/// parties. Shares of party are evaluations of the polynomial at consecutive points, party order
+/// follows `weights`.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct WeightedSecretSharing<E: Curve> {
+    pub threshold_weight: u32,
+    pub weights: Vec<(PartyId, u32)>,
+    pub vss: VerifiableSS<E>,
+}
+
+/// Shares of a single party produced by [WeightedSecretSharing::share]
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct WeightedShare<E: Curve> {
+    pub party: PartyId,
+    pub shares: Vec<Scalar<E>>,
+}
+
+impl<E: Curve> WeightedSecretSharing<E> {
+    /// Shares a secret among weighted parties
+    ///
+    /// ## Panics
+    /// Panics if party ids are not unique, if `threshold_weight` is zero or exceeds the total
+    /// weight, or if the total weight doesn't fit into `u16`.
+    pub fn share(
+        secret: &Scalar<E>,
+        weights: &[(PartyId, u32)],
+        threshold_weight: u32,
+    ) -> (WeightedSecretSharing<E>, Vec<WeightedShare<E>>) {
+        for (i, (party, _)) in weights.iter().enumerate() {
+            assert!(
+                weights[i + 1..].iter().all(|(p, _)| p != party),
+                "party ids must be unique"
+            );
+        }
+        let total_weight: u32 = weights.iter().map(|(_, w)| w).sum();
+        let n = u16::try_from(total_weight).expect("total weight must fit into u16");
+        assert!(threshold_weight > 0 && threshold_weight <= total_weight);
+        let t = u16::try_from(threshold_weight - 1).unwrap();
+
+        let indices: Vec<u16> = (1..=n).collect();
+        let (vss, secret_shares) = VerifiableSS::share_at_indices(t, n, secret, &indices);
+        let weighted = WeightedSecretSharing {
+            threshold_weight,
+            weights: weights.to_vec(),
+            vss,
+        };
+        let shares = weights
+            .iter()
+            .map(|(party, _)| {
+                let range = weighted.share_indices(*party).unwrap();
+                WeightedShare {
+                    party: *party,
+                    shares: secret_shares[usize::from(range.start - 1)..usize::from(range.end - 1)]
+                        .to_vec(),
+                }
+            })
+            .collect();
+        (weighted, shares)
+    }
+
+    /// Points on the polynomial at which shares of the party are evaluated
+    ///
+    /// Returns `None` if party is unknown.
+    pub fn share_indices(&self, party: PartyId) -> Option<Range<u16>> {
+        let mut start = 1u32;
+        for (p, w) in &self.weights {
+            if *p == party {
+                let end = start + w;
+                return Some(u16::try_from(start).ok()?..u16::try_from(end).ok()?);
+            }
+            start += w;
+        }
+        None
+    }
+
+    /// Reconstructs the secret from shares of participating parties
+    ///
+    /// Fails with [InsufficientWeight] if total weight of participants is below
+    /// `threshold_weight`, and with [VerifyShareError] if some party is unknown or
+    /// appears twice, or if its shares don't match the commitments.
+    pub fn reconstruct(&self, shares: &[WeightedShare<E>]) -> Result<Scalar<E>, ErrorSS> {
+        let mut points = vec![];
+        let mut values = vec![];
+        for (i, share) in shares.iter().enumerate() {
+            if shares[i + 1..].iter().any(|s| s.party == share.party) {
+                return Err(VerifyShareError);
+            }
+            let indices = self.share_indices(share.party).ok_or(VerifyShareError)?;
+            if indices.len() != share.shares.len() {
+                return Err(VerifyShareError);
+            }
+            for (index, value) in indices.zip(&share.shares) {
+                self.vss.validate_share(value, index)?;
+                points.push(Scalar::from(index));
+                values.push(value.clone());
+            }
+        }
+        if points.len() < self.threshold_weight as usize {
+            return Err(InsufficientWeight);
+        }
+        Ok(VerifiableSS::lagrange_interpolation_at_zero(
+            &points, &values,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_weighted_reconstruction);
+    fn test_weighted_reconstruction<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (sharing, shares) = WeightedSecretSharing::share(&secret, &[(1, 3), (2, 1), (3, 1)], 3);
+        assert_eq!(shares[0].shares.len(), 3);
+        assert_eq!(shares[1].shares.len(), 1);
+
+        // Heavy party alone reaches the threshold
+        assert_eq!(sharing.reconstruct(&shares[..1]).ok(), Some(secret.clone()));
+        assert_eq!(sharing.reconstruct(&shares).ok(), Some(secret.clone()));
+        assert_eq!(sharing.reconstruct(&shares[..2]).ok(), Some(secret.clone()));
+
+        // Two light parties together have weight 2 < 3
+        assert!(matches!(
+            sharing.reconstruct(&shares[1..]),
+            Err(InsufficientWeight)
+        ));
+    }
+
+    test_for_all_curves!(test_weighted_reconstruction_rejects_bad_shares);
+    fn test_weighted_reconstruction_rejects_bad_shares<E: Curve>() {
+        let secret = Scalar::<E>::random();
+        let (sharing, shares) = WeightedSecretSharing::share(&secret, &[(1, 3), (2, 1), (3, 1)], 3);
+
+        let duplicated = [shares[1].clone(), shares[2].clone(), shares[2].clone()];
+        assert!(matches!(
+            sharing.reconstruct(&duplicated),
+            Err(VerifyShareError)
+        ));
+
+        let mut forged = shares[0].clone();
+        forged.shares[0] = Scalar::random();
+        assert!(matches!(
+            sharing.reconstruct(&[forged]),
+            Err(VerifyShareError)
+        ));
+
+        let unknown = WeightedShare {
+            party: 4,
+            shares: vec![Scalar::random()],
+        };
+        assert!(matches!(
+            sharing.reconstruct(&[shares[1].clone(), shares[2].clone(), unknown]),
+            Err(VerifyShareError)
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Builds on [Schnorr signatures](super::schnorr): signature (R, s) over message m is valid under
+//! public key P = xG if sG = R + cP, where c = H(R, P, m).
+//!
+//! Pre-signature with respect to adaptor point T = tG is (R, s') such that s'G = R - T + cP,
+//! with the same c = H(R, P, m). It can be checked by anyone knowing T ([PreSignature::verify]),
+//! but it's not a valid signature. Whoever knows t completes it into signature (R, s' + t)
+//! ([adapt]), and once that signature is published, t = s - s' can be extracted from it
+//! ([extract]). This makes a payment on one chain reveal the secret which unlocks a payment on
+//! another chain, which is the basis of atomic swaps.
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+use super::schnorr::challenge;
+pub use super::schnorr::Signature;
+
+/// Schnorr pre-signature `(R, s')` with respect to adaptor point `T`
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct PreSignature<E: Curve> {
+    pub r: Point<E>,
+    pub s: Scalar<E>,
+}
+
+impl<E: Curve> PreSignature<E> {
+    /// Verifies the pre-signature: `s'G = R - T + cP`
+    ///
+    /// Counterparty must check the pre-signature before relying on it: if it's valid, then
+    /// adapting it with discrete log of `adaptor_point` gives a valid signature.
+    pub fn verify(&self, pk: &Point<E>, message: &[u8], adaptor_point: &Point<E>) -> bool {
+        let c = challenge(&self.r, pk, message);
+        Point::generator() * &self.s == &self.r - adaptor_point + pk * c
+    }
+}
+
+/// Pre-signs the message with respect to adaptor point `T`
+pub fn pre_sign<E: Curve>(
+    sk: &Scalar<E>,
+    message: &[u8],
+    adaptor_point: &Point<E>,
+) -> PreSignature<E> {
+    let k = Scalar::random();
+    let r = Point::generator() * &k + adaptor_point;
+    let pk = Point::generator() * sk;
+    let c = challenge(&r, &pk, message);
+    PreSignature { s: k + c * sk, r }
+}
+
+/// Completes pre-signature into a signature using discrete log of adaptor point
+pub fn adapt<E: Curve>(pre_signature: &PreSignature<E>, secret: &Scalar<E>) -> Signature<E> {
+    Signature {
+        r: pre_signature.r.clone(),
+        s: &pre_signature.s + secret,
+    }
+}
+
+/// Extracts discrete log of adaptor point from pre-signature and signature completed from it
+///
+/// Output is meaningful only if `signature` is valid and has the same `R` as `pre_signature`,
+/// it's up to caller to check that.
+pub fn extract<E: Curve>(pre_signature: &PreSignature<E>, signature: &Signature<E>) -> Scalar<E> {
+    &signature.s - &pre_signature.s
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_adapt_and_extract);
+    fn test_adapt_and_extract<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let secret = Scalar::<E>::random();
+        let adaptor_point = Point::generator() * &secret;
+
+        let pre_signature = pre_sign(&sk, b"swap", &adaptor_point);
+        assert!(pre_signature.verify(&pk, b"swap", &adaptor_point));
+        assert!(!pre_signature.verify(&pk, b"other", &adaptor_point));
+        // Pre-signature alone is not a valid signature
+        let incomplete = Signature {
+            r: pre_signature.r.clone(),
+            s: pre_signature.s.clone(),
+        };
+        assert!(!incomplete.verify(&pk, b"swap"));
+
+        let signature = adapt(&pre_signature, &secret);
+        assert!(signature.verify(&pk, b"swap"));
+        assert!(!signature.verify(&pk, b"other"));
+
+        let extracted = extract(&pre_signature, &signature);
+        assert_eq!(Point::generator() * &extracted, adaptor_point);
+    }
+
+    test_for_all_curves!(test_adapt_with_wrong_secret);
+    fn test_adapt_with_wrong_secret<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let adaptor_point = Point::generator() * Scalar::random();
+
+        let pre_signature = pre_sign(&sk, b"swap", &adaptor_point);
+        let signature = adapt(&pre_signature, &Scalar::random());
+        assert!(!signature.verify(&pk, b"swap"));
+    }
+}
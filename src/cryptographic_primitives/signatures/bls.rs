@@ -0,0 +1,206 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Signature is a point in G1, public key is a point in G2: sk ∈ Z_q, PK = sk G2,
+//! σ = sk H(m), where H hashes to G1. Signature is valid if e(σ, G2) = e(H(m), PK).
+//!
+//! Aggregate signature is σ = σ_1 + ... + σ_n, and it's verified against the product of pairings
+//! e(σ, G2) = e(H(m_1), PK_1) * ... * e(H(m_n), PK_n). Following the standard, aggregation is
+//! secure only if either all messages are distinct ([BLSSignature::aggregate_verify_distinct])
+//! or every public key comes with valid proof of possession ([BLSSignature::pop_verify]).
+//! Otherwise, an attacker may register rogue key PK_r = xG2 - PK_honest and forge an aggregate
+//! signature on behalf of the honest party.
+//!
+//! Messages and public keys being proven are hashed with different domain separation tags, so a
+//! proof of possession can never be used as a signature and vice versa.
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::bls12_381::g1::G1Point;
+use crate::elliptic::curves::bls12_381::Pair;
+use crate::elliptic::curves::{Bls12_381_1, Bls12_381_2, Point, Scalar};
+
+const SIGNATURE_DST: &[u8] = b"CURV_BLS_SIG_";
+const POP_DST: &[u8] = b"CURV_BLS_POP_";
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BLSSignature {
+    pub sigma: Point<Bls12_381_1>,
+}
+
+impl BLSSignature {
+    /// Computes public key `PK = sk G2` corresponding to the secret key
+    pub fn public_key(sk: &Scalar<Bls12_381_1>) -> Point<Bls12_381_2> {
+        Point::generator() * Scalar::<Bls12_381_2>::from_raw(sk.as_raw().clone())
+    }
+
+    pub fn sign(message: &[u8], sk: &Scalar<Bls12_381_1>) -> BLSSignature {
+        BLSSignature {
+            sigma: hash_to_g1(SIGNATURE_DST, message) * sk,
+        }
+    }
+
+    pub fn verify(&self, message: &[u8], pk: &Point<Bls12_381_2>) -> bool {
+        Self::aggregate_verify(&[pk], &[message], self)
+    }
+
+    /// Signs the message and produces proof of possession of the secret key
+    ///
+    /// Returns `(signature, proof_of_possession)`. Proof of possession has to be published along
+    /// with public key, see [pop_verify](Self::pop_verify).
+    pub fn sign_with_pop(message: &[u8], sk: &Scalar<Bls12_381_1>) -> (BLSSignature, BLSSignature) {
+        (Self::sign(message, sk), Self::pop_prove(sk))
+    }
+
+    /// Proves possession of secret key by signing the public key under separate domain
+    pub fn pop_prove(sk: &Scalar<Bls12_381_1>) -> BLSSignature {
+        let pk = Self::public_key(sk);
+        BLSSignature {
+            sigma: hash_to_g1(POP_DST, &pk.to_bytes(true)) * sk,
+        }
+    }
+
+    /// Validates proof of possession of secret key corresponding to the public key
+    ///
+    /// Public keys from other parties must be validated this way before they're used in
+    /// aggregate verification of signatures over the same message.
+    pub fn pop_verify(&self, pk: &Point<Bls12_381_2>) -> bool {
+        if pk.is_zero() {
+            return false;
+        }
+        let h = hash_to_g1(POP_DST, &pk.to_bytes(true));
+        Pair::compute_pairing(&self.sigma, Point::generator().as_point())
+            == Pair::compute_pairing(&h, pk)
+    }
+
+    /// Aggregates signatures into single signature
+    pub fn aggregate(signatures: &[BLSSignature]) -> BLSSignature {
+        BLSSignature {
+            sigma: signatures.iter().map(|s| &s.sigma).sum(),
+        }
+    }
+
+    /// Verifies aggregate signature over pairwise distinct messages
+    ///
+    /// Message `msgs[i]` is signed by the owner of `pks[i]`. Returns `false` if some messages
+    /// coincide, which rules out rogue key attack without proofs of possession.
+    pub fn aggregate_verify_distinct(
+        pks: &[Point<Bls12_381_2>],
+        msgs: &[&[u8]],
+        agg_sig: &BLSSignature,
+    ) -> bool {
+        let all_distinct = msgs
+            .iter()
+            .enumerate()
+            .all(|(i, m)| msgs[i + 1..].iter().all(|m2| m != m2));
+        all_distinct && Self::aggregate_verify(&pks.iter().collect::<Vec<_>>(), msgs, agg_sig)
+    }
+
+    fn aggregate_verify(
+        pks: &[&Point<Bls12_381_2>],
+        msgs: &[&[u8]],
+        agg_sig: &BLSSignature,
+    ) -> bool {
+        if pks.is_empty() || pks.len() != msgs.len() || pks.iter().any(|pk| pk.is_zero()) {
+            return false;
+        }
+        let hashes: Vec<_> = msgs.iter().map(|m| hash_to_g1(SIGNATURE_DST, m)).collect();
+        let pairs: Vec<_> = hashes.iter().zip(pks.iter().copied()).collect();
+        Pair::compute_pairing(&agg_sig.sigma, Point::generator().as_point())
+            == Pair::product_of_pairings(&pairs)
+    }
+}
+
+fn hash_to_g1(dst: &[u8], message: &[u8]) -> Point<Bls12_381_1> {
+    let p = G1Point::hash_to_curve(&[dst, message].concat());
+    Point::from_raw(p).expect("hash_to_curve output is in prime order subgroup")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_verify() {
+        let sk = Scalar::random();
+        let pk = BLSSignature::public_key(&sk);
+        let signature = BLSSignature::sign(b"message", &sk);
+        assert!(signature.verify(b"message", &pk));
+        assert!(!signature.verify(b"other message", &pk));
+    }
+
+    #[test]
+    fn test_aggregate_verify_distinct_messages() {
+        let sks: Vec<Scalar<Bls12_381_1>> = (0..3).map(|_| Scalar::random()).collect();
+        let pks: Vec<_> = sks.iter().map(BLSSignature::public_key).collect();
+        let msgs: [&[u8]; 3] = [b"message 1", b"message 2", b"message 3"];
+        let signatures: Vec<_> = sks
+            .iter()
+            .zip(&msgs)
+            .map(|(sk, m)| BLSSignature::sign(m, sk))
+            .collect();
+        let agg_sig = BLSSignature::aggregate(&signatures);
+
+        assert!(BLSSignature::aggregate_verify_distinct(
+            &pks, &msgs, &agg_sig
+        ));
+        let swapped: [&[u8]; 3] = [b"message 2", b"message 1", b"message 3"];
+        assert!(!BLSSignature::aggregate_verify_distinct(
+            &pks, &swapped, &agg_sig
+        ));
+    }
+
+    #[test]
+    fn test_rogue_key_forgery_fails() {
+        let honest_sk = Scalar::<Bls12_381_1>::random();
+        let honest_pk = BLSSignature::public_key(&honest_sk);
+
+        // Attacker knows x, but doesn't know discrete log of rogue_pk = xG2 - honest_pk
+        let x = Scalar::<Bls12_381_1>::random();
+        let rogue_pk = BLSSignature::public_key(&x) - &honest_pk;
+
+        // Forged aggregate signature claims that both parties signed the message
+        let message: &[u8] = b"transfer all funds";
+        let forged = BLSSignature::sign(message, &x);
+        let pks = [honest_pk, rogue_pk.clone()];
+
+        // Pairing equation holds, so naive aggregation would accept the forgery
+        assert!(BLSSignature::aggregate_verify(
+            &[&pks[0], &pks[1]],
+            &[message, message],
+            &forged
+        ));
+        assert!(!BLSSignature::aggregate_verify_distinct(
+            &pks,
+            &[message, message],
+            &forged
+        ));
+
+        // Attacker can't prove possession of the rogue key
+        let rogue_pop = BLSSignature {
+            sigma: hash_to_g1(POP_DST, &rogue_pk.to_bytes(true)) * &x,
+        };
+        assert!(!rogue_pop.pop_verify(&rogue_pk));
+    }
+
+    #[test]
+    fn test_proof_of_possession() {
+        let sk = Scalar::random();
+        let pk = BLSSignature::public_key(&sk);
+        let (signature, pop) = BLSSignature::sign_with_pop(b"message", &sk);
+        assert!(signature.verify(b"message", &pk));
+        assert!(pop.pop_verify(&pk));
+
+        // Proof of possession is not a signature over public key bytes and vice versa
+        assert!(!pop.verify(&pk.to_bytes(true), &pk));
+        let signature_over_pk = BLSSignature::sign(&pk.to_bytes(true), &sk);
+        assert!(!signature_over_pk.pop_verify(&pk));
+
+        let other_pk = BLSSignature::public_key(&Scalar::random());
+        assert!(!pop.pop_verify(&other_pk));
+    }
+}
@@ -0,0 +1,315 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Ed25519 signatures as specified in RFC 8032: signature (R, S) over message M is valid under
+//! public key A if S B = R + k A, where k = SHA512(R || A || M) mod l.
+//!
+//! Verification rejects malleable signatures: S must be canonical (S < l), and R and A must be
+//! points of prime order l (small order and mixed order points are rejected). With these checks,
+//! [Ed25519::batch_verify] accepts a batch if and only if every signature is accepted by
+//! [Signature::verify].
+
+use curve25519_dalek::constants::{ED25519_BASEPOINT_POINT, ED25519_BASEPOINT_TABLE};
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar as DalekScalar;
+use curve25519_dalek::traits::{IsIdentity, VartimeMultiscalarMul};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha512};
+
+use crate::arithmetic::Converter;
+use crate::elliptic::curves::{DeserializationError, Ed25519, Point, Scalar};
+
+/// Ed25519 signature `(R, S)` in its 64 bytes encoding
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Signature {
+    pub r: [u8; 32],
+    pub s: [u8; 32],
+}
+
+impl Signature {
+    /// Signs the message with 32 bytes secret key as specified in RFC 8032
+    pub fn sign(secret_key: &[u8; 32], message: &[u8]) -> Signature {
+        let (a, prefix) = expand_secret_key(secret_key);
+        let pk = (&a * &ED25519_BASEPOINT_TABLE).compress();
+
+        let r = DalekScalar::from_bytes_mod_order_wide(&sha512(&[&prefix, message]));
+        let big_r = (&r * &ED25519_BASEPOINT_TABLE).compress();
+        let k = DalekScalar::from_bytes_mod_order_wide(&sha512(&[
+            big_r.as_bytes(),
+            pk.as_bytes(),
+            message,
+        ]));
+        Signature {
+            r: big_r.to_bytes(),
+            s: (r + k * a).to_bytes(),
+        }
+    }
+
+    /// Verifies the signature of the message under public key `pk`
+    pub fn verify(&self, pk: &Point<Ed25519>, message: &[u8]) -> bool {
+        let decoded = match decode(pk, message, self) {
+            Some(decoded) => decoded,
+            None => return false,
+        };
+        let expected_r =
+            EdwardsPoint::vartime_double_scalar_mul_basepoint(&decoded.k, &-decoded.a, &decoded.s);
+        expected_r == decoded.r
+    }
+
+    /// Encodes the signature as `R || S`
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..].copy_from_slice(&self.s);
+        bytes
+    }
+
+    /// Decodes the signature from 64 bytes `R || S`
+    ///
+    /// Only the length is checked here, validity of `R` and `S` is checked at verification.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Signature, DeserializationError> {
+        if bytes.len() != 64 {
+            return Err(DeserializationError);
+        }
+        let mut signature = Signature {
+            r: [0u8; 32],
+            s: [0u8; 32],
+        };
+        signature.r.copy_from_slice(&bytes[..32]);
+        signature.s.copy_from_slice(&bytes[32..]);
+        Ok(signature)
+    }
+}
+
+/// Derives public key `A = a B` from 32 bytes secret key as specified in RFC 8032
+pub fn public_key(secret_key: &[u8; 32]) -> Point<Ed25519> {
+    let (a, _prefix) = expand_secret_key(secret_key);
+    let pk = (&a * &ED25519_BASEPOINT_TABLE).compress();
+    Point::from_bytes(pk.as_bytes()).expect("public key is a point of prime order")
+}
+
+impl Ed25519 {
+    /// Verifies a batch of signatures, `sigs[i]` being a signature of `msgs[i]` under `pks[i]`
+    ///
+    /// Signature equations are combined with random weights z_i into a single equation
+    /// (Σ z_i S_i) B - Σ z_i R_i - Σ (z_i k_i) A_i = 0, which is checked by one multi-scalar
+    /// multiplication. If it doesn't hold, the batch is bisected to find the index of the first
+    /// invalid signature, which is returned as error. Error is also returned if lengths of
+    /// `pks`, `msgs` and `sigs` differ, then it's the index of the first signature lacking
+    /// a public key or a message.
+    pub fn batch_verify(
+        pks: &[Point<Ed25519>],
+        msgs: &[&[u8]],
+        sigs: &[Signature],
+    ) -> Result<(), usize> {
+        let n = pks.len().min(msgs.len()).min(sigs.len());
+        let decoded: Vec<Option<Decoded>> =
+            (0..n).map(|i| decode(&pks[i], msgs[i], &sigs[i])).collect();
+        if let Some(i) = first_invalid(&decoded, 0) {
+            return Err(i);
+        }
+        if n != pks.len() || n != msgs.len() || n != sigs.len() {
+            return Err(n);
+        }
+        Ok(())
+    }
+}
+
+/// Signature equation `S B = R + k A` with all the values decoded and validated
+struct Decoded {
+    a: EdwardsPoint,
+    r: EdwardsPoint,
+    s: DalekScalar,
+    k: DalekScalar,
+}
+
+fn decode(pk: &Point<Ed25519>, message: &[u8], signature: &Signature) -> Option<Decoded> {
+    let s = DalekScalar::from_canonical_bytes(signature.s)?;
+    // Point::from_bytes accepts only points of prime order or identity
+    let r = Point::<Ed25519>::from_bytes(&signature.r).ok()?;
+    if r.is_zero() || pk.is_zero() {
+        return None;
+    }
+    let pk_bytes = pk.to_bytes(true);
+    let k = DalekScalar::from_bytes_mod_order_wide(&sha512(&[&signature.r, &pk_bytes, message]));
+    Some(Decoded {
+        a: to_dalek_point(&pk_bytes)?,
+        r: to_dalek_point(&signature.r)?,
+        s,
+        k,
+    })
+}
+
+/// Returns index of the first invalid signature, or `None` if all signatures are valid
+fn first_invalid(batch: &[Option<Decoded>], offset: usize) -> Option<usize> {
+    if batch.is_empty() || batch_holds(batch) {
+        return None;
+    }
+    if batch.len() == 1 {
+        return Some(offset);
+    }
+    let (left, right) = batch.split_at(batch.len() / 2);
+    first_invalid(left, offset).or_else(|| first_invalid(right, offset + left.len()))
+}
+
+fn batch_holds(batch: &[Option<Decoded>]) -> bool {
+    let batch: Vec<&Decoded> = match batch.iter().map(Option::as_ref).collect() {
+        Some(batch) => batch,
+        None => return false,
+    };
+    let weights: Vec<DalekScalar> = batch.iter().map(|_| random_weight()).collect();
+
+    let b_coefficient: DalekScalar = batch.iter().zip(&weights).map(|(d, z)| z * d.s).sum();
+    let scalars = std::iter::once(b_coefficient)
+        .chain(weights.iter().map(|z| -z))
+        .chain(batch.iter().zip(&weights).map(|(d, z)| -(z * d.k)));
+    let points = std::iter::once(ED25519_BASEPOINT_POINT)
+        .chain(batch.iter().map(|d| d.r))
+        .chain(batch.iter().map(|d| d.a));
+    EdwardsPoint::vartime_multiscalar_mul(scalars, points).is_identity()
+}
+
+fn random_weight() -> DalekScalar {
+    let z = Scalar::<Ed25519>::random().to_bigint();
+    let mut bytes = z.to_bytes_array::<32>().expect("scalar fits into 32 bytes");
+    bytes.reverse();
+    DalekScalar::from_bytes_mod_order(bytes)
+}
+
+fn expand_secret_key(secret_key: &[u8; 32]) -> (DalekScalar, [u8; 32]) {
+    let h = sha512(&[secret_key]);
+    let mut a = [0u8; 32];
+    a.copy_from_slice(&h[..32]);
+    a[0] &= 248;
+    a[31] &= 127;
+    a[31] |= 64;
+    let mut prefix = [0u8; 32];
+    prefix.copy_from_slice(&h[32..]);
+    (DalekScalar::from_bits(a), prefix)
+}
+
+fn to_dalek_point(bytes: &[u8]) -> Option<EdwardsPoint> {
+    CompressedEdwardsY::from_slice(bytes).decompress()
+}
+
+fn sha512(chunks: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    for chunk in chunks {
+        hasher.update(chunk);
+    }
+    let mut output = [0u8; 64];
+    output.copy_from_slice(&hasher.finalize());
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+
+    use super::*;
+    use crate::BigInt;
+
+    fn keypair(i: u8) -> ([u8; 32], Point<Ed25519>) {
+        let sk = [i; 32];
+        (sk, public_key(&sk))
+    }
+
+    #[test]
+    fn test_rfc8032_vector() {
+        // RFC 8032, section 7.1, TEST 1
+        let sk: [u8; 32] =
+            hex::decode("9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        let pk = public_key(&sk);
+        assert_eq!(
+            hex::encode(&*pk.to_bytes(true)),
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a"
+        );
+        let signature = Signature::sign(&sk, b"");
+        assert_eq!(
+            hex::encode(signature.to_bytes()),
+            "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b"
+        );
+        assert!(signature.verify(&pk, b""));
+        assert!(!signature.verify(&pk, b"x"));
+    }
+
+    #[test]
+    fn test_batch_verify_valid_signatures() {
+        let messages: Vec<Vec<u8>> = (0..16u8).map(|i| vec![i; i as usize]).collect();
+        let msgs: Vec<&[u8]> = messages.iter().map(Vec::as_slice).collect();
+        let (pks, sigs): (Vec<_>, Vec<_>) = msgs
+            .iter()
+            .enumerate()
+            .map(|(i, m)| {
+                let (sk, pk) = keypair(i as u8);
+                (pk, Signature::sign(&sk, m))
+            })
+            .unzip();
+
+        assert!(sigs
+            .iter()
+            .zip(&pks)
+            .zip(&msgs)
+            .all(|((s, pk), m)| s.verify(pk, m)));
+        assert_eq!(Ed25519::batch_verify(&pks, &msgs, &sigs), Ok(()));
+        assert_eq!(Ed25519::batch_verify(&[], &[], &[]), Ok(()));
+        assert_eq!(Ed25519::batch_verify(&pks, &msgs[..15], &sigs), Err(15));
+    }
+
+    #[test]
+    fn test_batch_verify_reports_tampered_signature() {
+        let msgs: Vec<&[u8]> = vec![b"block"; 16];
+        let (pks, mut sigs): (Vec<_>, Vec<_>) = (0..16u8)
+            .map(|i| {
+                let (sk, pk) = keypair(i);
+                (pk, Signature::sign(&sk, b"block"))
+            })
+            .unzip();
+
+        sigs[11].s[0] ^= 1;
+        assert!(!sigs[11].verify(&pks[11], msgs[11]));
+        assert_eq!(Ed25519::batch_verify(&pks, &msgs, &sigs), Err(11));
+    }
+
+    #[test]
+    fn test_malleable_signatures_are_rejected() {
+        let (sk, pk) = keypair(1);
+        let signature = Signature::sign(&sk, b"message");
+        assert!(signature.verify(&pk, b"message"));
+
+        // S + l is the same scalar modulo l, but non-canonical encoding
+        let mut s_be = signature.s;
+        s_be.reverse();
+        let s_plus_l = BigInt::from_bytes(&s_be) + Scalar::<Ed25519>::group_order();
+        let mut malleated = signature.clone();
+        malleated.s = s_plus_l.to_bytes_array().unwrap();
+        malleated.s.reverse();
+        assert!(!malleated.verify(&pk, b"message"));
+        assert_eq!(
+            Ed25519::batch_verify(std::slice::from_ref(&pk), &[b"message"], &[malleated]),
+            Err(0)
+        );
+
+        // R of small order: identity and point (0, -1) of order 2
+        let mut order_2 = [0xff; 32];
+        order_2[0] = 0xec;
+        order_2[31] = 0x7f;
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        for r in [identity, order_2] {
+            let small_order_r = Signature { r, s: signature.s };
+            assert!(!small_order_r.verify(&pk, b"message"));
+            assert_eq!(
+                Ed25519::batch_verify(std::slice::from_ref(&pk), &[b"message"], &[small_order_r]),
+                Err(0)
+            );
+        }
+    }
+}
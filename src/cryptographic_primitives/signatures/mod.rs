@@ -0,0 +1,32 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+/// Schnorr adaptor signatures for atomic swaps.
+/// reference: L. Aumayr, O. Ersoy, A. Erwig et al. Generalized Channels from Limited Blockchain
+/// Scripts and Adaptor Signatures. ASIACRYPT 2021. <https://eprint.iacr.org/2020/476.pdf>
+pub mod adaptor;
+
+/// BLS signatures over BLS12-381 with aggregation over distinct messages and proofs of
+/// possession.
+/// reference: D. Boneh, B. Lynn, H. Shacham. Short signatures from the Weil pairing.
+/// ASIACRYPT 2001. <https://datatracker.ietf.org/doc/draft-irtf-cfrg-bls-signature/>
+pub mod bls;
+
+/// Ed25519 signatures with batch verification.
+/// reference: S. Josefsson, I. Liusvaara. Edwards-Curve Digital Signature Algorithm (EdDSA).
+/// RFC 8032. <https://datatracker.ietf.org/doc/html/rfc8032>
+pub mod eddsa;
+
+/// MuSig2 multi-signatures with precomputed nonces.
+/// reference: J. Nick, T. Ruffing, Y. Seurin. MuSig2: Simple Two-Round Schnorr Multi-Signatures.
+/// CRYPTO 2021. <https://eprint.iacr.org/2020/1261.pdf>
+pub mod musig2;
+
+/// Schnorr signatures with offline/online signing.
+/// reference: C.P. Schnorr. Efficient Signature Generation by Smart Cards. Journal of Cryptology,
+/// 1991. <https://link.springer.com/article/10.1007/BF00196725>
+pub mod schnorr;
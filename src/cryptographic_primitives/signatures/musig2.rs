@@ -0,0 +1,445 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Two-round multi-signature: n signers with public keys X_1, ..., X_n produce a single Schnorr
+//! signature valid under aggregated key X̃ = a_1 X_1 + ... + a_n X_n, where a_i = H_agg(L, X_i)
+//! and L is the list of all public keys.
+//!
+//! Each signer holds a nonce pair (k_1, k_2) and publishes (R_1, R_2) = (k_1 G, k_2 G). Nonces
+//! don't depend on the message, so they can be generated in advance (see [precompute_nonces]).
+//! Once the message is known, public nonces are summed up into (R_1, R_2), and every signer
+//! computes b = H_non(X̃, R_1, R_2, m), R = R_1 + b R_2, c = H_sig(X̃, R, m) and partial
+//! signature s_i = k_1 + b k_2 + c a_i x_i. Signature is (R, s_1 + ... + s_n).
+//!
+//! Nonce pair must never be used twice: two signatures under the same nonces reveal the secret
+//! key. [partial_sign] takes [NoncePair] by value, and the pair can't be cloned or serialized, to
+//! make accidental reuse harder.
+
+use std::fmt;
+
+use hmac::{Hmac, Mac, NewMac};
+use serde::{Deserialize, Serialize};
+use sha2::{Sha256, Sha512};
+
+use crate::arithmetic::Converter;
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+use crate::BigInt;
+
+const KEY_AGG_LIST_DST: &[u8] = b"CURV_MUSIG2_KEYAGG_LIST_";
+const KEY_AGG_COEF_DST: &[u8] = b"CURV_MUSIG2_KEYAGG_COEF_";
+const NONCE_COEF_DST: &[u8] = b"CURV_MUSIG2_NONCE_COEF_";
+const CHALLENGE_DST: &[u8] = b"CURV_MUSIG2_CHALLENGE_";
+const NONCE_DERIVATION_LABEL: &[u8] = b"curv.musig2.precompute_nonces";
+
+/// Aggregated public key along with aggregation coefficients of every signer
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct KeyAgg<E: Curve> {
+    pub pks: Vec<Point<E>>,
+    pub coefficients: Vec<Scalar<E>>,
+    pub agg_pk: Point<E>,
+}
+
+impl<E: Curve> KeyAgg<E> {
+    /// Aggregates public keys of signers
+    ///
+    /// Order of keys matters: signer `i` refers to `pks[i]` in [partial_sign].
+    pub fn new(pks: &[Point<E>]) -> Self {
+        let list_hash = Sha256::new()
+            .chain(KEY_AGG_LIST_DST)
+            .chain_points(pks)
+            .finalize();
+        let coefficients: Vec<Scalar<E>> = pks
+            .iter()
+            .map(|pk| {
+                Sha256::new()
+                    .chain(KEY_AGG_COEF_DST)
+                    .chain(list_hash)
+                    .chain_point(pk)
+                    .result_scalar()
+            })
+            .collect();
+        let agg_pk = pks.iter().zip(&coefficients).map(|(pk, a)| pk * a).sum();
+        KeyAgg {
+            pks: pks.to_vec(),
+            coefficients,
+            agg_pk,
+        }
+    }
+}
+
+/// Public part of a nonce pair, `(R_1, R_2) = (k_1 G, k_2 G)`
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct PublicNonce<E: Curve> {
+    pub r1: Point<E>,
+    pub r2: Point<E>,
+}
+
+impl<E: Curve> PublicNonce<E> {
+    /// Sums up public nonces of all signers
+    pub fn aggregate(nonces: &[PublicNonce<E>]) -> PublicNonce<E> {
+        PublicNonce {
+            r1: nonces.iter().map(|n| &n.r1).sum(),
+            r2: nonces.iter().map(|n| &n.r2).sum(),
+        }
+    }
+}
+
+/// Secret nonce pair `(k_1, k_2)` along with its public part
+///
+/// Secret nonces can't be read, copied, or serialized: the only way to use them is to pass the
+/// pair to [partial_sign], which consumes it. Nonces derived by [precompute_nonces] don't need
+/// to be stored, they can be derived again from the same secret key and seed.
+pub struct NoncePair<E: Curve> {
+    k1: Scalar<E>,
+    k2: Scalar<E>,
+    pub public: PublicNonce<E>,
+}
+
+impl<E: Curve> NoncePair<E> {
+    /// Samples random nonce pair
+    pub fn random() -> Self {
+        Self::from_secrets(Scalar::random(), Scalar::random())
+    }
+
+    fn from_secrets(k1: Scalar<E>, k2: Scalar<E>) -> Self {
+        let g = Point::generator();
+        let public = PublicNonce {
+            r1: g * &k1,
+            r2: g * &k2,
+        };
+        NoncePair { k1, k2, public }
+    }
+}
+
+/// Deterministically derives `count` nonce pairs of the signer owning `sk`
+///
+/// Nonces are derived by HMAC-SHA512 keyed with the seed over the secret key and nonce index, so
+/// a signer can pre-generate nonce pairs before messages are known, publish public parts, and
+/// consume one pair per signature. The same `sk` and `seed` always give the same nonces, so
+/// seed must be kept secret and never reused for another batch: otherwise nonces are reused
+/// across signatures, which leaks the secret key.
+pub fn precompute_nonces<E: Curve>(
+    sk: &Scalar<E>,
+    count: usize,
+    seed: &[u8; 32],
+) -> Vec<NoncePair<E>> {
+    (0..count as u64)
+        .map(|i| {
+            NoncePair::from_secrets(derive_nonce(sk, seed, i, 1), derive_nonce(sk, seed, i, 2))
+        })
+        .collect()
+}
+
+fn derive_nonce<E: Curve>(sk: &Scalar<E>, seed: &[u8; 32], i: u64, j: u8) -> Scalar<E> {
+    for counter in 0u32.. {
+        let mut prf = Hmac::<Sha512>::new_from_slice(seed).expect("HMAC takes key of any length");
+        prf.update(NONCE_DERIVATION_LABEL);
+        prf.update(&sk.to_bytes());
+        prf.update(&i.to_be_bytes());
+        prf.update(&[j]);
+        prf.update(&counter.to_be_bytes());
+        let output = BigInt::from_bytes(&prf.finalize().into_bytes());
+        let nonce = Scalar::from_bigint(&output);
+        if !nonce.is_zero() {
+            return nonce;
+        }
+    }
+    unreachable!("PRF output is zero modulo group order for 2^32 counters in a row")
+}
+
+/// Computes partial signature of signer `i` over the message
+///
+/// `agg_nonce` is [PublicNonce::aggregate] of public nonces of all signers. Nonce pair is
+/// consumed and must not be used again.
+pub fn partial_sign<E: Curve>(
+    key_agg: &KeyAgg<E>,
+    i: usize,
+    sk: &Scalar<E>,
+    nonce: NoncePair<E>,
+    agg_nonce: &PublicNonce<E>,
+    message: &[u8],
+) -> Scalar<E> {
+    let (b, c) = coefficients(key_agg, agg_nonce, message);
+    nonce.k1 + b * nonce.k2 + c * &key_agg.coefficients[i] * sk
+}
+
+/// Verifies partial signature of signer `i` against its public nonce
+pub fn verify_partial<E: Curve>(
+    key_agg: &KeyAgg<E>,
+    i: usize,
+    public_nonce: &PublicNonce<E>,
+    agg_nonce: &PublicNonce<E>,
+    message: &[u8],
+    partial_signature: &Scalar<E>,
+) -> bool {
+    if i >= key_agg.pks.len() {
+        return false;
+    }
+    let (b, c) = coefficients(key_agg, agg_nonce, message);
+    Point::generator() * partial_signature
+        == &public_nonce.r1
+            + &public_nonce.r2 * b
+            + &key_agg.pks[i] * (c * &key_agg.coefficients[i])
+}
+
+/// Reason why partial signatures can't be aggregated
+#[derive(Clone, PartialEq, Debug)]
+pub enum AggregationError {
+    /// Number of public nonces or partial signatures differs from number of signers
+    LengthMismatch {
+        signers: usize,
+        public_nonces: usize,
+        partial_signatures: usize,
+    },
+    /// Some partial signatures didn't pass [verify_partial]
+    InvalidPartialSignatures {
+        /// Indexes (in the list of public keys) of signers that sent invalid partial signatures
+        misbehaving_signers: Vec<usize>,
+    },
+}
+
+impl fmt::Display for AggregationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AggregationError::LengthMismatch {
+                signers,
+                public_nonces,
+                partial_signatures,
+            } => write!(
+                f,
+                "expected {} public nonces and partial signatures, got {} and {}",
+                signers, public_nonces, partial_signatures
+            ),
+            AggregationError::InvalidPartialSignatures {
+                misbehaving_signers,
+            } => write!(
+                f,
+                "signers {:?} sent invalid partial signatures",
+                misbehaving_signers
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AggregationError {}
+
+/// Schnorr signature `(R, s)` valid under aggregated public key
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Signature<E: Curve> {
+    pub r: Point<E>,
+    pub s: Scalar<E>,
+}
+
+impl<E: Curve> Signature<E> {
+    /// Checks every partial signature with [verify_partial] and sums them up
+    ///
+    /// `public_nonces[i]` and `partial_signatures[i]` are sent by signer `i`. If any signer
+    /// misbehaved, reports all signers whose partial signatures are invalid, so they can be
+    /// excluded from the next signing session.
+    pub fn aggregate(
+        key_agg: &KeyAgg<E>,
+        public_nonces: &[PublicNonce<E>],
+        message: &[u8],
+        partial_signatures: &[Scalar<E>],
+    ) -> Result<Signature<E>, AggregationError> {
+        let signers = key_agg.pks.len();
+        if public_nonces.len() != signers || partial_signatures.len() != signers {
+            return Err(AggregationError::LengthMismatch {
+                signers,
+                public_nonces: public_nonces.len(),
+                partial_signatures: partial_signatures.len(),
+            });
+        }
+
+        let agg_nonce = PublicNonce::aggregate(public_nonces);
+        let misbehaving_signers: Vec<usize> = public_nonces
+            .iter()
+            .zip(partial_signatures)
+            .enumerate()
+            .filter(|(i, (nonce, s_i))| {
+                !verify_partial(key_agg, *i, nonce, &agg_nonce, message, s_i)
+            })
+            .map(|(i, _)| i)
+            .collect();
+        if !misbehaving_signers.is_empty() {
+            return Err(AggregationError::InvalidPartialSignatures {
+                misbehaving_signers,
+            });
+        }
+
+        let b = nonce_coefficient(&key_agg.agg_pk, &agg_nonce, message);
+        Ok(Signature {
+            r: &agg_nonce.r1 + &agg_nonce.r2 * b,
+            s: partial_signatures.iter().sum(),
+        })
+    }
+
+    /// Verifies the signature: `s G = R + c X̃`
+    pub fn verify(&self, agg_pk: &Point<E>, message: &[u8]) -> bool {
+        let c = challenge(agg_pk, &self.r, message);
+        Point::generator() * &self.s == &self.r + agg_pk * c
+    }
+}
+
+fn coefficients<E: Curve>(
+    key_agg: &KeyAgg<E>,
+    agg_nonce: &PublicNonce<E>,
+    message: &[u8],
+) -> (Scalar<E>, Scalar<E>) {
+    let b = nonce_coefficient(&key_agg.agg_pk, agg_nonce, message);
+    let r = &agg_nonce.r1 + &agg_nonce.r2 * &b;
+    let c = challenge(&key_agg.agg_pk, &r, message);
+    (b, c)
+}
+
+fn nonce_coefficient<E: Curve>(
+    agg_pk: &Point<E>,
+    agg_nonce: &PublicNonce<E>,
+    message: &[u8],
+) -> Scalar<E> {
+    Sha256::new()
+        .chain(NONCE_COEF_DST)
+        .chain_point(agg_pk)
+        .chain_point(&agg_nonce.r1)
+        .chain_point(&agg_nonce.r2)
+        .chain(message)
+        .result_scalar()
+}
+
+fn challenge<E: Curve>(agg_pk: &Point<E>, r: &Point<E>, message: &[u8]) -> Scalar<E> {
+    Sha256::new()
+        .chain(CHALLENGE_DST)
+        .chain_point(agg_pk)
+        .chain_point(r)
+        .chain(message)
+        .result_scalar()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_precomputed_nonces_are_deterministic);
+    fn test_precomputed_nonces_are_deterministic<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        // Public nonces determine the secret ones
+        let publics = |nonces: Vec<NoncePair<E>>| -> Vec<PublicNonce<E>> {
+            nonces.into_iter().map(|n| n.public).collect()
+        };
+        let nonces = publics(precompute_nonces(&sk, 5, &[7; 32]));
+        assert_eq!(nonces.len(), 5);
+        assert_eq!(nonces, publics(precompute_nonces(&sk, 5, &[7; 32])));
+
+        for (i, n) in nonces.iter().enumerate() {
+            assert!(nonces[i + 1..].iter().all(|m| n != m));
+        }
+        assert_ne!(nonces, publics(precompute_nonces(&sk, 5, &[8; 32])));
+        assert_ne!(
+            nonces,
+            publics(precompute_nonces(&Scalar::random(), 5, &[7; 32]))
+        );
+    }
+
+    test_for_all_curves!(test_sign_with_precomputed_nonces);
+    fn test_sign_with_precomputed_nonces<E: Curve>() {
+        let sks: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let pks: Vec<Point<E>> = sks.iter().map(|sk| Point::generator() * sk).collect();
+        let key_agg = KeyAgg::new(&pks);
+
+        let mut nonces: Vec<Vec<NoncePair<E>>> = sks
+            .iter()
+            .zip([[1; 32], [2; 32], [3; 32]].iter())
+            .map(|(sk, seed)| precompute_nonces(sk, 2, seed))
+            .collect();
+
+        for message in [&b"first message"[..], b"second message"] {
+            // Every signer consumes the next precomputed nonce pair
+            let round_nonces: Vec<NoncePair<E>> = nonces.iter_mut().map(|n| n.remove(0)).collect();
+            let publics: Vec<PublicNonce<E>> =
+                round_nonces.iter().map(|n| n.public.clone()).collect();
+            let agg_nonce = PublicNonce::aggregate(&publics);
+
+            let partials: Vec<Scalar<E>> = round_nonces
+                .into_iter()
+                .enumerate()
+                .map(|(i, nonce)| partial_sign(&key_agg, i, &sks[i], nonce, &agg_nonce, message))
+                .collect();
+            for (i, s_i) in partials.iter().enumerate() {
+                assert!(verify_partial(
+                    &key_agg,
+                    i,
+                    &publics[i],
+                    &agg_nonce,
+                    message,
+                    s_i
+                ));
+                assert!(!verify_partial(
+                    &key_agg,
+                    (i + 1) % 3,
+                    &publics[i],
+                    &agg_nonce,
+                    message,
+                    s_i
+                ));
+            }
+
+            let signature = Signature::aggregate(&key_agg, &publics, message, &partials).unwrap();
+            assert!(signature.verify(&key_agg.agg_pk, message));
+            assert!(!signature.verify(&key_agg.agg_pk, b"other message"));
+        }
+        assert!(nonces.iter().all(|n| n.is_empty()));
+    }
+
+    test_for_all_curves!(test_aggregation_flags_bad_partial_signature);
+    fn test_aggregation_flags_bad_partial_signature<E: Curve>() {
+        let sks: Vec<Scalar<E>> = (0..3).map(|_| Scalar::random()).collect();
+        let pks: Vec<Point<E>> = sks.iter().map(|sk| Point::generator() * sk).collect();
+        let key_agg = KeyAgg::new(&pks);
+        let message = b"message";
+
+        let nonces: Vec<NoncePair<E>> = sks
+            .iter()
+            .zip([[1; 32], [2; 32], [3; 32]].iter())
+            .map(|(sk, seed)| precompute_nonces(sk, 1, seed).remove(0))
+            .collect();
+        let publics: Vec<PublicNonce<E>> = nonces.iter().map(|n| n.public.clone()).collect();
+        let agg_nonce = PublicNonce::aggregate(&publics);
+        let mut partials: Vec<Scalar<E>> = nonces
+            .into_iter()
+            .enumerate()
+            .map(|(i, nonce)| partial_sign(&key_agg, i, &sks[i], nonce, &agg_nonce, message))
+            .collect();
+
+        let signature = Signature::aggregate(&key_agg, &publics, message, &partials)
+            .expect("all partial signatures are valid");
+        assert!(signature.verify(&key_agg.agg_pk, message));
+
+        // Signer 2 doesn't send its partial signature
+        assert_eq!(
+            Signature::aggregate(&key_agg, &publics, message, &partials[..2]),
+            Err(AggregationError::LengthMismatch {
+                signers: 3,
+                public_nonces: 3,
+                partial_signatures: 2
+            })
+        );
+
+        // Signer 1 sends garbage instead of its partial signature
+        partials[1] = Scalar::random();
+        assert_eq!(
+            Signature::aggregate(&key_agg, &publics, message, &partials),
+            Err(AggregationError::InvalidPartialSignatures {
+                misbehaving_signers: vec![1]
+            })
+        );
+    }
+}
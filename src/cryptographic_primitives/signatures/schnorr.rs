@@ -0,0 +1,126 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Schnorr signature (R, s) over message m is valid under public key P = xG if
+//! sG = R + cP, where c = H(R, P, m). Signer samples nonce k, and computes R = kG and
+//! s = k + cx.
+//!
+//! Nonce and its commitment R don't depend on the message, so signing can be split into offline
+//! phase ([sign_offline]) which does the scalar multiplication in advance, and cheap online
+//! phase ([sign_online]) once the message is known. Nonce must never be used twice: two
+//! signatures under the same nonce reveal the secret key, so precomputed [PartialState] is
+//! consumed by the online phase and can't be cloned.
+
+use serde::{Deserialize, Serialize};
+
+use crate::elliptic::curves::{Curve, ECScalar, Point, Scalar};
+
+/// Schnorr signature `(R, s)`
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Signature<E: Curve> {
+    pub r: Point<E>,
+    pub s: Scalar<E>,
+}
+
+impl<E: Curve> Signature<E> {
+    /// Verifies the signature: `sG = R + cP`
+    pub fn verify(&self, pk: &Point<E>, message: &[u8]) -> bool {
+        let c = challenge(&self.r, pk, message);
+        Point::generator() * &self.s == &self.r + pk * c
+    }
+}
+
+/// Signs the message
+pub fn sign<E: Curve>(sk: &Scalar<E>, message: &[u8]) -> Signature<E> {
+    sign_with_nonce(sk, &Scalar::random(), message)
+}
+
+fn sign_with_nonce<E: Curve>(sk: &Scalar<E>, k: &Scalar<E>, message: &[u8]) -> Signature<E> {
+    let g = Point::generator();
+    let r = g * k;
+    let c = challenge(&r, &(g * sk), message);
+    Signature { s: k + c * sk, r }
+}
+
+/// Message-independent part of signing, produced by [sign_offline] and consumed by [sign_online]
+pub struct PartialState<E: Curve> {
+    sk: Scalar<E>,
+    pk: Point<E>,
+    k: Scalar<E>,
+    r: Point<E>,
+}
+
+/// Samples nonce `k` and computes its commitment `R = kG`
+///
+/// Returns state to be passed to [sign_online], and `R` which can be published in advance if
+/// protocol requires.
+pub fn sign_offline<E: Curve>(sk: &Scalar<E>) -> (PartialState<E>, Point<E>) {
+    let state = partial_state(sk, Scalar::random());
+    let r = state.r.clone();
+    (state, r)
+}
+
+/// Completes signing of the message using precomputed state
+pub fn sign_online<E: Curve>(state: PartialState<E>, message: &[u8]) -> Signature<E> {
+    let c = challenge(&state.r, &state.pk, message);
+    Signature {
+        s: &state.k + c * &state.sk,
+        r: state.r,
+    }
+}
+
+fn partial_state<E: Curve>(sk: &Scalar<E>, k: Scalar<E>) -> PartialState<E> {
+    let g = Point::generator();
+    PartialState {
+        sk: sk.clone(),
+        pk: g * sk,
+        r: g * &k,
+        k,
+    }
+}
+
+pub(super) fn challenge<E: Curve>(r: &Point<E>, pk: &Point<E>, message: &[u8]) -> Scalar<E> {
+    Scalar::from_raw(E::Scalar::challenge(
+        &[r.as_raw(), pk.as_raw()],
+        &[],
+        message,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(test_sign_verify);
+    fn test_sign_verify<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+        let signature = sign(&sk, b"message");
+        assert!(signature.verify(&pk, b"message"));
+        assert!(!signature.verify(&pk, b"other message"));
+        assert!(!signature.verify(&(Point::generator() * Scalar::random()), b"message"));
+    }
+
+    test_for_all_curves!(test_offline_online_signing);
+    fn test_offline_online_signing<E: Curve>() {
+        let sk = Scalar::<E>::random();
+        let pk = Point::generator() * &sk;
+
+        let (state, r) = sign_offline(&sk);
+        let signature = sign_online(state, b"message");
+        assert_eq!(signature.r, r);
+        assert!(signature.verify(&pk, b"message"));
+
+        // Same nonce gives the same signature as one-shot signing
+        let k = Scalar::<E>::random();
+        let one_shot = sign_with_nonce(&sk, &k, b"message");
+        let split = sign_online(partial_state(&sk, k), b"message");
+        assert_eq!(split, one_shot);
+    }
+}
@@ -0,0 +1,13 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+/// This is an implementation of Wesolowski verifiable delay function.
+/// Output is y = x^(2^t) mod N computed by t sequential squarings in RSA group of unknown order,
+/// proof of correct evaluation is verified with two exponentiations by short exponents.
+/// reference: B. Wesolowski. Efficient verifiable delay functions. EUROCRYPT 2019.
+/// (<https://eprint.iacr.org/2018/623.pdf>)
+pub mod wesolowski;
@@ -0,0 +1,136 @@
+/*
+    This file is part of Curv library
+    Copyright 2018 by Kzen Networks
+    (https://github.com/KZen-networks/curv)
+    License MIT: <https://github.com/KZen-networks/curv/blob/master/LICENSE>
+*/
+
+//! Evaluator computes y = x^(2^t) mod N by t sequential squarings. To prove the result, it
+//! derives prime challenge l = H(N, x, y, t) and publishes π = x^⌊2^t / l⌋ mod N. Verifier computes
+//! r = 2^t mod l and checks π^l x^r = y mod N, which takes two exponentiations by exponents of
+//! at most 128 bits, regardless of t.
+//!
+//! Modulus N must be an RSA modulus whose factorization is unknown to everyone (eg. produced by
+//! a trusted setup or taken from RSA factoring challenge), otherwise the order of the group is
+//! known and the output can be computed without t sequential squarings.
+
+use std::convert::TryFrom;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::arithmetic::traits::*;
+use crate::cryptographic_primitives::proofs::ProofError;
+use crate::BigInt;
+
+const CHALLENGE_LABEL: &[u8] = b"curv.vdf.wesolowski";
+
+/// Bit length of the prime challenge
+const CHALLENGE_BITS: usize = 128;
+
+/// Proof that `y = x^(2^t) mod N`
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct WesolowskiProof {
+    pub pi: BigInt,
+}
+
+/// Computes `y = x^(2^t) mod N` and proof of correct evaluation
+///
+/// Takes `2t` modular squarings: `t` to compute the output and `t` to compute the proof.
+pub fn eval(x: &BigInt, t: u64, modulus: &BigInt) -> (BigInt, WesolowskiProof) {
+    let x = x.modulus(modulus);
+    let mut y = x.clone();
+    for _ in 0..t {
+        y = BigInt::mod_mul(&y, &y, modulus);
+    }
+
+    let l = challenge(&x, &y, t, modulus);
+    let q = (BigInt::one() << usize::try_from(t).expect("t doesn't fit into usize")) / &l;
+    let pi = BigInt::mod_pow(&x, &q, modulus);
+    (y, WesolowskiProof { pi })
+}
+
+/// Verifies that `y = x^(2^t) mod N`
+///
+/// Returns error if `x`, `y`, or the proof is not in range `[0; N)`, or if the proof is invalid.
+pub fn verify(
+    x: &BigInt,
+    y: &BigInt,
+    t: u64,
+    proof: &WesolowskiProof,
+    modulus: &BigInt,
+) -> Result<(), ProofError> {
+    let in_range = |n: &BigInt| &BigInt::zero() <= n && n < modulus;
+    if !in_range(x) || !in_range(y) || !in_range(&proof.pi) {
+        return Err(ProofError);
+    }
+
+    let l = challenge(x, y, t, modulus);
+    let r = BigInt::mod_pow(&BigInt::from(2), &BigInt::from(t), &l);
+    let expected = BigInt::mod_mul(
+        &BigInt::mod_pow(&proof.pi, &l, modulus),
+        &BigInt::mod_pow(x, &r, modulus),
+        modulus,
+    );
+    if expected == *y {
+        Ok(())
+    } else {
+        Err(ProofError)
+    }
+}
+
+/// Derives prime challenge `l = next_prime(H(N, x, y, t))`
+fn challenge(x: &BigInt, y: &BigInt, t: u64, modulus: &BigInt) -> BigInt {
+    let mut hasher = Sha256::new().chain(CHALLENGE_LABEL);
+    for n in [modulus, x, y] {
+        let bytes = n.to_bytes();
+        hasher = hasher
+            .chain((bytes.len() as u64).to_be_bytes())
+            .chain(&bytes);
+    }
+    let digest = hasher.chain(t.to_be_bytes()).finalize();
+    BigInt::from_bytes(&digest[..CHALLENGE_BITS / 8]).next_prime()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Product of Mersenne primes 2^127 - 1 and 2^89 - 1
+    fn modulus() -> BigInt {
+        let p = (BigInt::one() << 127) - BigInt::one();
+        let q = (BigInt::one() << 89) - BigInt::one();
+        p * q
+    }
+
+    #[test]
+    fn test_wesolowski_vdf() {
+        let n = modulus();
+        let x = BigInt::from(0xdeadbeef_u32);
+        let t = 1000;
+
+        let (y, proof) = eval(&x, t, &n);
+        assert_eq!(y, BigInt::mod_pow(&x, &(BigInt::one() << t as usize), &n));
+        assert!(verify(&x, &y, t, &proof, &n).is_ok());
+
+        let wrong_y = BigInt::mod_add(&y, &BigInt::one(), &n);
+        assert!(verify(&x, &wrong_y, t, &proof, &n).is_err());
+        assert!(verify(&x, &y, t + 1, &proof, &n).is_err());
+        assert!(verify(&(&x + BigInt::one()), &y, t, &proof, &n).is_err());
+        let wrong_proof = WesolowskiProof {
+            pi: BigInt::mod_add(&proof.pi, &BigInt::one(), &n),
+        };
+        assert!(verify(&x, &y, t, &wrong_proof, &n).is_err());
+        // Out of range values are rejected even if they're congruent to valid ones
+        assert!(verify(&x, &(&y + &n), t, &proof, &n).is_err());
+    }
+
+    #[test]
+    fn test_wesolowski_vdf_zero_delay() {
+        let n = modulus();
+        let x = BigInt::from(12345);
+        let (y, proof) = eval(&x, 0, &n);
+        assert_eq!(y, x);
+        assert!(verify(&x, &y, 0, &proof, &n).is_ok());
+    }
+}
@@ -95,6 +95,12 @@ impl ECPoint for G1Point {
         &BASE_POINT2
     }
 
+    fn derive_generator(label: &[u8], index: u64) -> G1Point {
+        let mut message = label.to_vec();
+        message.extend_from_slice(&index.to_be_bytes());
+        Self::hash_to_curve(&message)
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<G1Point, NotOnCurve> {
         let vec_x = x.to_bytes();
         let vec_y = y.to_bytes();
@@ -193,6 +199,17 @@ impl ECPoint for G1Point {
         }
     }
 
+    fn sum(points: &[Self]) -> G1Point {
+        let mut result = G1::zero();
+        for point in points {
+            result.add_assign_mixed(&point.ge);
+        }
+        G1Point {
+            purpose: "sum",
+            ge: result.into_affine(),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> G1Point {
         let mut result = G1::from(self.ge);
         result.add_assign_mixed(&other.ge);
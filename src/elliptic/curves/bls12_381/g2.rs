@@ -102,6 +102,12 @@ impl ECPoint for G2Point {
         &BASE_POINT2
     }
 
+    fn derive_generator(label: &[u8], index: u64) -> G2Point {
+        let mut message = label.to_vec();
+        message.extend_from_slice(&index.to_be_bytes());
+        Self::hash_to_curve(&message)
+    }
+
     fn from_coords(x: &BigInt, y: &BigInt) -> Result<G2Point, NotOnCurve> {
         let vec_x = x.to_bytes();
         let vec_y = y.to_bytes();
@@ -200,6 +206,17 @@ impl ECPoint for G2Point {
         }
     }
 
+    fn sum(points: &[Self]) -> G2Point {
+        let mut result = G2::zero();
+        for point in points {
+            result.add_assign_mixed(&point.ge);
+        }
+        G2Point {
+            purpose: "sum",
+            ge: result.into_affine(),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> G2Point {
         let mut result = G2::from(self.ge);
         result.add_assign_mixed(&other.ge);
@@ -55,6 +55,35 @@ impl Pair {
         }
     }
 
+    /// Efficiently computes product of many pairings
+    ///
+    /// Computes `e(p_1,q_1) * ... * e(p_n,q_n)` with a single final exponentiation. Product of
+    /// empty list is identity.
+    ///
+    /// ## Panic
+    /// Method panics if miller_loop of product is equal to zero.
+    pub fn product_of_pairings(pairs: &[(&Point<Bls12_381_1>, &Point<Bls12_381_2>)]) -> Self {
+        let prepared: Vec<_> = pairs
+            .iter()
+            .map(|(p, q)| {
+                (
+                    p.as_raw().underlying_ref().prepare(),
+                    q.as_raw().underlying_ref().prepare(),
+                )
+            })
+            .collect();
+        Pair {
+            e: Bls12::final_exponentiation(&Bls12::miller_loop(
+                prepared
+                    .iter()
+                    .map(|(p, q)| (p, q))
+                    .collect::<Vec<_>>()
+                    .iter(),
+            ))
+            .unwrap(),
+        }
+    }
+
     pub fn add_pair(&self, other: &Pair) -> Self {
         let mut res = *self;
         res.e.mul_assign(&other.e);
@@ -21,7 +21,7 @@ use zeroize::{Zeroize, Zeroizing};
 use crate::arithmetic::*;
 use crate::elliptic::curves::traits::*;
 
-use super::traits::{ECPoint, ECScalar};
+use super::traits::{ConstantTimeMul, ECPoint, ECScalar};
 
 lazy_static::lazy_static! {
     static ref GROUP_ORDER: BigInt = RistrettoScalar {
@@ -335,6 +335,13 @@ impl ECPoint for RistrettoPoint {
     }
 }
 
+impl ConstantTimeMul for RistrettoPoint {
+    /// [curve25519_dalek] multiplication is constant time, so it delegates to [scalar_mul](ECPoint::scalar_mul)
+    fn scalar_mul_ct(&self, scalar: &Self::Scalar) -> RistrettoPoint {
+        self.scalar_mul(scalar)
+    }
+}
+
 impl PartialEq for RistrettoPoint {
     fn eq(&self, other: &RistrettoPoint) -> bool {
         self.ge == other.ge
@@ -13,6 +13,9 @@ use std::sync::atomic;
 use std::{fmt, ops, ptr, str};
 
 use cryptoxide::curve25519::*;
+use curve25519_dalek::constants::ED25519_BASEPOINT_TABLE;
+use curve25519_dalek::edwards::CompressedEdwardsY;
+use curve25519_dalek::scalar::Scalar as DalekScalar;
 use generic_array::GenericArray;
 use zeroize::{Zeroize, Zeroizing};
 
@@ -20,7 +23,7 @@ use crate::arithmetic::traits::*;
 use crate::cryptographic_primitives::hashing::Digest;
 use crate::BigInt;
 
-use super::traits::{ECPoint, ECScalar};
+use super::traits::{ConstantTimeMul, ECPoint, ECScalar};
 use crate::elliptic::curves::{Curve, DeserializationError, NotOnCurve, PointCoords};
 
 lazy_static::lazy_static! {
@@ -477,6 +480,37 @@ impl ECPoint for Ed25519Point {
     }
 }
 
+impl ConstantTimeMul for Ed25519Point {
+    /// Uses constant-time variable-base multiplication from [curve25519_dalek] (fixed window
+    /// with constant-time table lookups), while [scalar_mul](Self::scalar_mul) is variable time
+    fn scalar_mul_ct(&self, fe: &Self::Scalar) -> Ed25519Point {
+        let point = CompressedEdwardsY(self.ge.to_bytes())
+            .decompress()
+            .expect("valid ed25519 point");
+        let scalar = DalekScalar::from_bits(fe.fe.to_bytes());
+        let mut result = Ed25519Point::deserialize(&(point * scalar).compress().to_bytes())
+            .expect("valid ed25519 point");
+        result.purpose = "scalar_mul_ct";
+        result
+    }
+}
+
+impl Ed25519Point {
+    /// Multiplies the generator by a scalar in constant time
+    ///
+    /// Uses precomputed table of generator multiples from [curve25519_dalek] with constant-time
+    /// table lookups, while [generator_mul](ECPoint::generator_mul) is variable time. Use it when
+    /// scalar is secret, eg. when computing public key or nonce commitment in signing.
+    pub fn generator_mul_ct(s: &Ed25519Scalar) -> Ed25519Point {
+        let scalar = DalekScalar::from_bits(s.fe.to_bytes());
+        let mut result =
+            Ed25519Point::deserialize(&(&ED25519_BASEPOINT_TABLE * &scalar).compress().to_bytes())
+                .expect("valid ed25519 point");
+        result.purpose = "generator_mul_ct";
+        result
+    }
+}
+
 #[allow(clippy::many_single_char_names)]
 //helper function, based on https://ed25519.cr.yp.to/python/ed25519.py
 fn xrecover(y_coor: &BigInt) -> BigInt {
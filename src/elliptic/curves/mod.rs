@@ -84,9 +84,15 @@ pub mod secp256_k1;
 
 #[cfg(test)]
 mod test;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 mod traits;
 mod wrappers;
 
+#[cfg(feature = "pkcs8")]
+pub use self::wrappers::pkcs8::{self, NamedCurve};
+#[cfg(feature = "encryption")]
+pub use self::wrappers::serde_secret_key_encrypted;
 #[doc(inline)]
 pub use self::{
     bls12_381::{Bls12_381_1, Bls12_381_2},
@@ -96,8 +102,11 @@ pub use self::{
     secp256_k1::Secp256k1,
 };
 pub use self::{
-    traits::{Curve, ECPoint, ECScalar, PointCoords},
-    wrappers::{EncodedPoint, EncodedScalar, Generator, Point, Scalar},
+    traits::{ConstantTimeMul, Curve, ECPoint, ECScalar, PointCoords, ShortWeierstrassPoint},
+    wrappers::{
+        serde_ristretto_point, serde_scalar_vec, EncodedPoint, EncodedScalar, Generator, Point,
+        Scalar,
+    },
 };
 
 pub mod error {
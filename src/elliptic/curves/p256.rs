@@ -12,13 +12,16 @@ use rand::{thread_rng, Rng};
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
-use super::traits::{ECPoint, ECScalar};
+use super::traits::{ConstantTimeMul, ECPoint, ECScalar, ShortWeierstrassPoint};
 use crate::arithmetic::traits::*;
 use crate::elliptic::curves::{Curve, DeserializationError, NotOnCurve, PointCoords};
 use crate::BigInt;
 
 lazy_static::lazy_static! {
     static ref GROUP_ORDER: BigInt = BigInt::from_bytes(&GROUP_ORDER_BYTES);
+    static ref FIELD_PRIME: BigInt = BigInt::from_bytes(&FIELD_PRIME_BYTES);
+    static ref COEFFICIENT_A: BigInt = &*FIELD_PRIME - BigInt::from(3);
+    static ref COEFFICIENT_B: BigInt = BigInt::from_bytes(&COEFFICIENT_B_BYTES);
 
     static ref BASE_POINT2_ENCODED: EncodedPoint = {
         let mut g = [0u8; 65];
@@ -54,6 +57,14 @@ const GROUP_ORDER_BYTES: [u8; 32] = [
     0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
     0xbc, 0xe6, 0xfa, 0xad, 0xa7, 0x17, 0x9e, 0x84, 0xf3, 0xb9, 0xca, 0xc2, 0xfc, 0x63, 0x25, 0x51,
 ];
+const FIELD_PRIME_BYTES: [u8; 32] = [
+    0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+];
+const COEFFICIENT_B_BYTES: [u8; 32] = [
+    0x5a, 0xc6, 0x35, 0xd8, 0xaa, 0x3a, 0x93, 0xe7, 0xb3, 0xeb, 0xbd, 0x55, 0x76, 0x98, 0x86, 0xbc,
+    0x65, 0x1d, 0x06, 0xb0, 0xcc, 0x53, 0xb0, 0xf6, 0x3b, 0xce, 0x3c, 0x3e, 0x27, 0xd2, 0x60, 0x4b,
+];
 
 /// P-256 curve implementation based on [p256] library
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -323,6 +334,16 @@ impl ECPoint for Secp256r1Point {
         }
     }
 
+    fn sum(points: &[Self]) -> Self {
+        Secp256r1Point {
+            purpose: "sum",
+            ge: points
+                .iter()
+                .fold(ProjectivePoint::identity(), |acc, p| acc + p.ge)
+                .to_affine(),
+        }
+    }
+
     fn add_point(&self, other: &Self) -> Self {
         Secp256r1Point {
             purpose: "add_point",
@@ -361,6 +382,25 @@ impl ECPoint for Secp256r1Point {
     }
 }
 
+impl ConstantTimeMul for Secp256r1Point {
+    /// `p256` multiplication is constant time, so it delegates to [scalar_mul](ECPoint::scalar_mul)
+    fn scalar_mul_ct(&self, scalar: &Self::Scalar) -> Secp256r1Point {
+        self.scalar_mul(scalar)
+    }
+}
+
+impl ShortWeierstrassPoint for Secp256r1Point {
+    fn field_prime() -> &'static BigInt {
+        &FIELD_PRIME
+    }
+    fn coefficient_a() -> &'static BigInt {
+        &COEFFICIENT_A
+    }
+    fn coefficient_b() -> &'static BigInt {
+        &COEFFICIENT_B
+    }
+}
+
 impl Zeroize for Secp256r1Point {
     fn zeroize(&mut self) {
         self.ge.zeroize()
@@ -27,6 +27,8 @@ use secp256k1::constants::{
 };
 use secp256k1::{PublicKey, SecretKey, SECP256K1};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
 use zeroize::{Zeroize, Zeroizing};
 
 use crate::arithmetic::*;
@@ -35,6 +37,9 @@ use super::traits::*;
 
 lazy_static::lazy_static! {
     static ref CURVE_ORDER: BigInt = BigInt::from_bytes(&constants::CURVE_ORDER);
+    static ref FIELD_PRIME: BigInt = BigInt::from_bytes(&constants::FIELD_SIZE);
+    static ref COEFFICIENT_A: BigInt = BigInt::zero();
+    static ref COEFFICIENT_B: BigInt = BigInt::from(7);
 
     static ref GENERATOR_UNCOMRESSED: [u8; 65] = {
         let mut g = [0u8; 65];
@@ -451,6 +456,21 @@ impl ECPoint for Secp256k1Point {
         }
     }
 
+    fn sum(points: &[Self]) -> Secp256k1Point {
+        let keys: Vec<&PublicKey> = points
+            .iter()
+            .filter_map(|p| p.ge.as_ref())
+            .map(|pk| &pk.0)
+            .collect();
+        // combine_keys fails if the sum is zero
+        let ge = if keys.is_empty() {
+            None
+        } else {
+            PublicKey::combine_keys(&keys).ok().map(PK)
+        };
+        Secp256k1Point { purpose: "sum", ge }
+    }
+
     fn add_point(&self, other: &Self) -> Secp256k1Point {
         let ge = match (&self.ge, &other.ge) {
             (None, right) => *right,
@@ -502,6 +522,126 @@ impl ECPoint for Secp256k1Point {
     }
 }
 
+impl Secp256k1Point {
+    /// Encodes point in 64 bytes `x || y` as used by Ethereum, ie. uncompressed form without
+    /// `0x04` header
+    ///
+    /// Zero point is encoded as 64 zero bytes.
+    pub fn to_eth_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        if let Some(ge) = &self.ge {
+            bytes.copy_from_slice(&ge.serialize_uncompressed()[1..]);
+        }
+        bytes
+    }
+
+    /// Decodes point encoded by [to_eth_bytes](Self::to_eth_bytes)
+    ///
+    /// Returns error if `x || y` is not a point on the curve.
+    pub fn from_eth_bytes(bytes: &[u8; 64]) -> Result<Secp256k1Point, DeserializationError> {
+        let mut uncompressed = [0u8; 65];
+        if bytes != &[0u8; 64] {
+            uncompressed[0] = 0x04;
+            uncompressed[1..].copy_from_slice(bytes);
+        }
+        Self::deserialize(&uncompressed)
+    }
+
+    /// Ethereum address of the public key: last 20 bytes of `keccak256(to_eth_bytes())`
+    pub fn eth_address(&self) -> [u8; 20] {
+        let hash = Keccak256::digest(&self.to_eth_bytes());
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+
+    /// Returns x-only encoding of the point (as defined in BIP-340) and parity of its y coordinate
+    ///
+    /// Zero point has no coordinates, it's encoded as 32 zero bytes with even parity.
+    pub fn to_x_only(&self) -> ([u8; 32], Parity) {
+        let compressed = self.serialize_compressed();
+        let mut x = [0u8; 32];
+        x.copy_from_slice(&compressed[1..]);
+        let parity = if compressed[0] == 0x03 {
+            Parity::Odd
+        } else {
+            Parity::Even
+        };
+        (x, parity)
+    }
+
+    /// Decodes x-only encoding, taking the point with even y coordinate (`lift_x` in BIP-340)
+    pub fn from_x_only(x: &[u8; 32]) -> Result<Secp256k1Point, DeserializationError> {
+        let mut compressed = [0x02; 33];
+        compressed[1..].copy_from_slice(x);
+        Self::deserialize(&compressed)
+    }
+
+    /// Tweaks the key as defined in BIP-341: `Q = P' + tG`, where `P'` is the point with the
+    /// same x coordinate as `self` and even y (ie. `self` negated if its y is odd)
+    ///
+    /// Returns `Q` and parity of its y coordinate. BIP-341 treats zero `Q` as failure, it's up
+    /// to caller to check that, which is only needed if `t` is chosen by adversary.
+    pub fn tweak_add(&self, t: &Secp256k1Scalar) -> (Secp256k1Point, Parity) {
+        let even = match self.to_x_only().1 {
+            Parity::Even => *self,
+            Parity::Odd => self.neg_point(),
+        };
+        let mut tweaked = even.add_point(&Self::generator_mul(t));
+        tweaked.purpose = "tweak_add";
+        let parity = tweaked.to_x_only().1;
+        (tweaked, parity)
+    }
+
+    /// Computes Taproot output key from internal key (`taproot_tweak_pubkey` in BIP-341)
+    ///
+    /// Tweak is `t = hash_TapTweak(x(P) || merkle_root)`, merkle root of the script tree is
+    /// omitted for key-path-only outputs. Returns output key and parity of its y coordinate
+    /// (which is needed for spending via script path).
+    ///
+    /// ## Panics
+    /// Panics if `t` is not less than group order (happens with negligible probability).
+    pub fn taproot_tweak(&self, merkle_root: Option<&[u8; 32]>) -> (Secp256k1Point, Parity) {
+        let tag = Sha256::digest(b"TapTweak");
+        let mut hash = Sha256::new()
+            .chain(tag)
+            .chain(tag)
+            .chain(self.to_x_only().0);
+        if let Some(merkle_root) = merkle_root {
+            hash = hash.chain(merkle_root);
+        }
+        let t = Secp256k1Scalar::deserialize(&hash.finalize())
+            .expect("tweak exceeds group order with negligible probability");
+        self.tweak_add(&t)
+    }
+}
+
+/// Parity of y coordinate of the point
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Parity {
+    Even,
+    Odd,
+}
+
+impl ConstantTimeMul for Secp256k1Point {
+    /// libsecp256k1 multiplication is constant time, so it delegates to [scalar_mul](ECPoint::scalar_mul)
+    fn scalar_mul_ct(&self, scalar: &Self::Scalar) -> Secp256k1Point {
+        self.scalar_mul(scalar)
+    }
+}
+
+impl ShortWeierstrassPoint for Secp256k1Point {
+    fn field_prime() -> &'static BigInt {
+        &FIELD_PRIME
+    }
+    fn coefficient_a() -> &'static BigInt {
+        &COEFFICIENT_A
+    }
+    fn coefficient_b() -> &'static BigInt {
+        &COEFFICIENT_B
+    }
+}
+
 impl PartialEq for Secp256k1Point {
     fn eq(&self, other: &Secp256k1Point) -> bool {
         self.underlying_ref() == other.underlying_ref()
@@ -520,7 +660,9 @@ mod test {
 
     use crate::arithmetic::*;
 
-    use super::{ECPoint, GE};
+    use std::convert::TryInto;
+
+    use super::{ECPoint, Parity, GE};
 
     #[test]
     fn test_base_point2() {
@@ -547,4 +689,66 @@ mod test {
             base_point2
         );
     }
+
+    #[test]
+    fn test_taproot_tweak() {
+        // Test vectors from BIP-341 (wallet-test-vectors.json, scriptPubKey)
+        let vectors = [
+            (
+                "d6889cb081036e0faefa3a35157ad71086b123b2b144b649798b494c300a961d",
+                None,
+                "53a1f6e454df1aa2776a2814a721372d6258050de330b3c6d10ee8f4e0dda343",
+            ),
+            (
+                "187791b6f712a8ea41c8ecdd0ee77fab3e85263b37e1ec18a3651926b3a6cf27",
+                Some("5b75adecf53548f3ec6ad7d78383bf84cc57b55a3127c72b9a2481752dd88b21"),
+                "147c9c57132f6e7ecddba9800bb0c4449251c92a1e60371ee77557b6620f3ea3",
+            ),
+        ];
+        for (internal_key, merkle_root, output_key) in vectors {
+            let internal_key = hex::decode(internal_key).unwrap();
+            let internal_key = GE::from_x_only(&internal_key.try_into().unwrap()).unwrap();
+            let merkle_root: Option<[u8; 32]> =
+                merkle_root.map(|r| hex::decode(r).unwrap().try_into().unwrap());
+
+            let (output, parity) = internal_key.taproot_tweak(merkle_root.as_ref());
+            let (x, output_parity) = output.to_x_only();
+            assert_eq!(hex::encode(x), output_key);
+            assert_eq!(parity, output_parity);
+            assert_eq!(parity, Parity::Odd);
+
+            // Internal key with odd y is tweaked the same way
+            let negated = internal_key.neg_point();
+            assert_eq!(
+                negated.to_x_only(),
+                (internal_key.to_x_only().0, Parity::Odd)
+            );
+            assert_eq!(
+                negated.taproot_tweak(merkle_root.as_ref()),
+                (output, parity)
+            );
+        }
+    }
+
+    #[test]
+    fn test_eth_bytes_and_address() {
+        // Public key of private key 1 is the generator
+        let pk = GE::generator();
+        let eth_bytes = pk.to_eth_bytes();
+        assert_eq!(eth_bytes[..], pk.serialize_uncompressed()[1..]);
+        assert_eq!(&GE::from_eth_bytes(&eth_bytes).unwrap(), pk);
+        assert_eq!(
+            hex::encode(pk.eth_address()),
+            "7e5f4552091a69125d5dfcb7b8c2659029395bdf"
+        );
+
+        let zero = GE::zero();
+        assert_eq!(zero.to_eth_bytes(), [0u8; 64]);
+        assert!(GE::from_eth_bytes(&[0u8; 64]).unwrap().is_zero());
+
+        // x || y is not on the curve
+        let mut off_curve = eth_bytes;
+        off_curve[63] ^= 1;
+        assert!(GE::from_eth_bytes(&off_curve).is_err());
+    }
 }
@@ -10,12 +10,36 @@ use crate::test_for_all_curves;
 use super::traits::*;
 
 fn random_nonzero_scalar<S: ECScalar>() -> S {
-    loop {
-        let s = S::random();
-        if !s.is_zero() {
-            break s;
-        }
-    }
+    S::random_nonzero()
+}
+
+test_for_all_curves!(add_point_handles_exceptional_cases);
+fn add_point_handles_exceptional_cases<E: Curve>() {
+    let point = E::Point::generator_mul(&random_nonzero_scalar());
+    let two = E::Scalar::from_bigint(&BigInt::from(2));
+
+    assert_eq!(point.add_point(&point), point.double(), "P + P = 2P");
+    assert_eq!(
+        point.add_point(&point),
+        point.scalar_mul(&two),
+        "P + P = 2P"
+    );
+    assert_eq!(
+        point.sub_point(&point.neg_point()),
+        point.double(),
+        "P - (-P) = 2P"
+    );
+    assert!(
+        point.add_point(&point.neg_point()).is_zero(),
+        "P + (-P) = O"
+    );
+    assert_eq!(point.add_point(&E::Point::zero()), point, "P + O = P");
+    assert_eq!(E::Point::zero().add_point(&point), point, "O + P = P");
+    assert!(
+        E::Point::zero().add_point(&E::Point::zero()).is_zero(),
+        "O + O = O"
+    );
+    assert!(E::Point::zero().double().is_zero(), "2O = O");
 }
 
 test_for_all_curves!(valid_zero_point);
@@ -53,6 +77,234 @@ fn scalar_modulo_curve_order<E: Curve>() {
     assert_eq!(s, E::Scalar::from_bigint(&BigInt::from(1)));
 }
 
+test_for_all_curves!(scalar_from_seeded_rng_is_deterministic);
+fn scalar_from_seeded_rng_is_deterministic<E: Curve>() {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    let mut rng = StdRng::seed_from_u64(42);
+    let s1 = E::Scalar::from_rng(&mut rng);
+    let s2 = E::Scalar::from_rng(&mut rng);
+    assert_ne!(s1, s2);
+    assert!(s1.to_bigint() < *E::Scalar::group_order());
+
+    let mut rng = StdRng::seed_from_u64(42);
+    assert_eq!(s1, E::Scalar::from_rng(&mut rng));
+    assert_eq!(s2, E::Scalar::from_rng(&mut rng));
+}
+
+test_for_all_curves!(scalar_from_canonical_bytes);
+fn scalar_from_canonical_bytes<E: Curve>() {
+    let s: E::Scalar = random_nonzero_scalar();
+    let bytes = s.serialize();
+    assert_eq!(E::Scalar::try_from_canonical_bytes(&bytes).unwrap(), s);
+    assert!(
+        E::Scalar::try_from_canonical_bytes(&E::Scalar::zero().serialize())
+            .unwrap()
+            .is_zero()
+    );
+
+    // Largest canonical value is order - 1, the order itself is out of range
+    let max = E::Scalar::from_bigint(&(E::Scalar::group_order() - 1));
+    let max_bytes = max.serialize();
+    assert_eq!(
+        E::Scalar::try_from_canonical_bytes(&max_bytes).unwrap(),
+        max
+    );
+    let mut order_bytes = vec![0u8; max_bytes.len()];
+    let order_be = E::Scalar::group_order().to_bytes();
+    order_bytes[max_bytes.len() - order_be.len()..].copy_from_slice(&order_be);
+    let little_endian = E::Scalar::from_bigint(&BigInt::one()).serialize()[0] == 1;
+    if little_endian {
+        order_bytes.reverse();
+    }
+    assert!(E::Scalar::try_from_canonical_bytes(&order_bytes).is_err());
+    let all_ones = vec![0xff; bytes.len()];
+    assert!(E::Scalar::try_from_canonical_bytes(&all_ones).is_err());
+
+    // Wrong lengths
+    assert!(E::Scalar::try_from_canonical_bytes(&bytes[1..]).is_err());
+    let mut longer = bytes.to_vec();
+    longer.push(0);
+    assert!(E::Scalar::try_from_canonical_bytes(&longer).is_err());
+    assert!(E::Scalar::try_from_canonical_bytes(&[]).is_err());
+}
+
+test_for_all_curves!(hash_many_to_scalars_matches_hashing_one_by_one);
+#[allow(deprecated)]
+fn hash_many_to_scalars_matches_hashing_one_by_one<E: Curve>() {
+    use crate::cryptographic_primitives::hashing::hash_sha256::HSha256;
+    use crate::cryptographic_primitives::hashing::traits::Hash;
+
+    let numbers: Vec<BigInt> = (0..6).map(|_| BigInt::sample(300)).collect();
+    let (g1, g2, g3) = (
+        [&numbers[0]],
+        [&numbers[1], &numbers[2]],
+        [&numbers[3], &numbers[4], &numbers[5]],
+    );
+    let groups: [&[&BigInt]; 4] = [&[], &g1, &g2, &g3];
+
+    let batched = E::Scalar::hash_many_to_scalars(&groups);
+    assert_eq!(batched.len(), groups.len());
+    for (group, scalar) in groups.iter().zip(&batched) {
+        assert_eq!(
+            *scalar,
+            E::Scalar::from_bigint(&HSha256::create_hash(group))
+        );
+    }
+}
+
+test_for_all_curves!(combined_entropy_depends_on_multiset_of_contributions);
+fn combined_entropy_depends_on_multiset_of_contributions<E: Curve>() {
+    let a: &[u8] = b"contribution of party A";
+    let b: &[u8] = b"contribution of party B";
+    let c: &[u8] = b"contribution of party C";
+    let combined = E::Scalar::combine_entropy(&[a, b, c]);
+    assert_eq!(combined, E::Scalar::combine_entropy(&[c, a, b]));
+    assert_eq!(combined, E::Scalar::combine_entropy(&[b, c, a]));
+
+    let changed: &[u8] = b"contribution of party C!";
+    assert_ne!(combined, E::Scalar::combine_entropy(&[a, b, changed]));
+    assert_ne!(combined, E::Scalar::combine_entropy(&[a, b]));
+    assert_ne!(combined, E::Scalar::combine_entropy(&[a, b, c, c]));
+    // Boundaries between contributions matter
+    assert_ne!(
+        E::Scalar::combine_entropy(&[b"ab", b"c"]),
+        E::Scalar::combine_entropy(&[b"a", b"bc"])
+    );
+}
+
+test_for_all_curves!(hash_to_scalar_is_domain_separated);
+fn hash_to_scalar_is_domain_separated<E: Curve>() {
+    let scalar = E::Scalar::from_hash_with_dst(b"data", b"protocol A");
+    assert_eq!(
+        scalar,
+        E::Scalar::from_hash_with_dst(b"data", b"protocol A")
+    );
+    assert_ne!(
+        scalar,
+        E::Scalar::from_hash_with_dst(b"data", b"protocol B")
+    );
+    assert_ne!(
+        scalar,
+        E::Scalar::from_hash_with_dst(b"other data", b"protocol A")
+    );
+    // Moving bytes between the tag and the data changes the output
+    assert_ne!(
+        E::Scalar::from_hash_with_dst(b"Adata", b"protocol "),
+        E::Scalar::from_hash_with_dst(b"data", b"protocol A")
+    );
+}
+
+test_for_all_curves!(deterministic_blinding_depends_on_key_and_context);
+fn deterministic_blinding_depends_on_key_and_context<E: Curve>() {
+    let sk: E::Scalar = random_nonzero_scalar();
+    let blinding = E::Scalar::deterministic_blinding(&sk, b"message");
+    assert!(!blinding.is_zero());
+    assert_eq!(blinding, E::Scalar::deterministic_blinding(&sk, b"message"));
+    assert_ne!(
+        blinding,
+        E::Scalar::deterministic_blinding(&sk, b"other message")
+    );
+    assert_ne!(blinding, E::Scalar::deterministic_blinding(&sk, b""));
+
+    let other_sk: E::Scalar = random_nonzero_scalar();
+    assert_ne!(
+        blinding,
+        E::Scalar::deterministic_blinding(&other_sk, b"message")
+    );
+}
+
+test_for_all_curves!(challenge_is_bound_to_order_and_boundaries_of_inputs);
+fn challenge_is_bound_to_order_and_boundaries_of_inputs<E: Curve>() {
+    let g = E::Point::generator();
+    let p = E::Point::generator_mul(&random_nonzero_scalar());
+    let s: E::Scalar = random_nonzero_scalar();
+
+    let challenge = E::Scalar::challenge(&[g, &p], &[&s], b"proof");
+    assert_eq!(challenge, E::Scalar::challenge(&[g, &p], &[&s], b"proof"));
+    assert_ne!(challenge, E::Scalar::challenge(&[&p, g], &[&s], b"proof"));
+    assert_ne!(challenge, E::Scalar::challenge(&[g, &p], &[&s], b"proof!"));
+    assert_ne!(challenge, E::Scalar::challenge(&[g], &[&s], b"proof"));
+    // Scalar moved into extra data gives different challenge
+    assert_ne!(
+        E::Scalar::challenge::<E::Point>(&[], &[&s], b""),
+        E::Scalar::challenge::<E::Point>(&[], &[], &s.serialize())
+    );
+}
+
+test_for_all_curves!(challenge_is_uniform);
+fn challenge_is_uniform<E: Curve>() {
+    // Every bit of a uniform scalar below group order (except a few top bits) is set with
+    // probability close to 1/2, so in 256 challenges it's set 128 ± 48 times (6 sigma)
+    let g = E::Point::generator();
+    let challenges: Vec<BigInt> = (0..256u32)
+        .map(|i| E::Scalar::challenge(&[g], &[], &i.to_be_bytes()).to_bigint())
+        .collect();
+    assert!(challenges.iter().all(|c| c < E::Scalar::group_order()));
+    for bit in [0, 1, 64, 128, 200] {
+        let ones = challenges.iter().filter(|c| c.test_bit(bit)).count();
+        assert!(
+            (80..=176).contains(&ones),
+            "bit {} is set {} times",
+            bit,
+            ones
+        );
+    }
+}
+
+test_for_all_curves!(from_bytes_reduce_ct_matches_bigint_reduction);
+fn from_bytes_reduce_ct_matches_bigint_reduction<E: Curve>() {
+    let mut rng = OsRng;
+    // Ed25519 `from_bigint` truncates inputs longer than 64 bytes, so compare only up to 64 bytes
+    for len in [0, 1, 31, 32, 33, 48, 64] {
+        for _ in 0..20 {
+            let mut bytes = vec![0u8; len];
+            rng.fill(&mut bytes[..]);
+            let expected = E::Scalar::from_bigint(&BigInt::from_bytes(&bytes));
+            assert_eq!(
+                E::Scalar::from_bytes_reduce_ct(&bytes),
+                expected,
+                "{:?}",
+                bytes
+            );
+        }
+        let max = vec![0xff; len];
+        assert_eq!(
+            E::Scalar::from_bytes_reduce_ct(&max),
+            E::Scalar::from_bigint(&BigInt::from_bytes(&max))
+        );
+    }
+    let order = E::Scalar::group_order().to_bytes();
+    assert!(E::Scalar::from_bytes_reduce_ct(&order).is_zero());
+}
+
+test_for_all_curves!(scalar_bits_round_trip);
+fn scalar_bits_round_trip<E: Curve>() {
+    let bit_length = E::Scalar::group_order().bit_length();
+    for _ in 0..10 {
+        let x = random_nonzero_scalar::<E::Scalar>();
+        let bits = x.to_bits();
+        assert_eq!(bits.len(), bit_length);
+        assert_eq!(E::Scalar::from_bits(&bits), x);
+    }
+
+    let five = E::Scalar::from_bigint(&BigInt::from(5)).to_bits();
+    assert_eq!(five[..4], [true, false, true, false]);
+    assert!(five[4..].iter().all(|&bit| !bit));
+    assert!(E::Scalar::zero().to_bits().iter().all(|&bit| !bit));
+    assert!(E::Scalar::from_bits(&[]).is_zero());
+}
+
+test_for_all_curves!(random_nonzero_scalar_is_invertible);
+fn random_nonzero_scalar_is_invertible<E: Curve>() {
+    for _ in 0..100 {
+        let s = E::Scalar::random_nonzero();
+        assert!(!s.is_zero());
+        let s_inv = s.invert().expect("nonzero scalar is invertible");
+        assert_eq!(s.mul(&s_inv), E::Scalar::from_bigint(&BigInt::one()));
+    }
+}
+
 test_for_all_curves!(zero_scalar_arithmetic);
 fn zero_scalar_arithmetic<E: Curve>() {
     let s: E::Scalar = random_nonzero_scalar();
@@ -92,6 +344,38 @@ fn serialize_deserialize_point<E: Curve>() {
     }
 }
 
+test_for_all_curves!(scalar_to_point_is_generator_mul);
+fn scalar_to_point_is_generator_mul<E: Curve>() {
+    use super::{Point, Scalar};
+    for _ in 0..10 {
+        let s = Scalar::<E>::random();
+        let expected = Point::from_raw(E::Point::generator().scalar_mul(s.as_raw())).unwrap();
+        assert_eq!(s.to_point(), expected);
+        assert_eq!(Point::from_scalar(&s), expected);
+    }
+    assert!(Scalar::<E>::zero().to_point().is_zero());
+}
+
+test_for_all_curves!(compressed_and_uncompressed_encodings_are_consistent);
+fn compressed_and_uncompressed_encodings_are_consistent<E: Curve>() {
+    let point = <E::Point as ECPoint>::generator().scalar_mul(&random_nonzero_scalar());
+    let compressed = point.serialize_compressed();
+    let uncompressed = point.serialize_uncompressed();
+    let from_compressed = <E::Point as ECPoint>::deserialize(&compressed).unwrap();
+    let from_uncompressed = <E::Point as ECPoint>::deserialize(&uncompressed).unwrap();
+    assert_eq!(from_compressed, from_uncompressed);
+
+    if compressed.len() != uncompressed.len() {
+        // Header that doesn't match the length of encoding is rejected
+        let mut uncompressed_with_wrong_header = uncompressed.to_vec();
+        uncompressed_with_wrong_header[0] = compressed[0];
+        assert!(<E::Point as ECPoint>::deserialize(&uncompressed_with_wrong_header).is_err());
+        let mut compressed_with_wrong_header = compressed.to_vec();
+        compressed_with_wrong_header[0] = uncompressed[0];
+        assert!(<E::Point as ECPoint>::deserialize(&compressed_with_wrong_header).is_err());
+    }
+}
+
 test_for_all_curves!(zero_point_serialization);
 fn zero_point_serialization<E: Curve>() {
     let point: E::Point = ECPoint::zero();
@@ -306,6 +590,82 @@ fn serialize_deserialize_scalar<E: Curve>() {
     }
 }
 
+test_for_all_curves!(serialize_deserialize_scalar_be);
+fn serialize_deserialize_scalar_be<E: Curve>() {
+    let small = E::Scalar::from_bigint(&BigInt::from(0x0102));
+    let bytes = small.serialize_be();
+    assert!(bytes[..bytes.len() - 2].iter().all(|b| *b == 0));
+    assert_eq!(bytes[bytes.len() - 2..], [0x01, 0x02]);
+
+    let random: E::Scalar = random_nonzero_scalar();
+    for scalar in [small, random, E::Scalar::zero()] {
+        let bytes = scalar.serialize_be();
+        assert_eq!(BigInt::from_bytes(&bytes), scalar.to_bigint());
+        let deserialized = E::Scalar::deserialize_be(&bytes).unwrap();
+        assert_eq!(scalar, deserialized);
+    }
+}
+
+test_for_all_curves!(deserialize_be_rejects_group_order);
+fn deserialize_be_rejects_group_order<E: Curve>() {
+    let q = E::Scalar::group_order().to_bytes();
+    let mut bytes = generic_array::GenericArray::default();
+    let offset = bytes.len() - q.len();
+    bytes[offset..].copy_from_slice(&q);
+    assert!(E::Scalar::deserialize_be(&bytes).is_err());
+}
+
+test_for_all_curves!(ct_is_zero_scalar);
+fn ct_is_zero_scalar<E: Curve>() {
+    let zero = E::Scalar::zero();
+    assert_eq!(zero.ct_is_zero().unwrap_u8(), 1);
+    let zero = E::Scalar::from_bigint(E::Scalar::group_order());
+    assert_eq!(zero.ct_is_zero().unwrap_u8(), 1);
+    let nonzero: E::Scalar = random_nonzero_scalar();
+    assert_eq!(nonzero.ct_is_zero().unwrap_u8(), 0);
+}
+
+#[test]
+fn scalar_mul_ct_matches_scalar_mul_secp256k1() {
+    scalar_mul_ct_matches_scalar_mul::<super::Secp256k1>()
+}
+
+#[test]
+fn scalar_mul_ct_matches_scalar_mul_p256() {
+    scalar_mul_ct_matches_scalar_mul::<super::Secp256r1>()
+}
+
+#[test]
+fn scalar_mul_ct_matches_scalar_mul_ed25519() {
+    scalar_mul_ct_matches_scalar_mul::<super::Ed25519>()
+}
+
+#[test]
+fn scalar_mul_ct_matches_scalar_mul_ristretto() {
+    scalar_mul_ct_matches_scalar_mul::<super::Ristretto>()
+}
+
+fn scalar_mul_ct_matches_scalar_mul<E: Curve>()
+where
+    E::Point: ConstantTimeMul,
+{
+    use super::{Point, Scalar};
+
+    let point = <E::Point as ECPoint>::generator_mul(&random_nonzero_scalar());
+    for _ in 0..100 {
+        let scalar: E::Scalar = ECScalar::random();
+        assert_eq!(point.scalar_mul_ct(&scalar), point.scalar_mul(&scalar));
+    }
+    assert!(point.scalar_mul_ct(&E::Scalar::zero()).is_zero());
+    assert!(E::Point::zero()
+        .scalar_mul_ct(&random_nonzero_scalar())
+        .is_zero());
+
+    let point = Point::<E>::generator() * Scalar::random();
+    let scalar = Scalar::random();
+    assert_eq!(point.mul_ct(&scalar), &point * &scalar);
+}
+
 test_for_all_curves!(scalar_invert);
 fn scalar_invert<E: Curve>() {
     let n: E::Scalar = random_nonzero_scalar();
@@ -358,3 +718,301 @@ fn scalar_assign_negation<E: Curve>() {
     };
     assert_eq!(s_neg_1, s_neg_2);
 }
+
+test_for_all_curves!(ct_eq_of_points);
+fn ct_eq_of_points<E: Curve>() {
+    let p1 = <E::Point as ECPoint>::generator_mul(&random_nonzero_scalar());
+    let p2 = p1.clone();
+    let p3 = p1.add_point(ECPoint::generator());
+    let zero = E::Point::zero();
+
+    assert_eq!(p1.ct_eq(&p2).unwrap_u8(), 1);
+    assert_eq!(zero.ct_eq(&E::Point::zero()).unwrap_u8(), 1);
+    assert_eq!(p1.ct_eq(&p3).unwrap_u8(), 0);
+    assert_eq!(p1.ct_eq(&zero).unwrap_u8(), 0);
+}
+
+/// Finds a point whose compressed encoding differs from `point`'s only in the last byte, and
+/// asserts that they're not equal in constant time
+fn ct_eq_of_points_differing_in_last_byte<E: Curve>() {
+    let point = <E::Point as ECPoint>::generator_mul(&random_nonzero_scalar());
+    let bytes = point.serialize_compressed();
+
+    let mut found = false;
+    for delta in 1..=u8::MAX {
+        let mut tweaked = bytes.clone();
+        let last = tweaked.len() - 1;
+        tweaked[last] = tweaked[last].wrapping_add(delta);
+        if let Ok(other) = <E::Point as ECPoint>::deserialize(&tweaked) {
+            assert_eq!(point.ct_eq(&other).unwrap_u8(), 0);
+            found = true;
+            break;
+        }
+    }
+    assert!(
+        found,
+        "couldn't find a point differing only in the last byte"
+    );
+}
+
+#[test]
+fn ct_eq_of_points_differing_in_last_byte_secp256k1() {
+    ct_eq_of_points_differing_in_last_byte::<super::Secp256k1>()
+}
+
+#[test]
+fn ct_eq_of_points_differing_in_last_byte_p256() {
+    ct_eq_of_points_differing_in_last_byte::<super::Secp256r1>()
+}
+
+#[test]
+fn ct_eq_of_points_differing_in_last_byte_ed25519() {
+    ct_eq_of_points_differing_in_last_byte::<super::Ed25519>()
+}
+
+test_for_all_curves!(derive_generators);
+fn derive_generators<E: Curve>() {
+    use super::Point;
+
+    let generators = Point::<E>::derive_generators(b"vector pedersen", 5);
+    assert_eq!(generators.len(), 5);
+    for (i, g_i) in generators.iter().enumerate() {
+        assert!(!g_i.is_zero());
+        assert_ne!(g_i, &Point::generator().to_point());
+        assert!(g_i.as_raw().check_point_order_equals_group_order());
+        for g_j in &generators[i + 1..] {
+            assert_ne!(g_i, g_j);
+        }
+    }
+
+    assert_eq!(
+        generators,
+        Point::<E>::derive_generators(b"vector pedersen", 5)
+    );
+    let other = Point::<E>::derive_generators(b"another label", 5);
+    for (g, g_other) in generators.iter().zip(&other) {
+        assert_ne!(g, g_other);
+    }
+}
+
+test_for_all_curves!(from_coords_le_and_be_produce_the_same_point);
+fn from_coords_le_and_be_produce_the_same_point<E: Curve>() {
+    use super::{Point, Scalar};
+
+    let point = Point::<E>::generator() * Scalar::random();
+    let coords = match point.coords() {
+        Some(coords) => coords,
+        None => {
+            // Curve doesn't expose coordinates (e.g. Ristretto)
+            return;
+        }
+    };
+    let x_be = coords.x.to_bytes();
+    let y_be = coords.y.to_bytes();
+    let x_le: Vec<u8> = x_be.iter().rev().copied().collect();
+    let y_le: Vec<u8> = y_be.iter().rev().copied().collect();
+
+    let point_be = Point::<E>::from_coords_be(&x_be, &y_be).unwrap();
+    let point_le = Point::<E>::from_coords_le(&x_le, &y_le).unwrap();
+    assert_eq!(point_be, point);
+    assert_eq!(point_le, point);
+}
+
+test_for_all_curves!(valid_point_is_accepted);
+fn valid_point_is_accepted<E: Curve>() {
+    use super::error::InvalidPoint;
+
+    let point = <E::Point as ECPoint>::generator_mul(&random_nonzero_scalar());
+    assert!(point.is_valid_point().is_ok());
+    assert!(E::Point::generator().is_valid_point().is_ok());
+    assert!(matches!(
+        E::Point::zero().is_valid_point(),
+        Err(InvalidPoint::ZeroPoint)
+    ));
+}
+
+#[test]
+fn small_order_point_is_rejected_ed25519() {
+    use super::ed25519::Ed25519Point;
+    use super::error::InvalidPoint;
+
+    // (0, -1) is a point of order 2, encoded as y = p - 1
+    let mut encoding = [0xff; 32];
+    encoding[0] = 0xec;
+    encoding[31] = 0x7f;
+    let small_order = Ed25519Point::deserialize(&encoding).unwrap();
+    assert!(!small_order.is_zero());
+    assert!(small_order.add_point(&small_order).is_zero());
+    assert!(matches!(
+        small_order.is_valid_point(),
+        Err(InvalidPoint::MismatchedPointOrder)
+    ));
+
+    // Point of mixed order 2q
+    let mixed = Ed25519Point::generator().add_point(&small_order);
+    assert!(matches!(
+        mixed.is_valid_point(),
+        Err(InvalidPoint::MismatchedPointOrder)
+    ));
+    assert!(super::Point::<super::Ed25519>::from_bytes(&mixed.serialize_compressed()).is_err());
+}
+
+#[test]
+fn generator_mul_ct_matches_generator_mul_ed25519() {
+    use super::ed25519::{Ed25519Point, Ed25519Scalar};
+
+    // generator_mul_ct does table lookups in constant time, so it must be a drop-in replacement
+    // for variable-time generator_mul on secret scalars. Only correctness is tested here, timing
+    // isn't observable in unit tests.
+    for _ in 0..100 {
+        let scalar: Ed25519Scalar = random_nonzero_scalar();
+        assert_eq!(
+            Ed25519Point::generator_mul_ct(&scalar),
+            Ed25519Point::generator_mul(&scalar)
+        );
+    }
+    assert!(Ed25519Point::generator_mul_ct(&Ed25519Scalar::zero()).is_zero());
+    assert_eq!(
+        Ed25519Point::generator_mul_ct(&Ed25519Scalar::from_bigint(&BigInt::one())),
+        *Ed25519Point::generator()
+    );
+}
+
+test_for_all_curves!(sum_of_points_equals_sequential_addition);
+fn sum_of_points_equals_sequential_addition<E: Curve>() {
+    let fold = |points: &[E::Point]| {
+        points
+            .iter()
+            .fold(E::Point::zero(), |acc, p| acc.add_point(p))
+    };
+
+    assert!(E::Point::sum(&[]).is_zero());
+
+    let single = [E::Point::generator_mul(&random_nonzero_scalar())];
+    assert_eq!(E::Point::sum(&single), single[0]);
+
+    let mut points: Vec<E::Point> = iter::repeat_with(random_nonzero_scalar)
+        .map(|s| E::Point::generator_mul(&s))
+        .take(20)
+        .collect();
+    points.push(E::Point::zero());
+    assert_eq!(E::Point::sum(&points), fold(&points));
+
+    // Sum turns to zero
+    let negation = points[0].neg_point();
+    let cancelling = [points[0].clone(), negation];
+    assert!(E::Point::sum(&cancelling).is_zero());
+}
+
+#[test]
+fn compress_decompress_secp256k1() {
+    compress_decompress::<super::secp256_k1::Secp256k1Point>()
+}
+
+#[test]
+fn compress_decompress_p256() {
+    compress_decompress::<super::p256::Secp256r1Point>()
+}
+
+fn compress_decompress<P: ShortWeierstrassPoint>() {
+    let mut seen_even = false;
+    let mut seen_odd = false;
+    while !(seen_even && seen_odd) {
+        let point = P::generator_mul(&random_nonzero_scalar());
+        let compressed = point.compress();
+        assert_eq!(&compressed[..], &point.serialize_compressed()[..]);
+        match compressed[0] {
+            0x02 => seen_even = true,
+            0x03 => seen_odd = true,
+            header => panic!("unexpected header {:#x}", header),
+        }
+        assert_eq!(P::decompress(&compressed).unwrap(), point);
+    }
+
+    assert_eq!(P::zero().compress(), [0u8; 33]);
+    assert!(P::decompress(&[0u8; 33]).unwrap().is_zero());
+
+    let mut garbage = P::generator().compress();
+    for header in [0x00, 0x01, 0x04, 0x05, 0xff] {
+        garbage[0] = header;
+        assert!(P::decompress(&garbage).is_err());
+    }
+
+    // x = p is not a canonical field element
+    let mut non_canonical = [0x02; 33];
+    non_canonical[1..].copy_from_slice(&P::field_prime().to_bytes_array::<32>().unwrap());
+    assert!(P::decompress(&non_canonical).is_err());
+}
+
+#[test]
+fn point_coords_bytes64_round_trip_with_small_x() {
+    use super::secp256_k1::Secp256k1Point;
+
+    // Find a point whose x coordinate takes a single byte
+    let point = (1u8..)
+        .find_map(|x| {
+            let mut compressed = [0u8; 33];
+            compressed[0] = 0x02;
+            compressed[32] = x;
+            Secp256k1Point::decompress(&compressed).ok()
+        })
+        .unwrap();
+    let coords = point.coords().unwrap();
+    assert!(coords.x < BigInt::from(256));
+
+    let bytes = coords.to_bytes64();
+    assert_eq!(bytes[..31], [0u8; 31]);
+    assert_eq!(BigInt::from_bytes(&bytes[..32]), coords.x);
+    assert_eq!(BigInt::from_bytes(&bytes[32..]), coords.y);
+
+    let decoded = PointCoords::from_bytes64(&bytes);
+    assert_eq!(decoded.x, coords.x);
+    assert_eq!(decoded.y, coords.y);
+    assert_eq!(
+        Secp256k1Point::from_coords(&decoded.x, &decoded.y).unwrap(),
+        point
+    );
+}
+
+#[test]
+fn fingerprint_of_secp256k1_public_key() {
+    use super::{Point, Secp256k1};
+
+    // Public key of secret key 1 is the generator
+    let pk = super::secp256_k1::Secp256k1Point::generator();
+    assert_eq!(
+        hex::encode(pk.fingerprint()),
+        "751e76e8199196d454941c45d1b3a323f1433bd6"
+    );
+    assert_eq!(
+        Point::<Secp256k1>::generator()
+            .to_point()
+            .fingerprint_base58check(0),
+        "1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH"
+    );
+}
+
+test_for_all_curves!(distinct_points_have_distinct_fingerprints);
+fn distinct_points_have_distinct_fingerprints<E: Curve>() {
+    let p1 = E::Point::generator_mul(&random_nonzero_scalar());
+    let p2 = E::Point::generator_mul(&random_nonzero_scalar());
+    assert_ne!(p1, p2);
+    assert_eq!(p1.fingerprint(), p1.clone().fingerprint());
+    assert_ne!(p1.fingerprint(), p2.fingerprint());
+}
+
+test_for_all_curves!(mul_u64_matches_scalar_mul);
+fn mul_u64_matches_scalar_mul<E: Curve>() {
+    let point = E::Point::generator_mul(&random_nonzero_scalar());
+    assert_eq!(point.mul_u64(2), point.add_point(&point));
+    assert_eq!(point.double(), point.add_point(&point));
+    assert_eq!(point.triple(), point.add_point(&point).add_point(&point));
+    assert!(point.mul_u64(0).is_zero());
+    assert_eq!(point.mul_u64(1), point);
+    assert!(E::Point::zero().mul_u64(8).is_zero());
+
+    for n in [3, 4, 5, 8, 13, 255, 1 << 20, u64::MAX] {
+        let expected = point.scalar_mul(&E::Scalar::from_bigint(&BigInt::from(n)));
+        assert_eq!(point.mul_u64(n), expected, "n = {}", n);
+    }
+}
@@ -0,0 +1,81 @@
+//! Deterministic scalars and points for reproducible tests
+//!
+//! Values are derived from a `u64` seed by hashing it with SHA-512, so the same seed yields the
+//! same scalar/point on every run and platform. This lets a failing test based on random values
+//! be replayed exactly. Never use these functions to produce secrets.
+//!
+//! Requires `testing` feature to be enabled.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use curv::elliptic::curves::{Secp256k1, testing::{deterministic_point, deterministic_scalar}};
+//!
+//! let x = deterministic_scalar::<Secp256k1>(42);
+//! assert_eq!(x, deterministic_scalar::<Secp256k1>(42));
+//! assert_eq!(deterministic_point::<Secp256k1>(42), deterministic_point::<Secp256k1>(42));
+//! ```
+
+use sha2::Sha512;
+
+use crate::cryptographic_primitives::hashing::{Digest, DigestExt};
+use crate::elliptic::curves::{Curve, Point, Scalar};
+
+/// Derives a scalar from the seed
+pub fn deterministic_scalar<E: Curve>(seed: u64) -> Scalar<E> {
+    let n = Sha512::new()
+        .chain(b"curv.testing.deterministic_scalar")
+        .chain(seed.to_be_bytes())
+        .result_bigint();
+    Scalar::from_bigint(&n)
+}
+
+/// Derives a point from the seed
+///
+/// Point is computed as `s * G` where `s` is derived from the seed independently from
+/// [deterministic_scalar], ie. the point's discrete log differs from `deterministic_scalar(seed)`.
+pub fn deterministic_point<E: Curve>(seed: u64) -> Point<E> {
+    let n = Sha512::new()
+        .chain(b"curv.testing.deterministic_point")
+        .chain(seed.to_be_bytes())
+        .result_bigint();
+    Point::generator() * Scalar::from_bigint(&n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_for_all_curves;
+
+    test_for_all_curves!(same_seed_reproduces_same_values);
+    fn same_seed_reproduces_same_values<E: Curve>() {
+        assert_eq!(
+            deterministic_scalar::<E>(1337),
+            deterministic_scalar::<E>(1337)
+        );
+        assert_eq!(
+            deterministic_point::<E>(1337),
+            deterministic_point::<E>(1337)
+        );
+        assert_ne!(
+            deterministic_scalar::<E>(1337),
+            deterministic_scalar::<E>(1338)
+        );
+        assert_ne!(
+            deterministic_point::<E>(1337),
+            deterministic_point::<E>(1338)
+        );
+    }
+
+    #[test]
+    fn deterministic_scalar_is_stable_across_runs() {
+        use crate::arithmetic::Converter;
+        use crate::elliptic::curves::Secp256k1;
+        use crate::BigInt;
+
+        let expected =
+            BigInt::from_hex("c7aa18540385b6d2be48ffdf4c05d0edbf63df1689195e77e3fefdace77357ef")
+                .unwrap();
+        assert_eq!(deterministic_scalar::<Secp256k1>(0).to_bigint(), expected);
+    }
+}
@@ -8,11 +8,17 @@
 use std::fmt;
 
 use generic_array::{ArrayLength, GenericArray};
+use hmac::{Hmac, Mac, NewMac};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+use subtle::{Choice, ConstantTimeEq};
 use typenum::Unsigned;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
-use crate::BigInt;
+use crate::arithmetic::{BitManipulation, Converter, Modulo, Samplable};
+use crate::cryptographic_primitives::hashing::hash160::hash160;
+use crate::elliptic::curves::error::InvalidPoint;
+use crate::{BigInt, RandomSource};
 
 /// Elliptic curve implementation
 ///
@@ -45,6 +51,22 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + 'static {
 
     /// Samples a random scalar
     fn random() -> Self;
+    /// Samples a random nonzero scalar
+    ///
+    /// Resamples until the scalar is nonzero (which is practically never needed), so the result is
+    /// always invertible.
+    fn random_nonzero() -> Self {
+        loop {
+            let s = Self::random();
+            if !s.is_zero() {
+                break s;
+            }
+        }
+    }
+    /// Samples a random scalar using given source of randomness
+    fn from_rng<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
+        Self::from_bigint(&BigInt::sample_below_with_rng(Self::group_order(), rng))
+    }
 
     /// Constructs a zero scalar
     fn zero() -> Self;
@@ -52,15 +74,209 @@ pub trait ECScalar: Clone + PartialEq + fmt::Debug + 'static {
     fn is_zero(&self) -> bool {
         self == &Self::zero()
     }
+    /// Checks if the scalar equals to zero in constant time
+    ///
+    /// Unlike [is_zero](Self::is_zero), it doesn't branch on scalar value, so it can be used for
+    /// secret-derived scalars (e.g. to reject degenerate nonces).
+    fn ct_is_zero(&self) -> Choice {
+        let bytes = self.serialize();
+        let zero = GenericArray::<u8, Self::ScalarLength>::default();
+        bytes[..].ct_eq(&zero[..])
+    }
 
     /// Constructs a scalar `n % group_order`
     fn from_bigint(n: &BigInt) -> Self;
+    /// Hashes every group of bigints with SHA256 and reduces each digest modulo group order
+    ///
+    /// Output `i` equals to `from_bigint(HSha256::create_hash(inputs[i]))`, but hasher is set up
+    /// once for the whole batch.
+    fn hash_many_to_scalars(inputs: &[&[&BigInt]]) -> Vec<Self> {
+        let hasher = Sha256::new();
+        inputs
+            .iter()
+            .map(|group| {
+                let mut hasher = hasher.clone();
+                for n in group.iter() {
+                    hasher.update(n.to_bytes());
+                }
+                Self::from_bigint(&BigInt::from_bytes(&hasher.finalize()))
+            })
+            .collect()
+    }
+    /// Combines entropy contributions of several parties into a single scalar
+    ///
+    /// Contributions are sorted, length-prefixed and hashed together with SHA512, and the digest
+    /// is reduced modulo group order. Output depends only on the multiset of contributions, not
+    /// on their order, and it's uniform as long as at least one contribution is uniform and
+    /// independent of the others. Parties must commit to their contributions before revealing
+    /// them, otherwise the last party to reveal can bias the output.
+    fn combine_entropy(contributions: &[&[u8]]) -> Self {
+        let mut sorted = contributions.to_vec();
+        sorted.sort_unstable();
+        let mut hasher = Sha512::new();
+        hasher.update(b"curv.combine_entropy");
+        hasher.update((sorted.len() as u64).to_be_bytes());
+        for contribution in sorted {
+            hasher.update((contribution.len() as u64).to_be_bytes());
+            hasher.update(contribution);
+        }
+        Self::from_bigint(&BigInt::from_bytes(&hasher.finalize()))
+    }
+    /// Hashes `data` into a scalar under domain separation tag `dst`
+    ///
+    /// Recommended way to derive a scalar from bytes: every protocol (or every use of the hash
+    /// within a protocol) should pick its own `dst`, so that outputs derived in one context can't
+    /// coincide with outputs derived in another one. The tag is length-prefixed and hashed along
+    /// with `data` by SHA512, and the digest, twice as long as group order, is reduced by
+    /// [from_bytes_reduce_ct](Self::from_bytes_reduce_ct), giving a scalar that is statistically
+    /// close to uniform.
+    fn from_hash_with_dst(data: &[u8], dst: &[u8]) -> Self {
+        let mut hasher = Sha512::new();
+        hasher.update(b"curv.hash_to_scalar");
+        hasher.update((dst.len() as u64).to_be_bytes());
+        hasher.update(dst);
+        hasher.update(data);
+        Self::from_bytes_reduce_ct(&hasher.finalize())
+    }
+    /// Derives blinding factor (or nonce) from secret key `sk` and `context` deterministically
+    ///
+    /// Output is HMAC-SHA512 keyed by `sk` over `context`, reduced modulo group order. It's
+    /// a pseudorandom function of `context`, so blindings for distinct contexts are independent,
+    /// and no randomness source or state is needed to avoid reusing a blinding. The same
+    /// `(sk, context)` always gives the same blinding, so `context` must include everything the
+    /// blinding is used for (eg. the message being committed or signed).
+    fn deterministic_blinding(sk: &Self, context: &[u8]) -> Self {
+        let key = Zeroizing::new(sk.serialize().to_vec());
+        let mut prf = Hmac::<Sha512>::new_from_slice(&key).expect("HMAC takes key of any length");
+        prf.update(b"curv.deterministic_blinding");
+        prf.update(context);
+        let output = Zeroizing::new(prf.finalize().into_bytes().to_vec());
+        Self::from_bytes_reduce_ct(&output)
+    }
+    /// Derives Fiat-Shamir challenge from points, scalars and extra data
+    ///
+    /// Every list and every element is length-prefixed before hashing with SHA512, so different
+    /// inputs can't produce the same byte string (eg. by moving bytes from one element to the
+    /// next one, or from scalars to `extra`). The digest is twice as long as group order, so
+    /// reducing it gives a scalar that is statistically close to uniform.
+    fn challenge<P: ECPoint<Scalar = Self>>(
+        points: &[&P],
+        scalars: &[&Self],
+        extra: &[u8],
+    ) -> Self {
+        let mut hasher = Sha512::new();
+        hasher.update(b"curv.challenge");
+        hasher.update((points.len() as u64).to_be_bytes());
+        for point in points {
+            let bytes = point.serialize_compressed();
+            hasher.update((bytes.len() as u64).to_be_bytes());
+            hasher.update(&bytes);
+        }
+        hasher.update((scalars.len() as u64).to_be_bytes());
+        for scalar in scalars {
+            let bytes = scalar.serialize();
+            hasher.update((bytes.len() as u64).to_be_bytes());
+            hasher.update(&bytes);
+        }
+        hasher.update((extra.len() as u64).to_be_bytes());
+        hasher.update(extra);
+        Self::from_bigint(&BigInt::from_bytes(&hasher.finalize()))
+    }
+    /// Interprets bytes as big-endian integer and reduces it modulo group order
+    ///
+    /// Gives the same result as `from_bigint(&BigInt::from_bytes(bytes))` (for ed25519 — as long
+    /// as input is at most 64 bytes, longer inputs are truncated by its `from_bigint`), but never
+    /// converts the input into BigInt, whose arithmetic is variable time. Input is split into chunks of
+    /// `ScalarLength - 1` bytes, every chunk is less than group order, so it's decoded as a
+    /// canonical scalar, and chunks are combined by Horner's scheme using scalar arithmetic.
+    /// Running time depends only on the input length as long as scalar decoding and arithmetic
+    /// of the backend are constant time (which is not the case for ed25519 backend that performs
+    /// arithmetic on BigInts).
+    fn from_bytes_reduce_ct(bytes: &[u8]) -> Self {
+        let scalar_len = Self::ScalarLength::USIZE;
+        let chunk_len = scalar_len - 1;
+        let little_endian = Self::from_bigint(&BigInt::from(1)).serialize()[0] == 1;
+        let shift = Self::from_bigint(&(BigInt::from(1) << (8 * chunk_len)));
+
+        let head_len = bytes.len() % chunk_len;
+        let chunks = std::iter::once(&bytes[..head_len]).chain(bytes[head_len..].chunks(chunk_len));
+        let mut result = Self::zero();
+        for chunk in chunks {
+            let mut encoded = Zeroizing::new(vec![0u8; scalar_len]);
+            encoded[scalar_len - chunk.len()..].copy_from_slice(chunk);
+            if little_endian {
+                encoded.reverse();
+            }
+            let chunk = Self::deserialize(&encoded).expect("chunk is less than group order");
+            result = result.mul(&shift).add(&chunk);
+        }
+        result
+    }
     /// Converts a scalar to BigInt
     fn to_bigint(&self) -> BigInt;
+    /// Bit decomposition of the scalar, least significant bit first
+    ///
+    /// Output is zero-padded to the bit length of [group order](Self::group_order).
+    fn to_bits(&self) -> Vec<bool> {
+        let n = self.to_bigint();
+        (0..Self::group_order().bit_length())
+            .map(|i| n.test_bit(i))
+            .collect()
+    }
+    /// Constructs a scalar from its bit decomposition, least significant bit first
+    ///
+    /// Inverse of [to_bits](Self::to_bits). Integer represented by `bits` is reduced modulo group
+    /// order.
+    fn from_bits(bits: &[bool]) -> Self {
+        let mut n = BigInt::from(0);
+        for (i, &bit) in bits.iter().enumerate() {
+            n.set_bit(i, bit);
+        }
+        Self::from_bigint(&n)
+    }
     /// Serializes scalar into bytes
     fn serialize(&self) -> GenericArray<u8, Self::ScalarLength>;
     /// Deserializes scalar from bytes
     fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>;
+    /// Deserializes scalar from its canonical encoding
+    ///
+    /// Strict counterpart of [from_bigint](Self::from_bigint): encoding must be exactly
+    /// [ScalarLength](Self::ScalarLength) bytes in curve-specific byte order (same as produced by
+    /// [serialize](Self::serialize)), and encoded integer must be in `[0, group_order)`. Returns
+    /// error instead of reducing or truncating the input.
+    fn try_from_canonical_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        if bytes.len() != Self::ScalarLength::USIZE {
+            return Err(DeserializationError);
+        }
+        let scalar = Self::deserialize(bytes)?;
+        if &scalar.to_bigint() >= Self::group_order() || scalar.serialize()[..] != *bytes {
+            return Err(DeserializationError);
+        }
+        Ok(scalar)
+    }
+    /// Serializes scalar into big-endian bytes, left-padded with zeroes
+    ///
+    /// Unlike [serialize](Self::serialize), which uses curve-specific encoding (e.g. little-endian
+    /// for ed25519), byte order of this method is the same for all curves.
+    fn serialize_be(&self) -> GenericArray<u8, Self::ScalarLength> {
+        let bytes = self.to_bigint().to_bytes();
+        let mut output = GenericArray::default();
+        let offset = output.len() - bytes.len();
+        output[offset..].copy_from_slice(&bytes);
+        output
+    }
+    /// Deserializes scalar from big-endian bytes
+    ///
+    /// Returns error if encoded integer is not less than [group order](Self::group_order)
+    fn deserialize_be(
+        bytes: &GenericArray<u8, Self::ScalarLength>,
+    ) -> Result<Self, DeserializationError> {
+        let n = BigInt::from_bytes(bytes);
+        if &n >= Self::group_order() {
+            return Err(DeserializationError);
+        }
+        Ok(Self::from_bigint(&n))
+    }
 
     /// Calculates `(self + other) mod group_order`
     fn add(&self, other: &Self) -> Self;
@@ -139,6 +355,15 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
         self == &Self::zero()
     }
 
+    /// Compares two points in constant time
+    ///
+    /// Unlike `PartialEq`, which may exit early on the first mismatching limb, this method compares
+    /// normalized compressed encodings of both points without branching on their content. Use it
+    /// whenever one of the operands is derived from secret data.
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.serialize_compressed()[..].ct_eq(&other.serialize_compressed()[..])
+    }
+
     /// Curve generator
     ///
     /// Returns a static reference at actual value because in most cases reference value is fine.
@@ -149,6 +374,39 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
     /// We provide an alternative generator value and prove that it was picked randomly
     fn base_point2() -> &'static Self;
 
+    /// Derives a point with unknown discrete log from `label` and `index`
+    ///
+    /// Output must be deterministic, and its order must be equal to [group order](ECScalar::group_order).
+    /// Default implementation uses try-and-increment: SHA-512 hash of `label || index || counter` is
+    /// interpreted as compressed point encoding, counter is incremented until the bytes decode into
+    /// a point of group order.
+    fn derive_generator(label: &[u8], index: u64) -> Self {
+        let len = Self::CompressedPointLength::to_usize();
+        for counter in 0u32.. {
+            let mut bytes = Vec::with_capacity(len + 64);
+            for block in 0u32.. {
+                if bytes.len() >= len {
+                    break;
+                }
+                bytes.extend_from_slice(
+                    &Sha512::new()
+                        .chain(label)
+                        .chain(index.to_be_bytes())
+                        .chain(counter.to_be_bytes())
+                        .chain(block.to_be_bytes())
+                        .finalize(),
+                );
+            }
+            bytes.truncate(len);
+            if let Ok(point) = Self::deserialize(&bytes) {
+                if point.check_point_order_equals_group_order() {
+                    return point;
+                }
+            }
+        }
+        unreachable!("counter space is exhausted")
+    }
+
     /// Constructs a curve point from its coordinates
     ///
     /// Returns error if x, y are not on curve
@@ -172,6 +430,13 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
     ///
     /// Whether point in compressed or uncompressed form will be deducted from its size
     fn deserialize(bytes: &[u8]) -> Result<Self, DeserializationError>;
+    /// Short fingerprint of the point: `HASH160` (`RIPEMD160(SHA256(..))`) of its
+    /// [compressed form](Self::serialize_compressed)
+    ///
+    /// For secp256k1 public keys it matches the hash committed to by Bitcoin P2PKH addresses.
+    fn fingerprint(&self) -> [u8; 20] {
+        hash160(&self.serialize_compressed())
+    }
 
     /// Checks that order of this point equals to [group order](ECScalar::group_order)
     ///
@@ -194,6 +459,27 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
         !self.is_zero() && self_at_q.is_zero()
     }
 
+    /// Validates a point supplied by untrusted party
+    ///
+    /// Checks that the point is not zero and belongs to the prime-order subgroup (see
+    /// [check_point_order_equals_group_order](Self::check_point_order_equals_group_order)), which
+    /// protects against small-subgroup attacks on curves with co-factor ≠ 1. Point is on the curve
+    /// by construction, as every constructor of `ECPoint` checks it.
+    ///
+    /// [Point::from_bytes](crate::elliptic::curves::Point::from_bytes) and the rest of `Point<E>`
+    /// constructors perform this check via [Point::from_raw](crate::elliptic::curves::Point::from_raw),
+    /// except that they accept zero point. Use
+    /// [Point::ensure_nonzero](crate::elliptic::curves::Point::ensure_nonzero) to reject it as well.
+    fn is_valid_point(&self) -> Result<(), InvalidPoint> {
+        if self.is_zero() {
+            Err(InvalidPoint::ZeroPoint)
+        } else if !self.check_point_order_equals_group_order() {
+            Err(InvalidPoint::MismatchedPointOrder)
+        } else {
+            Ok(())
+        }
+    }
+
     /// Multiplies the point at scalar value
     fn scalar_mul(&self, scalar: &Self::Scalar) -> Self;
     /// Multiplies curve generator at given scalar
@@ -204,11 +490,60 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
         Self::generator().scalar_mul(scalar)
     }
     /// Adds two points
+    ///
+    /// Must give correct result for all inputs, including exceptional cases of incomplete
+    /// addition formulas: `P + P`, `P + (-P)` and `P + O`. Whether it runs in constant time
+    /// depends on backend: p256 and ristretto use complete formulas and are constant time;
+    /// ed25519 uses complete formulas, but normalizes the sum via variable time decoding;
+    /// secp256k1 and bls12_381 branch on exceptional cases and are variable time. There's no
+    /// constant-time alternative for the latter two: libsecp256k1 doesn't expose field
+    /// arithmetic, and projective addition of `pairing_plus` branches on its inputs.
     fn add_point(&self, other: &Self) -> Self;
+    /// Sums up all the points
+    ///
+    /// Returns zero point if `points` is empty. Default implementation folds points with
+    /// [add_point](Self::add_point). Backends which keep points in affine form override it to
+    /// accumulate the sum in projective coordinates and normalize once at the end.
+    fn sum(points: &[Self]) -> Self {
+        points
+            .iter()
+            .fold(Self::zero(), |acc, point| acc.add_point(point))
+    }
     /// Substrates `other` from `self`
     fn sub_point(&self, other: &Self) -> Self;
     /// Negates point
     fn neg_point(&self) -> Self;
+    /// Calculates `2 * self`
+    fn double(&self) -> Self {
+        self.add_point(self)
+    }
+    /// Calculates `3 * self`
+    fn triple(&self) -> Self {
+        self.double().add_point(self)
+    }
+    /// Multiplies the point at small integer `n`
+    ///
+    /// Uses double-and-add over bits of `n`, which takes at most 2 * log2(n) point operations.
+    /// For small `n` (e.g. cofactor clearing) it's much cheaper than [scalar_mul](Self::scalar_mul)
+    /// that always processes full-size scalar. Not constant time: running time depends on `n`.
+    fn mul_u64(&self, n: u64) -> Self {
+        match n {
+            0 => return Self::zero(),
+            1 => return self.clone(),
+            2 => return self.double(),
+            3 => return self.triple(),
+            _ => (),
+        }
+        let top_bit = 63 - n.leading_zeros();
+        (0..top_bit).rev().fold(self.clone(), |acc, i| {
+            let doubled = acc.double();
+            if (n >> i) & 1 == 1 {
+                doubled.add_point(self)
+            } else {
+                doubled
+            }
+        })
+    }
 
     /// Multiplies the point at scalar value, assigns result to `self`
     fn scalar_mul_assign(&mut self, scalar: &Self::Scalar) {
@@ -235,6 +570,84 @@ pub trait ECPoint: Zeroize + Clone + PartialEq + fmt::Debug + 'static {
     fn from_underlying(u: Self::Underlying) -> Self;
 }
 
+/// Point of a curve in short Weierstrass form $y^2 = x^3 + ax + b$ over 256 bits prime field
+///
+/// Provides SEC1 compressed encoding computed directly from curve equation: 33 bytes where the
+/// first byte is `0x02` for even `y`, `0x03` for odd `y`, followed by big-endian `x`. Point at
+/// infinity is encoded as 33 zero bytes, same as in [ECPoint::serialize_compressed].
+///
+/// Decompression recovers `y` as modular square root of $x^3 + ax + b$, which is computed as
+/// exponentiation to $(p+1)/4$, so field prime must satisfy $p = 3 \pmod 4$.
+pub trait ShortWeierstrassPoint: ECPoint {
+    /// Prime $p$ of the base field
+    fn field_prime() -> &'static BigInt;
+    /// Coefficient $a$ of the curve equation
+    fn coefficient_a() -> &'static BigInt;
+    /// Coefficient $b$ of the curve equation
+    fn coefficient_b() -> &'static BigInt;
+
+    /// Encodes point in 33 bytes compressed form
+    fn compress(&self) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        if let Some(coords) = self.coords() {
+            bytes[0] = if coords.y.test_bit(0) { 0x03 } else { 0x02 };
+            bytes[1..].copy_from_slice(
+                &coords
+                    .x
+                    .to_bytes_array::<32>()
+                    .expect("x coordinate doesn't exceed 32 bytes"),
+            );
+        }
+        bytes
+    }
+
+    /// Decodes point from 33 bytes compressed form
+    ///
+    /// Returns error if header byte is neither `0x02` nor `0x03`, if `x` isn't a canonical field
+    /// element, or if there's no point with given `x` on the curve.
+    fn decompress(bytes: &[u8; 33]) -> Result<Self, DeserializationError> {
+        if bytes == &[0u8; 33] {
+            return Ok(Self::zero());
+        }
+        let odd = match bytes[0] {
+            0x02 => false,
+            0x03 => true,
+            _ => return Err(DeserializationError),
+        };
+        let p = Self::field_prime();
+        let x = BigInt::from_bytes(&bytes[1..]);
+        if &x >= p {
+            return Err(DeserializationError);
+        }
+
+        let x_cubed = BigInt::mod_pow(&x, &BigInt::from(3), p);
+        let ax = BigInt::mod_mul(Self::coefficient_a(), &x, p);
+        let rhs = BigInt::mod_add(&BigInt::mod_add(&x_cubed, &ax, p), Self::coefficient_b(), p);
+        let exponent = (p + BigInt::from(1)) >> 2;
+        let mut y = BigInt::mod_pow(&rhs, &exponent, p);
+        if BigInt::mod_mul(&y, &y, p) != rhs {
+            return Err(DeserializationError);
+        }
+        if y.test_bit(0) != odd {
+            y = BigInt::mod_sub(p, &y, p);
+        }
+        Self::from_coords(&x, &y).map_err(|_| DeserializationError)
+    }
+}
+
+/// Point of a curve whose backend can multiply it at secret scalar in constant time
+///
+/// Implemented for secp256k1, p256, ed25519 and ristretto. BLS12-381 backend doesn't provide
+/// constant-time arithmetic, so its points don't implement this trait.
+pub trait ConstantTimeMul: ECPoint {
+    /// Multiplies the point at scalar value in constant time
+    ///
+    /// Use it instead of [scalar_mul](ECPoint::scalar_mul) when the scalar is secret (e.g.
+    /// long-term private key) and the point is not a generator. Result is the same as of
+    /// `scalar_mul`.
+    fn scalar_mul_ct(&self, scalar: &Self::Scalar) -> Self;
+}
+
 /// Affine coordinates of a point
 #[derive(Serialize, Deserialize)]
 pub struct PointCoords {
@@ -242,6 +655,40 @@ pub struct PointCoords {
     pub y: BigInt,
 }
 
+impl PointCoords {
+    /// Encodes coordinates in 64 bytes `x || y`, each coordinate is 32 bytes big-endian
+    /// zero-padded from the left
+    ///
+    /// ## Panics
+    /// Panics if any coordinate doesn't fit into 32 bytes (eg. coordinates of BLS12-381 points)
+    pub fn to_bytes64(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(
+            &self
+                .x
+                .to_bytes_array::<32>()
+                .expect("x coordinate exceeds 32 bytes"),
+        );
+        bytes[32..].copy_from_slice(
+            &self
+                .y
+                .to_bytes_array::<32>()
+                .expect("y coordinate exceeds 32 bytes"),
+        );
+        bytes
+    }
+
+    /// Decodes coordinates encoded by [to_bytes64](Self::to_bytes64)
+    ///
+    /// Doesn't check that coordinates belong to any curve, use [ECPoint::from_coords] for that.
+    pub fn from_bytes64(bytes: &[u8; 64]) -> Self {
+        PointCoords {
+            x: BigInt::from_bytes(&bytes[..32]),
+            y: BigInt::from_bytes(&bytes[32..]),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct DeserializationError;
 
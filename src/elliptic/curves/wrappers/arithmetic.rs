@@ -315,6 +315,8 @@ impl<E: Curve> ops::Neg for Generator<E> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::elliptic::curves::{ECPoint, ECScalar};
+    use crate::test_for_all_curves;
 
     macro_rules! assert_operator_defined_for {
         (
@@ -439,8 +441,8 @@ mod test {
         fn _curve<E: Curve>() {
             assert_operator_defined_for! {
                 assert_fn = assert_scalars_addition_defined,
-                lhs = {Scalar<E>, Scalar<E>},
-                rhs = {Scalar<E>, Scalar<E>},
+                lhs = {Scalar<E>, &Scalar<E>},
+                rhs = {Scalar<E>, &Scalar<E>},
             }
         }
     }
@@ -461,8 +463,8 @@ mod test {
         fn _curve<E: Curve>() {
             assert_operator_defined_for! {
                 assert_fn = assert_scalars_subtraction_defined,
-                lhs = {Scalar<E>, Scalar<E>},
-                rhs = {Scalar<E>, Scalar<E>},
+                lhs = {Scalar<E>, &Scalar<E>},
+                rhs = {Scalar<E>, &Scalar<E>},
             }
         }
     }
@@ -483,9 +485,92 @@ mod test {
         fn _curve<E: Curve>() {
             assert_operator_defined_for! {
                 assert_fn = assert_scalars_multiplication_defined,
-                lhs = {Scalar<E>, Scalar<E>},
-                rhs = {Scalar<E>, Scalar<E>},
+                lhs = {Scalar<E>, &Scalar<E>},
+                rhs = {Scalar<E>, &Scalar<E>},
             }
         }
     }
+
+    /// Function asserts that N can be negated (ie. -N) and result is O.
+    /// If any condition doesn't meet, function won't compile.
+    #[allow(dead_code)]
+    fn assert_negation_defined<N, O>()
+    where
+        N: ops::Neg<Output = O>,
+    {
+        // no-op
+    }
+
+    #[test]
+    fn test_negation_defined() {
+        fn _curve<E: Curve>() {
+            assert_negation_defined::<Scalar<E>, Scalar<E>>();
+            assert_negation_defined::<&Scalar<E>, Scalar<E>>();
+            assert_negation_defined::<Point<E>, Point<E>>();
+            assert_negation_defined::<&Point<E>, Point<E>>();
+            assert_negation_defined::<Generator<E>, Point<E>>();
+        }
+    }
+
+    test_for_all_curves!(scalar_operators_match_trait_methods);
+    fn scalar_operators_match_trait_methods<E: Curve>() {
+        let a = Scalar::<E>::random();
+        let b = Scalar::<E>::random();
+
+        let sum = Scalar::from_raw(a.as_raw().add(b.as_raw()));
+        assert_eq!(&a + &b, sum);
+        assert_eq!(a.clone() + &b, sum);
+        assert_eq!(&a + b.clone(), sum);
+        assert_eq!(a.clone() + b.clone(), sum);
+
+        let difference = Scalar::from_raw(a.as_raw().sub(b.as_raw()));
+        assert_eq!(&a - &b, difference);
+        assert_eq!(a.clone() - &b, difference);
+        assert_eq!(&a - b.clone(), difference);
+        assert_eq!(a.clone() - b.clone(), difference);
+
+        let product = Scalar::from_raw(a.as_raw().mul(b.as_raw()));
+        assert_eq!(&a * &b, product);
+        assert_eq!(a.clone() * &b, product);
+        assert_eq!(&a * b.clone(), product);
+        assert_eq!(a.clone() * b.clone(), product);
+
+        let negation = Scalar::from_raw(a.as_raw().neg());
+        assert_eq!(-&a, negation);
+        assert_eq!(-a, negation);
+    }
+
+    test_for_all_curves!(point_operators_match_trait_methods);
+    fn point_operators_match_trait_methods<E: Curve>() {
+        let p = Point::<E>::generator() * Scalar::random();
+        let q = Point::<E>::generator() * Scalar::random();
+        let s = Scalar::<E>::random();
+
+        let sum = Point::from_raw(p.as_raw().add_point(q.as_raw())).unwrap();
+        assert_eq!(&p + &q, sum);
+        assert_eq!(p.clone() + &q, sum);
+        assert_eq!(&p + q.clone(), sum);
+        assert_eq!(p.clone() + q.clone(), sum);
+
+        let difference = Point::from_raw(p.as_raw().sub_point(q.as_raw())).unwrap();
+        assert_eq!(&p - &q, difference);
+        assert_eq!(p.clone() - &q, difference);
+        assert_eq!(&p - q.clone(), difference);
+        assert_eq!(p.clone() - q.clone(), difference);
+
+        let product = Point::from_raw(p.as_raw().scalar_mul(s.as_raw())).unwrap();
+        assert_eq!(&p * &s, product);
+        assert_eq!(p.clone() * &s, product);
+        assert_eq!(&p * s.clone(), product);
+        assert_eq!(&s * &p, product);
+        assert_eq!(s.clone() * p.clone(), product);
+
+        let generator_product = Point::from_raw(E::Point::generator_mul(s.as_raw())).unwrap();
+        assert_eq!(Point::generator() * &s, generator_product);
+        assert_eq!(&s * Point::generator(), generator_product);
+
+        let negation = Point::from_raw(p.as_raw().neg_point()).unwrap();
+        assert_eq!(-&p, negation);
+        assert_eq!(-p, negation);
+    }
 }
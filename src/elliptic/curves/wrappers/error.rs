@@ -14,15 +14,33 @@ impl MismatchedPointOrder {
     }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, Clone, PartialEq)]
 pub enum PointFromBytesError {
+    /// Length matches neither compressed nor uncompressed point encoding
+    #[error("invalid point length: expected {compressed} (compressed) or {uncompressed} (uncompressed) bytes, got {got}")]
+    InvalidLength {
+        compressed: usize,
+        uncompressed: usize,
+        got: usize,
+    },
+    /// Bytes have valid length, but don't encode a point (eg. invalid header, or point is not
+    /// on the curve)
     #[error("failed to deserialize the point")]
     DeserializationError,
     #[error("invalid point ({0})")]
     InvalidPoint(MismatchedPointOrder),
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, Clone, PartialEq)]
+pub enum ScalarFromBytesError {
+    #[error("invalid scalar length: expected {expected} bytes, got {got}")]
+    InvalidLength { expected: usize, got: usize },
+    /// Encoded integer is not less than group order
+    #[error("encoded scalar is not less than group order")]
+    Overflow,
+}
+
+#[derive(Debug, Error, Clone, PartialEq)]
 pub enum PointFromCoordsError {
     #[error("{}", NotOnCurve)]
     NotOnCurve,
@@ -73,3 +91,77 @@ pub enum InvalidPoint {
     #[error("{}", MismatchedPointOrder(()))]
     MismatchedPointOrder,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arithmetic::Converter;
+    use crate::elliptic::curves::{Curve, Point, Scalar};
+    use crate::test_for_all_curves;
+    use crate::BigInt;
+
+    test_for_all_curves!(point_from_bytes_reports_cause);
+    fn point_from_bytes_reports_cause<E: Curve>() {
+        let compressed = E::Point::generator().serialize_compressed().len();
+        let uncompressed = E::Point::generator().serialize_uncompressed().len();
+        let too_long = vec![0xff; uncompressed.max(compressed) + 1];
+        assert_eq!(
+            Point::<E>::from_bytes(&too_long),
+            Err(PointFromBytesError::InvalidLength {
+                compressed,
+                uncompressed,
+                got: too_long.len()
+            })
+        );
+
+        // Correct length, but not an encoding of a valid point. Ed25519 backend decodes
+        // non-canonical encodings, so it may end up with a point of small order instead
+        assert!(matches!(
+            Point::<E>::from_bytes(&vec![0xff; compressed]),
+            Err(PointFromBytesError::DeserializationError)
+                | Err(PointFromBytesError::InvalidPoint(_))
+        ));
+    }
+
+    test_for_all_curves!(scalar_from_bytes_reports_cause);
+    fn scalar_from_bytes_reports_cause<E: Curve>() {
+        let expected = Scalar::<E>::random().to_bytes().len();
+        assert_eq!(
+            Scalar::<E>::from_bytes(&vec![1; expected + 1]),
+            Err(ScalarFromBytesError::InvalidLength {
+                expected,
+                got: expected + 1
+            })
+        );
+        assert_eq!(
+            Scalar::<E>::from_bytes(&[]),
+            Err(ScalarFromBytesError::InvalidLength { expected, got: 0 })
+        );
+
+        // Ed25519 backend silently reduces the integer modulo group order
+        if E::CURVE_NAME != "ed25519" {
+            let mut order = vec![0u8; expected];
+            let order_be = Scalar::<E>::group_order().to_bytes();
+            order[expected - order_be.len()..].copy_from_slice(&order_be);
+            if Scalar::<E>::from(1).to_bytes()[0] == 1 {
+                order.reverse();
+            }
+            assert_eq!(
+                Scalar::<E>::from_bytes(&order),
+                Err(ScalarFromBytesError::Overflow)
+            );
+        }
+    }
+
+    test_for_all_curves!(point_from_coords_reports_cause);
+    fn point_from_coords_reports_cause<E: Curve>() {
+        let coords = Point::<E>::generator().to_point().coords();
+        if let Some(coords) = coords {
+            let y = coords.y + BigInt::from(1);
+            assert_eq!(
+                Point::<E>::from_coords(&coords.x, &y),
+                Err(PointFromCoordsError::NotOnCurve)
+            );
+        }
+    }
+}
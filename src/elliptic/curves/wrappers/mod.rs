@@ -3,8 +3,14 @@ mod encoded_point;
 mod encoded_scalar;
 pub mod error;
 mod generator;
+#[cfg(feature = "pkcs8")]
+pub mod pkcs8;
 mod point;
 mod scalar;
+pub mod serde_ristretto_point;
+pub mod serde_scalar_vec;
+#[cfg(feature = "encryption")]
+pub mod serde_secret_key_encrypted;
 mod serde_support;
 
 pub use self::{
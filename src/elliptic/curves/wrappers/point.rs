@@ -1,11 +1,19 @@
 use std::{fmt, iter};
 
+use subtle::{Choice, ConstantTimeEq};
+use typenum::Unsigned;
+
+use crate::arithmetic::Converter;
+use crate::cryptographic_primitives::hashing::hash160::base58check_encode;
 use crate::elliptic::curves::traits::*;
 use crate::BigInt;
 
 use super::{
-    error::{MismatchedPointOrder, PointFromBytesError, PointFromCoordsError, ZeroPointError},
-    EncodedPoint, Generator,
+    error::{
+        InvalidPoint, MismatchedPointOrder, PointFromBytesError, PointFromCoordsError,
+        ZeroPointError,
+    },
+    EncodedPoint, Generator, Scalar,
 };
 use crate::elliptic::curves::wrappers::encoded_point::EncodedPointChoice;
 
@@ -73,6 +81,14 @@ impl<E: Curve> Point<E> {
         Generator::default()
     }
 
+    /// Lifts scalar `s` to the point `s * G`, where `G` is the [generator](Self::generator)
+    ///
+    /// Same as `Point::generator() * s`. Not constant time, see [mul_ct](Self::mul_ct) if
+    /// the scalar is secret.
+    pub fn from_scalar(s: &Scalar<E>) -> Self {
+        Point::generator() * s
+    }
+
     /// Curve second generator
     ///
     /// We provide an alternative generator value and prove that it was picked randomly.
@@ -85,6 +101,24 @@ impl<E: Curve> Point<E> {
         unsafe { Self::from_raw_ref_unchecked(p) }
     }
 
+    /// Derives `count` independent generators from `label`
+    ///
+    /// Generators are obtained by hashing `label || i` to the curve for each index `i`, so no one
+    /// knows their discrete logs with respect to each other or to the [generator](Self::generator).
+    /// The same label always produces the same generators.
+    pub fn derive_generators(label: &[u8], count: usize) -> Vec<Self> {
+        (0..count as u64)
+            .map(|i| Self::derive_generator(label, i))
+            .collect()
+    }
+
+    /// Derives `index`-th generator from `label`, same as `derive_generators(label, n)[index]`
+    /// for any `n > index`
+    pub fn derive_generator(label: &[u8], index: u64) -> Self {
+        let p = E::Point::derive_generator(label, index);
+        Self::from_raw(p).expect("derived generator must have group order")
+    }
+
     /// Constructs zero point
     ///
     /// Zero point (or curve neutral element) is usually denoted as `O`. Its property: `forall A. A + O = A`.
@@ -139,12 +173,48 @@ impl<E: Curve> Point<E> {
         Self::from_raw(raw_point).map_err(PointFromCoordsError::InvalidPoint)
     }
 
+    /// Constructs a point from big-endian encoded coordinates
+    ///
+    /// Same as [from_coords](Self::from_coords), but takes coordinates as bytes.
+    pub fn from_coords_be(x_be: &[u8], y_be: &[u8]) -> Result<Self, PointFromCoordsError> {
+        Self::from_coords(&BigInt::from_bytes(x_be), &BigInt::from_bytes(y_be))
+    }
+
+    /// Constructs a point from little-endian encoded coordinates
+    ///
+    /// Same as [from_coords_be](Self::from_coords_be), but bytes of each coordinate go in reversed
+    /// order, as in some ed25519 tooling.
+    pub fn from_coords_le(x_le: &[u8], y_le: &[u8]) -> Result<Self, PointFromCoordsError> {
+        let x_be: Vec<u8> = x_le.iter().rev().copied().collect();
+        let y_be: Vec<u8> = y_le.iter().rev().copied().collect();
+        Self::from_coords_be(&x_be, &y_be)
+    }
+
     /// Tries to parse a point in (un)compressed form
     ///
-    /// Whether it's in compressed or uncompressed form will be deduced from its length
+    /// Whether it's in compressed or uncompressed form will be deduced from its length. Error
+    /// tells whether length is wrong ([InvalidLength](PointFromBytesError::InvalidLength)),
+    /// bytes don't encode a point ([DeserializationError](PointFromBytesError::DeserializationError)),
+    /// or point has invalid order ([InvalidPoint](PointFromBytesError::InvalidPoint)).
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, PointFromBytesError> {
-        let p = E::Point::deserialize(bytes)
-            .map_err(|_: DeserializationError| PointFromBytesError::DeserializationError)?;
+        let compressed = <E::Point as ECPoint>::CompressedPointLength::USIZE;
+        let uncompressed = <E::Point as ECPoint>::UncompressedPointLength::USIZE;
+        let invalid_length = PointFromBytesError::InvalidLength {
+            compressed,
+            uncompressed,
+            got: bytes.len(),
+        };
+        if bytes.len() > compressed.max(uncompressed) {
+            return Err(invalid_length);
+        }
+        // Some backends accept shorter encodings, so length is blamed only if decoding failed
+        let p = E::Point::deserialize(bytes).map_err(|_: DeserializationError| {
+            if bytes.len() != compressed && bytes.len() != uncompressed {
+                invalid_length
+            } else {
+                PointFromBytesError::DeserializationError
+            }
+        })?;
         Self::from_raw(p).map_err(PointFromBytesError::InvalidPoint)
     }
 
@@ -161,10 +231,24 @@ impl<E: Curve> Point<E> {
         }
     }
 
+    /// Short fingerprint of the point, see [ECPoint::fingerprint]
+    pub fn fingerprint(&self) -> [u8; 20] {
+        self.as_raw().fingerprint()
+    }
+
+    /// [Fingerprint](Self::fingerprint) encoded in Base58Check with given version byte
+    ///
+    /// Suitable for displaying public key as an address, eg. version `0x00` gives Bitcoin mainnet
+    /// P2PKH address for secp256k1 public key.
+    pub fn fingerprint_base58check(&self, version: u8) -> String {
+        base58check_encode(version, &self.fingerprint())
+    }
+
     /// Constructs a `Point<E>` from low-level [ECPoint] implementor
     ///
     /// Returns error if point is not valid. Valid point is either a zero point, or a point of
-    /// [group order].
+    /// [group order], i.e. a point that passes [is_valid_point]. Every safe constructor, including
+    /// [from_bytes](Self::from_bytes), goes through this check.
     ///
     /// Typically, you don't need to use this constructor. See [generator](Point::generator),
     /// [base_point2](Point::base_point2), [from_coords](Self::from_coords), [from_bytes](Self::from_bytes)
@@ -172,8 +256,9 @@ impl<E: Curve> Point<E> {
     ///
     /// [ECPoint]: crate::elliptic::curves::ECPoint
     /// [group order]: crate::elliptic::curves::ECScalar::group_order
+    /// [is_valid_point]: crate::elliptic::curves::ECPoint::is_valid_point
     pub fn from_raw(raw_point: E::Point) -> Result<Self, MismatchedPointOrder> {
-        if raw_point.is_zero() || raw_point.check_point_order_equals_group_order() {
+        if is_valid_or_zero(&raw_point) {
             Ok(Self { raw_point })
         } else {
             Err(MismatchedPointOrder::new())
@@ -191,7 +276,7 @@ impl<E: Curve> Point<E> {
     /// [ECPoint]: crate::elliptic::curves::ECPoint
     /// [group order]: crate::elliptic::curves::ECScalar::group_order
     pub fn from_raw_ref(raw_point: &E::Point) -> Result<&Self, MismatchedPointOrder> {
-        if raw_point.is_zero() || raw_point.check_point_order_equals_group_order() {
+        if is_valid_or_zero(raw_point) {
             // Safety: we checked that point is either zero or has correct order
             let reference = unsafe { Self::from_raw_ref_unchecked(raw_point) };
             Ok(reference)
@@ -264,12 +349,47 @@ impl<E: Curve> Point<E> {
     }
 }
 
+impl<E: Curve> Point<E>
+where
+    E::Point: ConstantTimeMul,
+{
+    /// Multiplies the point at scalar in constant time
+    ///
+    /// Gives the same result as `self * scalar`, but doesn't leak the scalar through timing. Use
+    /// it when scalar is secret. Available for [Secp256k1], [Secp256r1], [Ed25519] and
+    /// [Ristretto]; BLS12-381 backend has no constant-time multiplication.
+    ///
+    /// [Secp256k1]: crate::elliptic::curves::Secp256k1
+    /// [Secp256r1]: crate::elliptic::curves::Secp256r1
+    /// [Ed25519]: crate::elliptic::curves::Ed25519
+    /// [Ristretto]: crate::elliptic::curves::Ristretto
+    pub fn mul_ct(&self, scalar: &Scalar<E>) -> Self {
+        let p = self.as_raw().scalar_mul_ct(scalar.as_raw());
+        // Safety: multiplication at scalar preserves point order (or yields zero point)
+        unsafe { Self::from_raw_unchecked(p) }
+    }
+}
+
+/// Checks [ECPoint::is_valid_point], but accepts zero point
+fn is_valid_or_zero<P: ECPoint>(raw_point: &P) -> bool {
+    matches!(
+        raw_point.is_valid_point(),
+        Ok(()) | Err(InvalidPoint::ZeroPoint)
+    )
+}
+
 impl<E: Curve> PartialEq for Point<E> {
     fn eq(&self, other: &Self) -> bool {
         self.raw_point.eq(&other.raw_point)
     }
 }
 
+impl<E: Curve> ConstantTimeEq for Point<E> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_raw().ct_eq(other.as_raw())
+    }
+}
+
 impl<E: Curve> PartialEq<Generator<E>> for Point<E> {
     fn eq(&self, other: &Generator<E>) -> bool {
         self.as_raw().eq(other.as_raw())
@@ -298,12 +418,14 @@ impl<E: Curve> From<Generator<E>> for Point<E> {
 
 impl<E: Curve> iter::Sum for Point<E> {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.fold(Point::zero(), |acc, p| acc + p)
+        let points: Vec<E::Point> = iter.map(Point::into_raw).collect();
+        // Safety: sum of points of group order is either zero or a point of group order
+        unsafe { Point::from_raw_unchecked(E::Point::sum(&points)) }
     }
 }
 
 impl<'p, E: Curve> iter::Sum<&'p Point<E>> for Point<E> {
     fn sum<I: Iterator<Item = &'p Point<E>>>(iter: I) -> Self {
-        iter.fold(Point::zero(), |acc, p| acc + p)
+        iter.cloned().sum()
     }
 }
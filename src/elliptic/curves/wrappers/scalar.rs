@@ -1,9 +1,13 @@
 use std::{fmt, iter};
 
+use generic_array::GenericArray;
+use subtle::Choice;
+use typenum::Unsigned;
+
 use crate::elliptic::curves::traits::{Curve, ECScalar};
 use crate::elliptic::curves::wrappers::encoded_scalar::EncodedScalar;
-use crate::elliptic::curves::{DeserializationError, ZeroScalarError};
-use crate::BigInt;
+use crate::elliptic::curves::{DeserializationError, Point, ScalarFromBytesError, ZeroScalarError};
+use crate::{BigInt, RandomSource};
 
 /// Scalar value in a prime field
 ///
@@ -47,10 +51,20 @@ impl<E: Curve> Scalar<E> {
         }
     }
 
+    /// Lifts the scalar to the point `self * G`, see [Point::from_scalar]
+    pub fn to_point(&self) -> Point<E> {
+        Point::from_scalar(self)
+    }
+
     /// Samples a random nonzero scalar
     pub fn random() -> Self {
+        Scalar::from_raw(E::Scalar::random_nonzero())
+    }
+
+    /// Samples a random nonzero scalar using given source of randomness
+    pub fn random_with_rng<R: RandomSource + ?Sized>(rng: &mut R) -> Self {
         loop {
-            let s = E::Scalar::random();
+            let s = E::Scalar::from_rng(rng);
             if !s.is_zero() {
                 break Scalar::from_raw(s);
             }
@@ -67,6 +81,11 @@ impl<E: Curve> Scalar<E> {
         self.as_raw().is_zero()
     }
 
+    /// Checks if a scalar is zero in constant time
+    pub fn ct_is_zero(&self) -> Choice {
+        self.as_raw().ct_is_zero()
+    }
+
     /// Converts a scalar to [BigInt]
     pub fn to_bigint(&self) -> BigInt {
         self.as_raw().to_bigint()
@@ -83,8 +102,36 @@ impl<E: Curve> Scalar<E> {
     }
 
     /// Constructs a scalar from bytes
-    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
-        ECScalar::deserialize(bytes).map(Self::from_raw)
+    ///
+    /// Bytes must be exactly [scalar length](ECScalar::ScalarLength) long, otherwise
+    /// [InvalidLength](ScalarFromBytesError::InvalidLength) error is returned. Fails with
+    /// [Overflow](ScalarFromBytesError::Overflow) if backend rejects the encoded integer as not
+    /// less than group order.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ScalarFromBytesError> {
+        let expected = <E::Scalar as ECScalar>::ScalarLength::USIZE;
+        if bytes.len() != expected {
+            return Err(ScalarFromBytesError::InvalidLength {
+                expected,
+                got: bytes.len(),
+            });
+        }
+        ECScalar::deserialize(bytes)
+            .map(Self::from_raw)
+            .map_err(|_: DeserializationError| ScalarFromBytesError::Overflow)
+    }
+
+    /// Serializes a scalar to big-endian bytes, left-padded with zeroes to scalar length
+    pub fn to_be_bytes(&self) -> GenericArray<u8, <E::Scalar as ECScalar>::ScalarLength> {
+        self.as_raw().serialize_be()
+    }
+
+    /// Constructs a scalar from big-endian bytes
+    ///
+    /// Returns error if encoded integer is not less than [group order](Self::group_order)
+    pub fn from_be_bytes(
+        bytes: &GenericArray<u8, <E::Scalar as ECScalar>::ScalarLength>,
+    ) -> Result<Self, DeserializationError> {
+        E::Scalar::deserialize_be(bytes).map(Self::from_raw)
     }
 
     /// Returns an order of generator point
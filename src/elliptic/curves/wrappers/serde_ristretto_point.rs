@@ -0,0 +1,141 @@
+//! (De)serialization of Ristretto point as its canonical encoding
+//!
+//! By default, a point is serialized as a struct carrying curve name and point bytes. This module
+//! encodes `Point<Ristretto>` as hex string of its 32 bytes canonical encoding, which is the
+//! common representation of Ristretto points in other libraries and JSON APIs. Decoding is
+//! strict: the string must contain exactly 32 bytes, and non-canonical encodings are rejected.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use curv::elliptic::curves::{Point, Ristretto, serde_ristretto_point};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! pub struct PublicKey {
+//!     #[serde(with = "serde_ristretto_point")]
+//!     pub point: Point<Ristretto>,
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::de::{Error, Visitor};
+use serde::{Deserializer, Serializer};
+
+use crate::elliptic::curves::{Point, Ristretto};
+
+const ENCODING_SIZE: usize = 32;
+
+/// Serializes a point as hex string of its canonical encoding
+pub fn serialize<S>(point: &Point<Ristretto>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&hex::encode(&*point.to_bytes(true)))
+}
+
+/// Deserializes a point from hex string produced by [serialize]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Point<Ristretto>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct RistrettoPointVisitor;
+
+    impl<'de> Visitor<'de> for RistrettoPointVisitor {
+        type Value = Point<Ristretto>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "hex-encoded canonical encoding of ristretto point ({} bytes)",
+                ENCODING_SIZE
+            )
+        }
+
+        fn visit_str<Err>(self, v: &str) -> Result<Self::Value, Err>
+        where
+            Err: Error,
+        {
+            let bytes = hex::decode(v).map_err(|e| Err::custom(format!("invalid hex: {}", e)))?;
+            if bytes.len() != ENCODING_SIZE {
+                return Err(Err::invalid_length(bytes.len(), &self));
+            }
+            Point::from_bytes(&bytes)
+                .map_err(|_| Err::custom("invalid or non-canonical ristretto point encoding"))
+        }
+    }
+
+    deserializer.deserialize_str(RistrettoPointVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use crate::elliptic::curves::{Point, Ristretto, Scalar};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct PublicKey {
+        #[serde(with = "super")]
+        point: Point<Ristretto>,
+    }
+
+    #[test]
+    fn serialize_deserialize_ristretto_point() {
+        let pk = PublicKey {
+            point: Point::generator() * Scalar::random(),
+        };
+        let encoding = hex::encode(&*pk.point.to_bytes(true));
+        assert_tokens(
+            &pk,
+            &[
+                Token::Struct {
+                    name: "PublicKey",
+                    len: 1,
+                },
+                Token::Str("point"),
+                Token::Str(Box::leak(encoding.into_boxed_str())),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn ristretto_point_survives_json_round_trip() {
+        for point in [Point::zero(), Point::generator() * Scalar::random()] {
+            let pk = PublicKey { point };
+            let json = serde_json::to_string(&pk).unwrap();
+            let pk_deserialized: PublicKey = serde_json::from_str(&json).unwrap();
+            assert_eq!(pk, pk_deserialized);
+        }
+    }
+
+    #[test]
+    fn doesnt_deserialize_non_canonical_encoding() {
+        // Field element p = 2^255 - 19, non-canonical encoding of zero
+        let non_canonical = "edffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f";
+        assert_de_tokens_error::<PublicKey>(
+            &[
+                Token::Struct {
+                    name: "PublicKey",
+                    len: 1,
+                },
+                Token::Str("point"),
+                Token::Str(non_canonical),
+                Token::StructEnd,
+            ],
+            "invalid or non-canonical ristretto point encoding",
+        );
+    }
+
+    #[test]
+    fn doesnt_deserialize_truncated_encoding() {
+        let encoding = hex::encode(&*Point::<Ristretto>::generator().to_bytes(true));
+        let truncated = &encoding[2..];
+        assert!(
+            serde_json::from_str::<PublicKey>(&format!("{{\"point\":\"{}\"}}", truncated)).is_err()
+        );
+    }
+}
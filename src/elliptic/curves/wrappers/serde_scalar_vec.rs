@@ -0,0 +1,177 @@
+//! Compact (de)serialization of a list of scalars
+//!
+//! By default, `Vec<Scalar<E>>` is serialized as a sequence of structs, each carrying curve name
+//! and scalar bytes. This module encodes the whole list as a single byte blob: 4 bytes big-endian
+//! length prefix (number of scalars) followed by fixed-width encodings of scalars.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use curv::elliptic::curves::{Curve, Scalar, serde_scalar_vec};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! #[serde(bound = "")]
+//! pub struct LagrangeCoefficients<E: Curve> {
+//!     #[serde(with = "serde_scalar_vec")]
+//!     pub coefficients: Vec<Scalar<E>>,
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+use typenum::Unsigned;
+
+use crate::elliptic::curves::{Curve, ECScalar, Scalar};
+
+const LENGTH_PREFIX_SIZE: usize = 4;
+
+/// Serializes a list of scalars as a single byte blob
+pub fn serialize<E, S>(scalars: &[Scalar<E>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: Curve,
+    S: Serializer,
+{
+    let scalar_len = scalar_len::<E>();
+    let count = u32::try_from(scalars.len())
+        .map_err(|_| serde::ser::Error::custom("too many scalars to serialize"))?;
+
+    let mut bytes = Vec::with_capacity(LENGTH_PREFIX_SIZE + scalars.len() * scalar_len);
+    bytes.extend_from_slice(&count.to_be_bytes());
+    for scalar in scalars {
+        bytes.extend_from_slice(&scalar.to_bytes());
+    }
+    serializer.serialize_bytes(&bytes)
+}
+
+/// Deserializes a list of scalars from a byte blob produced by [serialize]
+pub fn deserialize<'de, E, D>(deserializer: D) -> Result<Vec<Scalar<E>>, D::Error>
+where
+    E: Curve,
+    D: Deserializer<'de>,
+{
+    struct ScalarVecVisitor<E: Curve>(PhantomData<E>);
+
+    impl<'de, E: Curve> Visitor<'de> for ScalarVecVisitor<E> {
+        type Value = Vec<Scalar<E>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f,
+                "length-prefixed list of scalars of {} curve",
+                E::CURVE_NAME
+            )
+        }
+
+        fn visit_bytes<Err>(self, v: &[u8]) -> Result<Self::Value, Err>
+        where
+            Err: Error,
+        {
+            decode(v)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte)
+            }
+            decode(&bytes)
+        }
+    }
+
+    deserializer.deserialize_bytes(ScalarVecVisitor(PhantomData))
+}
+
+fn decode<E: Curve, Err: Error>(bytes: &[u8]) -> Result<Vec<Scalar<E>>, Err> {
+    if bytes.len() < LENGTH_PREFIX_SIZE {
+        return Err(Err::custom("missing length prefix"));
+    }
+    let (prefix, body) = bytes.split_at(LENGTH_PREFIX_SIZE);
+    let mut count = [0u8; LENGTH_PREFIX_SIZE];
+    count.copy_from_slice(prefix);
+    let count = u32::from_be_bytes(count) as usize;
+
+    let scalar_len = scalar_len::<E>();
+    if count.checked_mul(scalar_len) != Some(body.len()) {
+        return Err(Err::invalid_length(
+            body.len(),
+            &format!("{} scalars of {} bytes each", count, scalar_len).as_str(),
+        ));
+    }
+
+    body.chunks(scalar_len)
+        .map(|bytes| Scalar::from_bytes(bytes).map_err(|_| Err::custom("invalid scalar")))
+        .collect()
+}
+
+fn scalar_len<E: Curve>() -> usize {
+    <<E::Scalar as ECScalar>::ScalarLength as Unsigned>::to_usize()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter;
+
+    use serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+    use crate::elliptic::curves::{Curve, Scalar};
+    use crate::test_for_all_curves;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    #[serde(bound = "")]
+    struct Scalars<E: Curve> {
+        #[serde(with = "super")]
+        scalars: Vec<Scalar<E>>,
+    }
+
+    fn expected_tokens<E: Curve>(scalars: &[Scalar<E>]) -> Vec<Token> {
+        let mut bytes = (scalars.len() as u32).to_be_bytes().to_vec();
+        for scalar in scalars {
+            bytes.extend_from_slice(&scalar.to_bytes());
+        }
+        vec![
+            Token::Struct {
+                name: "Scalars",
+                len: 1,
+            },
+            Token::Str("scalars"),
+            Token::Bytes(bytes.leak()),
+            Token::StructEnd,
+        ]
+    }
+
+    test_for_all_curves!(serialize_deserialize_scalar_vec);
+    fn serialize_deserialize_scalar_vec<E: Curve>() {
+        for size in [0, 1, 100] {
+            let scalars = Scalars::<E> {
+                scalars: iter::repeat_with(Scalar::random).take(size).collect(),
+            };
+            assert_tokens(&scalars, &expected_tokens(&scalars.scalars));
+        }
+    }
+
+    test_for_all_curves!(doesnt_deserialize_truncated_scalar_vec);
+    fn doesnt_deserialize_truncated_scalar_vec<E: Curve>() {
+        let mut tokens = expected_tokens::<E>(&[Scalar::random(), Scalar::random()]);
+        if let Token::Bytes(bytes) = &mut tokens[2] {
+            *bytes = &bytes[..bytes.len() - 1];
+        }
+        let scalar_len = Scalar::<E>::random().to_bytes().len();
+        assert_de_tokens_error::<Scalars<E>>(
+            &tokens,
+            &format!(
+                "invalid length {}, expected 2 scalars of {} bytes each",
+                2 * scalar_len - 1,
+                scalar_len
+            ),
+        );
+    }
+}
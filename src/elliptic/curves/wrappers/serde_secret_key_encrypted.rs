@@ -0,0 +1,211 @@
+//! (De)serialization of a secret scalar in encrypted form
+//!
+//! Scalar is encrypted with AES-256-GCM under a key derived from a passphrase via scrypt. Salt and
+//! nonce are freshly sampled on every serialization and embedded into the output, which has form
+//! `salt || nonce || ciphertext`.
+//!
+//! Serde doesn't allow passing extra arguments to (de)serialization functions, so the passphrase is
+//! supplied via [with_passphrase] which makes it available to the current thread while the closure
+//! is running. (De)serialization fails if no passphrase is set.
+//!
+//! Requires `encryption` feature to be enabled.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use serde::{Serialize, Deserialize};
+//! use curv::elliptic::curves::{Curve, Scalar, serde_secret_key_encrypted};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! #[serde(bound = "")]
+//! pub struct KeyConfig<E: Curve> {
+//!     #[serde(with = "serde_secret_key_encrypted")]
+//!     pub secret_key: Scalar<E>,
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::marker::PhantomData;
+
+use aes_gcm::aead::{Aead, NewAead};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use rand::{rngs::OsRng, RngCore};
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::{Deserializer, Serializer};
+use zeroize::Zeroizing;
+
+use crate::elliptic::curves::{Curve, Scalar};
+
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 12;
+const KEY_SIZE: usize = 32;
+
+thread_local! {
+    static PASSPHRASE: RefCell<Option<Zeroizing<Vec<u8>>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `passphrase` set for (de)serialization of encrypted secret keys in current thread
+///
+/// Previously set passphrase (if any) is restored once `f` returns.
+pub fn with_passphrase<R>(passphrase: &[u8], f: impl FnOnce() -> R) -> R {
+    struct RestoreGuard(Option<Zeroizing<Vec<u8>>>);
+    impl Drop for RestoreGuard {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            PASSPHRASE.with(|p| *p.borrow_mut() = previous)
+        }
+    }
+
+    let previous = PASSPHRASE.with(|p| p.replace(Some(Zeroizing::new(passphrase.to_vec()))));
+    let _guard = RestoreGuard(previous);
+    f()
+}
+
+/// Encrypts and serializes a secret scalar
+pub fn serialize<E, S>(secret: &Scalar<E>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    E: Curve,
+    S: Serializer,
+{
+    use serde::ser::Error;
+
+    let mut salt = [0u8; SALT_SIZE];
+    let mut nonce = [0u8; NONCE_SIZE];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let key = derive_key(&salt).ok_or_else(|| S::Error::custom("passphrase is not set"))?;
+    let plaintext = Zeroizing::new(secret.to_bytes().to_vec());
+    let ciphertext = Aes256Gcm::new(Key::from_slice(&key[..]))
+        .encrypt(Nonce::from_slice(&nonce), &plaintext[..])
+        .map_err(|_| S::Error::custom("encryption failed"))?;
+
+    let mut bytes = Vec::with_capacity(SALT_SIZE + NONCE_SIZE + ciphertext.len());
+    bytes.extend_from_slice(&salt);
+    bytes.extend_from_slice(&nonce);
+    bytes.extend_from_slice(&ciphertext);
+    serializer.serialize_bytes(&bytes)
+}
+
+/// Deserializes and decrypts a secret scalar
+pub fn deserialize<'de, E, D>(deserializer: D) -> Result<Scalar<E>, D::Error>
+where
+    E: Curve,
+    D: Deserializer<'de>,
+{
+    struct EncryptedScalarVisitor<E: Curve>(PhantomData<E>);
+
+    impl<'de, E: Curve> Visitor<'de> for EncryptedScalarVisitor<E> {
+        type Value = Scalar<E>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "encrypted scalar of {} curve", E::CURVE_NAME)
+        }
+
+        fn visit_bytes<Err>(self, v: &[u8]) -> Result<Self::Value, Err>
+        where
+            Err: Error,
+        {
+            decrypt(v)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: SeqAccess<'de>,
+        {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+            while let Some(byte) = seq.next_element::<u8>()? {
+                bytes.push(byte)
+            }
+            decrypt(&bytes)
+        }
+    }
+
+    deserializer.deserialize_bytes(EncryptedScalarVisitor(PhantomData))
+}
+
+fn decrypt<E: Curve, Err: Error>(bytes: &[u8]) -> Result<Scalar<E>, Err> {
+    if bytes.len() < SALT_SIZE + NONCE_SIZE {
+        return Err(Err::custom("encrypted scalar is too short"));
+    }
+    let (salt, rest) = bytes.split_at(SALT_SIZE);
+    let (nonce, ciphertext) = rest.split_at(NONCE_SIZE);
+
+    let key = derive_key(salt).ok_or_else(|| Err::custom("passphrase is not set"))?;
+    let plaintext = Aes256Gcm::new(Key::from_slice(&key[..]))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map(Zeroizing::new)
+        .map_err(|_| Err::custom("decryption failed: wrong passphrase or corrupted data"))?;
+    Scalar::from_bytes(&plaintext).map_err(|_| Err::custom("invalid scalar"))
+}
+
+/// Derives encryption key from passphrase set in current thread, returns `None` if passphrase
+/// is not set
+fn derive_key(salt: &[u8]) -> Option<Zeroizing<[u8; KEY_SIZE]>> {
+    PASSPHRASE.with(|passphrase| {
+        let passphrase = passphrase.borrow();
+        let passphrase = passphrase.as_ref()?;
+        let mut key = Zeroizing::new([0u8; KEY_SIZE]);
+        scrypt::scrypt(
+            passphrase,
+            salt,
+            &scrypt::Params::recommended(),
+            &mut key[..],
+        )
+        .expect("output length is valid");
+        Some(key)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::with_passphrase;
+    use crate::elliptic::curves::{Curve, Scalar, Secp256k1};
+    use crate::test_for_all_curves;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    #[serde(bound = "")]
+    struct KeyConfig<E: Curve> {
+        #[serde(with = "super")]
+        secret_key: Scalar<E>,
+    }
+
+    test_for_all_curves!(encrypted_secret_key_round_trip);
+    fn encrypted_secret_key_round_trip<E: Curve>() {
+        let config = KeyConfig::<E> {
+            secret_key: Scalar::random(),
+        };
+        let json = with_passphrase(b"correct horse battery staple", || {
+            serde_json::to_string(&config).unwrap()
+        });
+        let decrypted: KeyConfig<E> = with_passphrase(b"correct horse battery staple", || {
+            serde_json::from_str(&json).unwrap()
+        });
+        assert_eq!(config, decrypted);
+    }
+
+    #[test]
+    fn wrong_passphrase_fails_to_decrypt() {
+        let config = KeyConfig::<Secp256k1> {
+            secret_key: Scalar::random(),
+        };
+        let json = with_passphrase(b"correct horse battery staple", || {
+            serde_json::to_string(&config).unwrap()
+        });
+        let result = with_passphrase(b"wrong passphrase", || {
+            serde_json::from_str::<KeyConfig<Secp256k1>>(&json)
+        });
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn doesnt_serialize_without_passphrase() {
+        let config = KeyConfig::<Secp256k1> {
+            secret_key: Scalar::random(),
+        };
+        assert!(serde_json::to_string(&config).is_err());
+    }
+}
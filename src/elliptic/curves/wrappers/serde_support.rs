@@ -324,6 +324,23 @@ mod serde_tests {
         )
     }
 
+    #[test]
+    fn secp256k1_values_dont_deserialize_as_ed25519() {
+        let point = Point::<Secp256k1>::generator() * Scalar::random();
+        let json = serde_json::to_string(&point).unwrap();
+        let err = serde_json::from_str::<Point<Ed25519>>(&json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("belongs to secp256k1 curve, expected ed25519 curve"));
+
+        let scalar = Scalar::<Secp256k1>::random();
+        let json = serde_json::to_string(&scalar).unwrap();
+        let err = serde_json::from_str::<Scalar<Ed25519>>(&json).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("belongs to secp256k1 curve, expected ed25519 curve"));
+    }
+
     test_for_all_curves!(doesnt_deserialize_scalar_from_different_curve);
     fn doesnt_deserialize_scalar_from_different_curve<E: Curve>() {
         let tokens = vec![
@@ -14,6 +14,23 @@ pub use crate::arithmetic::BigInt;
 
 pub mod cryptographic_primitives;
 
+use rand::{CryptoRng, RngCore};
+
+/// Cryptographically secure source of randomness
+///
+/// Randomized constructors with `_with_rng` suffix (e.g. [Scalar::random_with_rng],
+/// [VerifiableSS::share_with_rng]) take randomness from a given source, which allows injecting
+/// a deterministic RNG in tests or a certified hardware RNG. Their counterparts without the
+/// suffix use OS RNG.
+///
+/// The trait is implemented for every RNG implementing [RngCore] and [CryptoRng].
+///
+/// [Scalar::random_with_rng]: crate::elliptic::curves::Scalar::random_with_rng
+/// [VerifiableSS::share_with_rng]: crate::cryptographic_primitives::secret_sharing::feldman_vss::VerifiableSS::share_with_rng
+pub trait RandomSource: RngCore + CryptoRng {}
+
+impl<R: RngCore + CryptoRng + ?Sized> RandomSource for R {}
+
 #[derive(Copy, PartialEq, Eq, Clone, Debug)]
 pub enum ErrorKey {
     InvalidPublicKey,
@@ -21,6 +38,12 @@ pub enum ErrorKey {
 
 pub enum ErrorSS {
     VerifyShareError,
+    CommitmentsLengthMismatch,
+    InsufficientWeight,
+    InsufficientShares,
+    TooLarge,
+    DecryptionError,
+    DeserializationError,
 }
 
 #[cfg(test)]